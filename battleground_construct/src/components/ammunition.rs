@@ -0,0 +1,96 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Ammunition of a weapon, weapons without this component have unlimited ammunition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ammunition {
+    count: u32,
+    capacity: u32,
+}
+
+impl Ammunition {
+    /// Create a full magazine.
+    pub fn new(capacity: u32) -> Self {
+        Ammunition {
+            count: capacity,
+            capacity,
+        }
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.count >= self.capacity
+    }
+
+    /// Use a round, returns false if there was nothing left.
+    pub fn consume(&mut self) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        self.count -= 1;
+        true
+    }
+
+    /// Add rounds, up to the capacity.
+    pub fn resupply(&mut self, rounds: u32) {
+        self.count = self.count.saturating_add(rounds).min(self.capacity);
+    }
+}
+impl Component for Ammunition {}
+
+/// Helper for the registers; count and capacity of the ammunition, negative if unlimited.
+pub fn ammunition_registers(world: &World, entity: EntityId) -> (i32, i32) {
+    world
+        .component::<Ammunition>(entity)
+        .map(|a| (a.count() as i32, a.capacity() as i32))
+        .unwrap_or((-1, -1))
+}
+
+/// Whether the weapon on this entity has a round to fire.
+pub fn has_ammunition(world: &World, entity: EntityId) -> bool {
+    world
+        .component::<Ammunition>(entity)
+        .map(|a| !a.is_empty())
+        .unwrap_or(true)
+}
+
+/// Ammunition capacity per unit type, if present in the world it is applied to the units that are
+/// spawned. Unit types without a capacity have unlimited ammunition.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct AmmunitionConfig {
+    /// Rounds for the tank's cannon.
+    pub tank: Option<u32>,
+    /// Rounds for the artillery's gun battery, each gun firing uses a round.
+    pub artillery: Option<u32>,
+    /// Missiles for the missile launcher.
+    pub missile_launcher: Option<u32>,
+}
+impl Component for AmmunitionConfig {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_ammunition() {
+        let mut ammunition = Ammunition::new(2);
+        assert!(ammunition.is_full());
+        assert!(ammunition.consume());
+        assert!(ammunition.consume());
+        assert!(ammunition.is_empty());
+        assert!(!ammunition.consume());
+        ammunition.resupply(5);
+        assert_eq!(ammunition.count(), 2);
+    }
+}
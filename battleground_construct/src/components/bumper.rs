@@ -0,0 +1,97 @@
+use super::pose::world_pose;
+use crate::display::primitives::Vec3;
+use engine::prelude::*;
+
+use battleground_unit_control::modules::bumper::*;
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+
+/// Holds the contacts found by the collision system in the current step. Directions point
+/// towards the contact, in world frame.
+#[derive(Debug, Clone, Default)]
+pub struct Bumper {
+    contacts: Vec<(EntityId, Vec3)>,
+    previous: Vec<EntityId>,
+}
+
+impl Bumper {
+    pub fn new() -> Self {
+        Bumper::default()
+    }
+
+    /// Start a new step, remembering the current contacts.
+    pub fn clear(&mut self) {
+        self.previous = self.contacts.iter().map(|v| v.0).collect();
+        self.contacts.clear();
+    }
+
+    pub fn add_contact(&mut self, entity: EntityId, direction: Vec3) {
+        self.contacts.push((entity, direction));
+    }
+
+    /// Whether the contact with this entity didn't exist in the previous step.
+    pub fn is_new_contact(&self, entity: EntityId) -> bool {
+        !self.previous.contains(&entity)
+    }
+
+    pub fn contacts(&self) -> &[(EntityId, Vec3)] {
+        &self.contacts[..]
+    }
+
+    pub fn in_contact(&self) -> bool {
+        !self.contacts.is_empty()
+    }
+
+    /// Average direction towards all contacts, zero if there are none.
+    pub fn direction(&self) -> Vec3 {
+        use cgmath::InnerSpace;
+        let sum = self
+            .contacts
+            .iter()
+            .fold(Vec3::new(0.0, 0.0, 0.0), |s, (_, d)| s + d);
+        if sum.magnitude2() > 0.0 {
+            sum.normalize()
+        } else {
+            sum
+        }
+    }
+}
+impl Component for Bumper {}
+
+pub struct BumperModule {
+    entity: EntityId,
+}
+
+impl BumperModule {
+    pub fn new(entity: EntityId) -> Self {
+        BumperModule { entity }
+    }
+}
+
+impl UnitModule for BumperModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        use crate::util::cgmath::InvertHomogeneous;
+        registers.clear();
+        if let Some(bumper) = world.component::<Bumper>(self.entity) {
+            // Express the direction in the local frame.
+            let pose = world_pose(world, self.entity);
+            let direction = (pose.to_inv_h() * bumper.direction().extend(0.0)).truncate();
+            registers.insert(
+                REG_BUMPER_IN_CONTACT,
+                Register::new_i32("in_contact", bumper.in_contact() as i32),
+            );
+            registers.insert(
+                REG_BUMPER_CONTACT_COUNT,
+                Register::new_i32("contact_count", bumper.contacts().len() as i32),
+            );
+            registers.insert(
+                REG_BUMPER_DIRECTION_X,
+                Register::new_f32("direction_x", direction.x),
+            );
+            registers.insert(
+                REG_BUMPER_DIRECTION_Y,
+                Register::new_f32("direction_y", direction.y),
+            );
+        }
+    }
+}
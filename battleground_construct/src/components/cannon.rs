@@ -14,6 +14,7 @@ pub struct Cannon {
     last_fire_time: f32,
     is_ready: bool,
     is_triggered: bool,
+    reload_scale: f32,
     config: CannonConfig,
 }
 
@@ -23,10 +24,21 @@ impl Cannon {
             last_fire_time: -2.0, // spawn ready to fire.
             is_ready: true,
             is_triggered: false,
+            reload_scale: 1.0,
             config,
         }
     }
 
+    /// The effective reload time of the cannon.
+    pub fn reload_time(&self) -> f32 {
+        self.config.reload_time * self.reload_scale
+    }
+
+    /// Scale the reload time, used to degrade the cannon when it is damaged.
+    pub fn set_reload_scale(&mut self, scale: f32) {
+        self.reload_scale = scale;
+    }
+
     pub fn is_triggered(&self) -> bool {
         self.is_triggered
     }
@@ -40,7 +52,7 @@ impl Cannon {
     }
 
     pub fn update(&mut self, current_time: f32) {
-        self.is_ready = (current_time - self.last_fire_time) > self.config.reload_time
+        self.is_ready = (current_time - self.last_fire_time) > self.reload_time()
     }
 
     pub fn fired(&mut self, current_time: f32) {
@@ -78,13 +90,20 @@ impl UnitModule for CannonModule {
                 REG_CANNON_IS_TRIGGERED,
                 Register::new_i32("is_triggered", cannon.is_triggered() as i32),
             );
+            let ready = cannon.is_ready() && super::ammunition::has_ammunition(world, self.entity);
+            registers.insert(REG_CANNON_READY, Register::new_i32("ready", ready as i32));
             registers.insert(
-                REG_CANNON_READY,
-                Register::new_i32("ready", cannon.is_ready() as i32),
+                REG_CANNON_RELOAD_TIME,
+                Register::new_f32("reload_time", cannon.reload_time()),
             );
+            let (count, capacity) = super::ammunition::ammunition_registers(world, self.entity);
             registers.insert(
-                REG_CANNON_RELOAD_TIME,
-                Register::new_f32("reload_time", cannon.config.reload_time),
+                REG_CANNON_AMMUNITION,
+                Register::new_i32("ammunition", count),
+            );
+            registers.insert(
+                REG_CANNON_AMMUNITION_CAPACITY,
+                Register::new_i32("ammunition_capacity", capacity),
             );
         }
     }
@@ -0,0 +1,69 @@
+use super::hit_box::HitBox;
+use super::hit_collection::HitCollection;
+use crate::display::primitives::Mat4;
+use crate::display::primitives::Vec3;
+use crate::util::box_collision::AxisAlignedBox;
+use engine::prelude::*;
+
+/// Boxes that prevent entities from moving through each other. Entities that have a velocity are
+/// pushed out of any penetration, entities without velocity are considered static.
+#[derive(Debug, Clone)]
+pub struct Collider {
+    boxes: Vec<(Mat4, HitBox)>,
+}
+
+impl Collider {
+    pub fn from_hit_box(transform: Mat4, hit_box: HitBox) -> Self {
+        Collider {
+            boxes: vec![(transform, hit_box)],
+        }
+    }
+
+    pub fn from_hit_collection(collection: &HitCollection) -> Self {
+        Collider {
+            boxes: collection.hit_boxes().to_vec(),
+        }
+    }
+
+    pub fn boxes(&self) -> &[(Mat4, HitBox)] {
+        &self.boxes[..]
+    }
+
+    /// Returns the largest translation in the xy plane required to move this collider out of the
+    /// other collider, None if they don't touch.
+    pub fn penetration(
+        &self,
+        transform: Mat4,
+        other: &Collider,
+        other_transform: Mat4,
+    ) -> Option<Vec3> {
+        use cgmath::InnerSpace;
+        let mut largest: Option<Vec3> = None;
+        for (box_transform, hit_box) in self.boxes.iter() {
+            let a = AxisAlignedBox::new(hit_box.length(), hit_box.width(), hit_box.height());
+            for (other_box_transform, other_hit_box) in other.boxes.iter() {
+                let b = AxisAlignedBox::new(
+                    other_hit_box.length(),
+                    other_hit_box.width(),
+                    other_hit_box.height(),
+                );
+                let p = a.penetration_planar(
+                    transform * box_transform,
+                    &b,
+                    other_transform * other_box_transform,
+                    Vec3::new(0.0, 0.0, 1.0),
+                );
+                if let Some(p) = p {
+                    if largest
+                        .map(|v| p.magnitude2() > v.magnitude2())
+                        .unwrap_or(true)
+                    {
+                        largest = Some(p);
+                    }
+                }
+            }
+        }
+        largest
+    }
+}
+impl Component for Collider {}
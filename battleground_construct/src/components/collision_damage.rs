@@ -0,0 +1,24 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for damage caused by collisions, if present in the world, units take damage when
+/// they bump into something.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct CollisionDamage {
+    /// Damage per m/s of closing speed at the moment of impact.
+    pub damage_per_speed: f32,
+    /// Closing speeds below this value do not cause any damage.
+    pub minimum_speed: f32,
+}
+
+impl CollisionDamage {
+    /// Damage to apply for a collision at this closing speed.
+    pub fn damage(&self, closing_speed: f32) -> f32 {
+        if closing_speed < self.minimum_speed {
+            0.0
+        } else {
+            closing_speed * self.damage_per_speed
+        }
+    }
+}
+impl Component for CollisionDamage {}
@@ -0,0 +1,365 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The structures a constructor can build.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureType {
+    Wall,
+    RadarTower,
+    RepairStation,
+}
+
+impl StructureType {
+    pub const ALL: [StructureType; 3] = [
+        StructureType::Wall,
+        StructureType::RadarTower,
+        StructureType::RepairStation,
+    ];
+}
+
+use battleground_unit_control::modules::constructor::*;
+impl TryFrom<i32> for StructureType {
+    type Error = String;
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            STRUCTURE_WALL => Ok(StructureType::Wall),
+            STRUCTURE_RADAR_TOWER => Ok(StructureType::RadarTower),
+            STRUCTURE_REPAIR_STATION => Ok(StructureType::RepairStation),
+            v => Err(format!("{v} is not a structure type")),
+        }
+    }
+}
+
+impl From<StructureType> for i32 {
+    fn from(value: StructureType) -> i32 {
+        match value {
+            StructureType::Wall => STRUCTURE_WALL,
+            StructureType::RadarTower => STRUCTURE_RADAR_TOWER,
+            StructureType::RepairStation => STRUCTURE_REPAIR_STATION,
+        }
+    }
+}
+
+/// Cost and build time of a structure.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct StructureCost {
+    /// Deducted from the team budget when the build starts.
+    pub cost: f32,
+    /// Duration the constructor has to be deployed to finish the build, in seconds.
+    pub build_time: f32,
+}
+
+/// Costs of the structures, if present in the world it is used by all constructors.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct ConstructionConfig {
+    pub wall: StructureCost,
+    pub radar_tower: StructureCost,
+    pub repair_station: StructureCost,
+}
+
+impl Default for ConstructionConfig {
+    fn default() -> Self {
+        ConstructionConfig {
+            wall: StructureCost {
+                cost: 10.0,
+                build_time: 5.0,
+            },
+            radar_tower: StructureCost {
+                cost: 30.0,
+                build_time: 10.0,
+            },
+            repair_station: StructureCost {
+                cost: 50.0,
+                build_time: 15.0,
+            },
+        }
+    }
+}
+
+impl ConstructionConfig {
+    pub fn cost(&self, structure: StructureType) -> StructureCost {
+        match structure {
+            StructureType::Wall => self.wall,
+            StructureType::RadarTower => self.radar_tower,
+            StructureType::RepairStation => self.repair_station,
+        }
+    }
+}
+impl Component for ConstructionConfig {}
+
+/// Outcome of the last build request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildStatus {
+    Idle,
+    Building,
+    InsufficientBudget,
+    NotDeployed,
+    Occupied,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BuildJob {
+    pub structure: StructureType,
+    pub cost: StructureCost,
+    pub elapsed: f32,
+}
+
+impl BuildJob {
+    pub fn progress(&self) -> f32 {
+        (self.elapsed / self.cost.build_time).min(1.0)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.cost.build_time
+    }
+}
+
+/// Build state of a constructor, lives on the same entity as the deploy component.
+#[derive(Debug, Clone, Copy)]
+pub struct Construction {
+    job: Option<BuildJob>,
+    status: BuildStatus,
+    /// Request from the controller, `Some(None)` requests cancelling the build.
+    request: Option<Option<StructureType>>,
+    costs: ConstructionConfig,
+    budget: Option<f32>,
+    site_entity: EntityId,
+    tower_radar: Option<EntityId>,
+}
+
+impl Construction {
+    /// Create a construction component, structures are placed relative to the site entity.
+    pub fn new(costs: ConstructionConfig, site_entity: EntityId) -> Self {
+        Construction {
+            job: None,
+            status: BuildStatus::Idle,
+            request: None,
+            costs,
+            budget: None,
+            site_entity,
+            tower_radar: None,
+        }
+    }
+
+    pub fn site_entity(&self) -> EntityId {
+        self.site_entity
+    }
+
+    pub fn job(&self) -> Option<BuildJob> {
+        self.job
+    }
+
+    pub fn set_job(&mut self, job: Option<BuildJob>) {
+        self.job = job;
+    }
+
+    pub fn status(&self) -> BuildStatus {
+        self.status
+    }
+
+    pub fn set_status(&mut self, status: BuildStatus) {
+        self.status = status;
+    }
+
+    pub fn costs(&self) -> &ConstructionConfig {
+        &self.costs
+    }
+
+    /// Request a structure to be built, or the current build to be cancelled if None.
+    pub fn request(&mut self, structure: Option<StructureType>) {
+        self.request = Some(structure);
+    }
+
+    pub fn take_request(&mut self) -> Option<Option<StructureType>> {
+        self.request.take()
+    }
+
+    /// The budget of the team as of the last update, None if unlimited.
+    pub fn budget(&self) -> Option<f32> {
+        self.budget
+    }
+
+    pub fn set_budget(&mut self, budget: Option<f32>) {
+        self.budget = budget;
+    }
+
+    /// The radar entity of the radar tower most recently built by this constructor.
+    pub fn tower_radar(&self) -> Option<EntityId> {
+        self.tower_radar
+    }
+
+    pub fn set_tower_radar(&mut self, radar_entity: Option<EntityId>) {
+        self.tower_radar = radar_entity;
+    }
+}
+impl Component for Construction {}
+
+/// The resources a team has to spend on structures, lives on the team entity. Teams without a
+/// budget have unlimited resources.
+#[derive(Debug, Clone, Copy)]
+pub struct TeamBudget {
+    remaining: f32,
+}
+
+impl TeamBudget {
+    pub fn new(remaining: f32) -> Self {
+        TeamBudget { remaining }
+    }
+
+    pub fn remaining(&self) -> f32 {
+        self.remaining
+    }
+
+    /// Spend the amount if sufficient budget remains, returns whether it was spent.
+    pub fn spend(&mut self, amount: f32) -> bool {
+        if amount <= self.remaining {
+            self.remaining -= amount;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn refund(&mut self, amount: f32) {
+        self.remaining += amount;
+    }
+}
+impl Component for TeamBudget {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+
+pub struct ConstructionModule {
+    entity: EntityId,
+}
+
+impl ConstructionModule {
+    pub fn new(entity: EntityId) -> Self {
+        ConstructionModule { entity }
+    }
+}
+
+impl UnitModule for ConstructionModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(construction) = world.component::<Construction>(self.entity) {
+            let job = construction.job();
+            let building = job.map(|j| j.structure.into()).unwrap_or(STRUCTURE_NONE);
+            registers.insert(REG_CONSTRUCTOR_BUILD, Register::new_i32("build", building));
+            registers.insert(
+                REG_CONSTRUCTOR_BUILD_PROGRESS,
+                Register::new_f32("build_progress", job.map(|j| j.progress()).unwrap_or(0.0)),
+            );
+            let status = match construction.status() {
+                BuildStatus::Idle => BUILD_STATUS_IDLE,
+                BuildStatus::Building => BUILD_STATUS_BUILDING,
+                BuildStatus::InsufficientBudget => BUILD_STATUS_INSUFFICIENT_BUDGET,
+                BuildStatus::NotDeployed => BUILD_STATUS_NOT_DEPLOYED,
+                BuildStatus::Occupied => BUILD_STATUS_OCCUPIED,
+            };
+            registers.insert(
+                REG_CONSTRUCTOR_BUILD_STATUS,
+                Register::new_i32("build_status", status),
+            );
+            registers.insert(
+                REG_CONSTRUCTOR_BUDGET,
+                Register::new_f32("budget", construction.budget().unwrap_or(-1.0)),
+            );
+
+            registers.insert(
+                REG_CONSTRUCTOR_STRUCTURE_COUNT,
+                Register::new_i32("structure_count", StructureType::ALL.len() as i32),
+            );
+            for structure in StructureType::ALL {
+                let cost = construction.costs().cost(structure);
+                let start = REG_CONSTRUCTOR_STRUCTURE_START
+                    + i32::from(structure) as u32 * REG_CONSTRUCTOR_STRUCTURE_STRIDE;
+                registers.insert(
+                    start + REG_CONSTRUCTOR_STRUCTURE_OFFSET_COST,
+                    Register::new_f32("cost", cost.cost),
+                );
+                registers.insert(
+                    start + REG_CONSTRUCTOR_STRUCTURE_OFFSET_BUILD_TIME,
+                    Register::new_f32("build_time", cost.build_time),
+                );
+            }
+        }
+    }
+
+    fn set_component(&self, world: &mut World, registers: &RegisterMap) {
+        if let Some(mut construction) = world.component_mut::<Construction>(self.entity) {
+            let requested = registers
+                .get(&REG_CONSTRUCTOR_BUILD)
+                .expect("register doesnt exist")
+                .value_i32()
+                .expect("wrong value type");
+            let current = construction
+                .job()
+                .map(|j| j.structure.into())
+                .unwrap_or(STRUCTURE_NONE);
+            if requested == current {
+                return;
+            }
+            if requested == STRUCTURE_NONE {
+                construction.request(None);
+            } else if let Ok(structure) = StructureType::try_from(requested) {
+                construction.request(Some(structure));
+            }
+        }
+    }
+}
+
+/// Exposes the radar of the last radar tower built by the constructor.
+pub struct TowerRadarModule {
+    entity: EntityId,
+}
+
+impl TowerRadarModule {
+    pub fn new(entity: EntityId) -> Self {
+        TowerRadarModule { entity }
+    }
+}
+
+impl UnitModule for TowerRadarModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        use crate::components::radar::{Radar, RadarModule};
+        use crate::util::cgmath::prelude::*;
+        use battleground_unit_control::modules::tower_radar::*;
+        registers.clear();
+        let radar_entity = world
+            .component::<Construction>(self.entity)
+            .and_then(|c| c.tower_radar())
+            .filter(|e| world.component::<Radar>(*e).is_some());
+        registers.insert(
+            REG_TOWER_RADAR_PRESENT,
+            Register::new_i32("present", radar_entity.is_some() as i32),
+        );
+        if let Some(radar_entity) = radar_entity {
+            let pose = crate::components::pose::world_pose(world, radar_entity);
+            registers.insert(REG_TOWER_RADAR_X, Register::new_f32("x", pose.w.x));
+            registers.insert(REG_TOWER_RADAR_Y, Register::new_f32("y", pose.w.y));
+            registers.insert(REG_TOWER_RADAR_Z, Register::new_f32("z", pose.w.z));
+            registers.insert(
+                REG_TOWER_RADAR_YAW,
+                Register::new_f32("yaw", pose.to_rpy().z),
+            );
+            let mut radar_registers = RegisterMap::new();
+            RadarModule::new(radar_entity).get_registers(world, &mut radar_registers);
+            registers.extend(radar_registers);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_team_budget() {
+        let mut budget = TeamBudget::new(25.0);
+        assert!(budget.spend(10.0));
+        assert!(!budget.spend(20.0));
+        assert_eq!(budget.remaining(), 15.0);
+        budget.refund(10.0);
+        assert_eq!(budget.remaining(), 25.0);
+    }
+}
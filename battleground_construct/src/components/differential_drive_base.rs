@@ -26,7 +26,14 @@ pub struct DifferentialDriveBase {
     #[serde(skip)]
     wheel_velocity_cmd: (f32, f32),
     wheel_velocity_vel: (f32, f32),
+    #[serde(skip, default = "default_velocity_scale")]
+    velocity_scale: f32,
 }
+
+fn default_velocity_scale() -> f32 {
+    1.0
+}
+
 impl Default for DifferentialDriveBase {
     fn default() -> Self {
         DifferentialDriveBase::new()
@@ -43,22 +50,23 @@ impl DifferentialDriveBase {
             config,
             wheel_velocity_cmd: (0.0, 0.0),
             wheel_velocity_vel: (0.0, 0.0),
+            velocity_scale: 1.0,
         }
     }
 
     pub fn set_velocities(&mut self, left: f32, right: f32) {
+        let bounds = self.wheel_velocity_bounds();
         self.wheel_velocity_cmd = (
-            left.clamp(
-                self.config.wheel_velocity_bounds.0,
-                self.config.wheel_velocity_bounds.1,
-            ),
-            right.clamp(
-                self.config.wheel_velocity_bounds.0,
-                self.config.wheel_velocity_bounds.1,
-            ),
+            left.clamp(bounds.0, bounds.1),
+            right.clamp(bounds.0, bounds.1),
         );
     }
 
+    /// Scale the velocity bounds, used to degrade the base when its tracks are damaged.
+    pub fn set_velocity_scale(&mut self, scale: f32) {
+        self.velocity_scale = scale;
+    }
+
     pub fn track_width(&self) -> f32 {
         self.config.track_width
     }
@@ -75,7 +83,10 @@ impl DifferentialDriveBase {
     }
 
     pub fn wheel_velocity_bounds(self) -> (f32, f32) {
-        self.config.wheel_velocity_bounds
+        (
+            self.config.wheel_velocity_bounds.0 * self.velocity_scale,
+            self.config.wheel_velocity_bounds.1 * self.velocity_scale,
+        )
     }
 
     pub fn wheel_acceleration_bounds(&self) -> Option<(f32, f32)> {
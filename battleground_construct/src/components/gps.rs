@@ -1,8 +1,130 @@
 use super::pose::world_pose;
+use super::random::Random;
+use crate::display::primitives::{Mat4, Vec3};
 use engine::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use battleground_unit_control::modules::gps::*;
 
+/// A circular zone in which there is no gps reception.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct GpsOutage {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+}
+
+impl GpsOutage {
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        (x - self.x).powi(2) + (y - self.y).powi(2) <= self.radius.powi(2)
+    }
+}
+
+/// Error model of the gps, if present in the world it applies to all gps modules. The default is
+/// a perfect gps.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct GpsConfig {
+    /// Standard deviation of the gaussian noise on the position, meters.
+    pub position_stddev: f32,
+    /// Standard deviation of the gaussian noise on the roll, pitch and yaw, radians.
+    pub orientation_stddev: f32,
+    /// Standard deviation of the constant horizontal position bias, drawn once for each unit.
+    pub bias_stddev: f32,
+    /// Time between fixes in seconds, zero provides a fix every step.
+    pub update_interval: f32,
+    /// Zones without gps reception.
+    pub outages: Vec<GpsOutage>,
+}
+impl Component for GpsConfig {}
+
+/// The last reading of a gps, updated by the gps update system.
+#[derive(Debug, Clone)]
+pub struct Gps {
+    bias: Option<Vec3>,
+    position: Vec3,
+    orientation: Vec3,
+    valid: bool,
+    fix_time: Option<f32>,
+}
+
+impl Default for Gps {
+    fn default() -> Self {
+        Gps::new()
+    }
+}
+
+impl Gps {
+    pub fn new() -> Self {
+        Gps {
+            bias: None,
+            position: Vec3::new(0.0, 0.0, 0.0),
+            orientation: Vec3::new(0.0, 0.0, 0.0),
+            valid: false,
+            fix_time: None,
+        }
+    }
+
+    /// Update the reading with the true pose of the gps at this time.
+    pub fn update(&mut self, config: &GpsConfig, time: f32, pose: &Mat4, random: &mut Random) {
+        use crate::util::cgmath::prelude::*;
+        let position = pose.to_translation();
+        if config
+            .outages
+            .iter()
+            .any(|o| o.contains(position.x, position.y))
+        {
+            self.valid = false;
+            return;
+        }
+
+        let due = self
+            .fix_time
+            .map(|t| (time - t) >= config.update_interval)
+            .unwrap_or(true);
+        if self.valid && !due {
+            return;
+        }
+
+        let bias = *self.bias.get_or_insert_with(|| {
+            Vec3::new(
+                random.normal(config.bias_stddev),
+                random.normal(config.bias_stddev),
+                0.0,
+            )
+        });
+        let mut noise = |stddev: f32| {
+            Vec3::new(
+                random.normal(stddev),
+                random.normal(stddev),
+                random.normal(stddev),
+            )
+        };
+        self.position = position + bias + noise(config.position_stddev);
+        self.orientation = pose.to_rpy() + noise(config.orientation_stddev);
+        self.valid = true;
+        self.fix_time = Some(time);
+    }
+
+    pub fn position(&self) -> Vec3 {
+        self.position
+    }
+
+    /// Roll, pitch and yaw.
+    pub fn orientation(&self) -> Vec3 {
+        self.orientation
+    }
+
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    pub fn fix_time(&self) -> f32 {
+        self.fix_time.unwrap_or(0.0)
+    }
+}
+impl Component for Gps {}
+
 use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
 pub struct GpsModule {
     entity: EntityId,
@@ -18,15 +140,93 @@ impl UnitModule for GpsModule {
     fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
         use crate::util::cgmath::ToRollPitchYaw;
         use crate::util::cgmath::ToTranslation;
-        let pose = world_pose(world, self.entity);
-        let translation = pose.to_translation();
+        let (translation, rpy, valid, fix_time) =
+            if let Some(gps) = world.component::<Gps>(self.entity) {
+                (
+                    gps.position(),
+                    gps.orientation(),
+                    gps.valid(),
+                    gps.fix_time(),
+                )
+            } else {
+                // No gps reading, provide the ground truth.
+                let pose = world_pose(world, self.entity);
+                let elapsed = world
+                    .component_iter::<super::clock::Clock>()
+                    .next()
+                    .map(|(_, c)| c.elapsed_as_f32())
+                    .unwrap_or(0.0);
+                (pose.to_translation(), pose.to_rpy(), true, elapsed)
+            };
         registers.insert(REG_GPS_X, Register::new_f32("x", translation.x));
         registers.insert(REG_GPS_Y, Register::new_f32("y", translation.y));
         registers.insert(REG_GPS_Z, Register::new_f32("z", translation.z));
 
-        let rpy = pose.to_rpy();
         registers.insert(REG_GPS_ROLL, Register::new_f32("r", rpy.x));
         registers.insert(REG_GPS_PITCH, Register::new_f32("p", rpy.y));
         registers.insert(REG_GPS_YAW, Register::new_f32("y", rpy.z));
+
+        registers.insert(REG_GPS_VALID, Register::new_i32("valid", valid as i32));
+        registers.insert(REG_GPS_FIX_TIME, Register::new_f32("fix_time", fix_time));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::test_util::approx_equal;
+
+    #[test]
+    fn test_gps_update() {
+        let mut random = Random::new(0);
+        let pose = Mat4::from_translation(Vec3::new(1.0, 2.0, 0.0));
+
+        // Perfect gps gives the truth every step.
+        let mut gps = Gps::new();
+        let perfect = GpsConfig::default();
+        gps.update(&perfect, 0.001, &pose, &mut random);
+        assert!(gps.valid());
+        assert_eq!(gps.position(), Vec3::new(1.0, 2.0, 0.0));
+
+        // Limited rate only updates after the interval.
+        let config = GpsConfig {
+            update_interval: 1.0,
+            outages: vec![GpsOutage {
+                x: 10.0,
+                y: 0.0,
+                radius: 2.0,
+            }],
+            ..Default::default()
+        };
+        let moved = Mat4::from_translation(Vec3::new(3.0, 2.0, 0.0));
+        gps.update(&config, 0.5, &moved, &mut random);
+        approx_equal!(gps.position().x, 1.0, 0.0001);
+        gps.update(&config, 1.001, &moved, &mut random);
+        approx_equal!(gps.position().x, 3.0, 0.0001);
+        approx_equal!(gps.fix_time(), 1.001, 0.0001);
+
+        // In the outage zone there's no reception, the last fix is retained.
+        let outage = Mat4::from_translation(Vec3::new(9.0, 0.0, 0.0));
+        gps.update(&config, 3.0, &outage, &mut random);
+        assert!(!gps.valid());
+        approx_equal!(gps.position().x, 3.0, 0.0001);
+
+        // Reception is regained immediately after leaving the zone.
+        let out = Mat4::from_translation(Vec3::new(5.0, 0.0, 0.0));
+        gps.update(&config, 3.1, &out, &mut random);
+        assert!(gps.valid());
+        approx_equal!(gps.position().x, 5.0, 0.0001);
+
+        // Bias is constant for a gps.
+        let config = GpsConfig {
+            bias_stddev: 1.0,
+            ..Default::default()
+        };
+        let mut gps = Gps::new();
+        gps.update(&config, 0.0, &pose, &mut random);
+        let offset = gps.position() - Vec3::new(1.0, 2.0, 0.0);
+        assert!(offset.x != 0.0);
+        gps.update(&config, 1.0, &moved, &mut random);
+        assert_eq!(gps.position() - Vec3::new(3.0, 2.0, 0.0), offset);
     }
 }
@@ -51,6 +51,7 @@ pub struct GunBattery {
     last_in_battery_fire_time: f32,
     is_triggered: bool,
     is_ready: bool,
+    reload_scale: f32,
     status: Vec<GunStatus>,
 }
 
@@ -71,10 +72,26 @@ impl GunBattery {
             config,
             is_triggered: false,
             is_ready: true,
+            reload_scale: 1.0,
             status,
         }
     }
 
+    /// The effective reload duration of an individual gun.
+    pub fn gun_reload(&self) -> f32 {
+        self.config.gun_reload * self.reload_scale
+    }
+
+    /// The effective reload duration of the battery.
+    pub fn battery_reload(&self) -> f32 {
+        self.config.battery_reload * self.reload_scale
+    }
+
+    /// Scale the reload durations, used to degrade the battery when it is damaged.
+    pub fn set_reload_scale(&mut self, scale: f32) {
+        self.reload_scale = scale;
+    }
+
     pub fn is_triggered(&self) -> bool {
         self.is_triggered
     }
@@ -91,12 +108,12 @@ impl GunBattery {
         let gun_interval_done =
             (current_time - self.last_gun_fire_time) >= self.config.inter_gun_duration;
         let battery_reload_done =
-            (current_time - self.last_in_battery_fire_time) >= self.config.battery_reload;
+            (current_time - self.last_in_battery_fire_time) >= self.battery_reload();
 
+        let gun_reload = self.gun_reload();
         let mut at_least_one_gun_loaded = false;
         for gun_status in self.status.iter_mut() {
-            gun_status.is_ready =
-                (current_time - gun_status.last_fire_time) >= self.config.gun_reload;
+            gun_status.is_ready = (current_time - gun_status.last_fire_time) >= gun_reload;
             if gun_status.is_ready {
                 at_least_one_gun_loaded = true;
             }
@@ -267,13 +284,15 @@ impl UnitModule for GunBatteryModule {
                 REG_GUN_BATTERY_IS_TRIGGERED,
                 Register::new_i32("is_triggered", gun_battery.is_triggered() as i32),
             );
+            let ready =
+                gun_battery.is_ready() && super::ammunition::has_ammunition(world, self.entity);
             registers.insert(
                 REG_GUN_BATTERY_READY,
-                Register::new_i32("ready", gun_battery.is_ready() as i32),
+                Register::new_i32("ready", ready as i32),
             );
             registers.insert(
                 REG_GUN_BATTERY_GUN_RELOAD,
-                Register::new_f32("gun_reload", gun_battery.config.gun_reload),
+                Register::new_f32("gun_reload", gun_battery.gun_reload()),
             );
             registers.insert(
                 REG_GUN_BATTERY_INTER_GUN_DURATION,
@@ -281,12 +300,21 @@ impl UnitModule for GunBatteryModule {
             );
             registers.insert(
                 REG_GUN_BATTERY_RELOAD,
-                Register::new_f32("battery_reload", gun_battery.config.battery_reload),
+                Register::new_f32("battery_reload", gun_battery.battery_reload()),
             );
             registers.insert(
                 REG_GUN_BATTERY_FIRE_INDEX,
                 Register::new_i32("fire_index", gun_battery.gun_index() as i32),
             );
+            let (count, capacity) = super::ammunition::ammunition_registers(world, self.entity);
+            registers.insert(
+                REG_GUN_BATTERY_AMMUNITION,
+                Register::new_i32("ammunition", count),
+            );
+            registers.insert(
+                REG_GUN_BATTERY_AMMUNITION_CAPACITY,
+                Register::new_i32("ammunition_capacity", capacity),
+            );
 
             registers.insert(
                 REG_GUN_BATTERY_COUNT,
@@ -25,6 +25,17 @@ impl Health {
         self.health
     }
 
+    /// Restore health, up to full health. Destroyed units can't be repaired. Returns the amount
+    /// that was actually restored.
+    pub fn add(&mut self, value: f32) -> f32 {
+        if self.is_destroyed() {
+            return 0.0;
+        }
+        let restored = value.min(1.0 - self.health).max(0.0);
+        self.health += restored;
+        restored
+    }
+
     pub fn is_destroyed(&self) -> bool {
         self.health <= 0.0
     }
@@ -46,6 +46,13 @@ impl HitBy {
         });
     }
 
+    /// Multiply the damage of each hit by the factor returned for its impact.
+    pub fn scale_damage<F: Fn(&Impact) -> f32>(&mut self, factor: F) {
+        for hit in self.hits.iter_mut() {
+            hit.damage *= factor(&hit.impact);
+        }
+    }
+
     pub fn hits(&self) -> Vec<(f32, &Impact)> {
         self.hits
             .iter()
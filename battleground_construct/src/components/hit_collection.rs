@@ -72,8 +72,45 @@ impl HitCollection {
         distance
     }
 
+    /// Check if the line segment between two points intersects any of the hit boxes.
+    pub fn is_intersecting(&self, collection_transform: Mat4, p0: Vec3, p1: Vec3) -> bool {
+        for (hitbox_transform, hitbox) in self.hit_boxes.iter() {
+            let hitbox_pose_inv = (collection_transform * hitbox_transform).to_inv_h();
+            let p0_in_hitbox_frame =
+                (hitbox_pose_inv * Mat4::from_translation(p0)).to_translation();
+            let p1_in_hitbox_frame =
+                (hitbox_pose_inv * Mat4::from_translation(p1)).to_translation();
+            let b = AxisAlignedBox::new(hitbox.length(), hitbox.width(), hitbox.height());
+            if b.is_intersecting(p0_in_hitbox_frame, p1_in_hitbox_frame) {
+                return true;
+            }
+        }
+        false
+    }
+
     pub fn hit_boxes(&self) -> &[(Mat4, HitBox)] {
         &self.hit_boxes[..]
     }
 }
 impl Component for HitCollection {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cgmath::vec3;
+
+    #[test]
+    fn test_is_intersecting() {
+        let collection = HitCollection::from_hit_boxes(&[(
+            Mat4::from_translation(vec3(0.0, 0.0, 1.0)),
+            HitBox::new(1.0, 1.0, 2.0),
+        )]);
+        let transform = Mat4::from_translation(vec3(5.0, 0.0, 0.0));
+        // Passing straight through.
+        assert!(collection.is_intersecting(transform, vec3(0.0, 0.0, 1.0), vec3(10.0, 0.0, 1.0)));
+        // Passing over the top.
+        assert!(!collection.is_intersecting(transform, vec3(0.0, 0.0, 2.5), vec3(10.0, 0.0, 2.5)));
+        // Ending before reaching it.
+        assert!(!collection.is_intersecting(transform, vec3(0.0, 0.0, 1.0), vec3(4.0, 0.0, 1.0)));
+    }
+}
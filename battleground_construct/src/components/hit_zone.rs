@@ -0,0 +1,98 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The subsystems of a unit that can be damaged individually.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Subsystem {
+    /// The drive base, degrades maximum velocity.
+    Tracks,
+    /// The radar, degrades radar range.
+    Radar,
+    /// The weapon, degrades the reload time.
+    Weapon,
+}
+
+/// Describes the armor of the entity it is attached to and the subsystem it belongs to.
+///
+/// Damage to the entity is multiplied by the front, side or rear multiplier, depending on where the
+/// impact is in the entity's local frame; the positive x axis is the front. The front covers
+/// the 90 degree cone around the positive x axis, the rear the 90 degree cone around the negative
+/// x axis, the remainder is the side.
+#[derive(Debug, Clone, Copy)]
+pub struct HitZone {
+    pub front: f32,
+    pub side: f32,
+    pub rear: f32,
+    pub subsystem: Option<Subsystem>,
+}
+
+impl Default for HitZone {
+    fn default() -> Self {
+        HitZone::uniform(1.0)
+    }
+}
+
+impl HitZone {
+    /// A hit zone with the same multiplier regardless of impact direction.
+    pub fn uniform(multiplier: f32) -> Self {
+        HitZone {
+            front: multiplier,
+            side: multiplier,
+            rear: multiplier,
+            subsystem: None,
+        }
+    }
+
+    /// A hit zone with directional armor.
+    pub fn directional(front: f32, side: f32, rear: f32) -> Self {
+        HitZone {
+            front,
+            side,
+            rear,
+            subsystem: None,
+        }
+    }
+
+    /// Assign the subsystem this zone belongs to.
+    pub fn with_subsystem(self, subsystem: Subsystem) -> Self {
+        HitZone {
+            subsystem: Some(subsystem),
+            ..self
+        }
+    }
+
+    /// Determine the damage multiplier for an impact at the provided position, expressed in the
+    /// local frame of the entity that owns this hit zone.
+    pub fn multiplier(&self, local_impact: cgmath::Vector3<f32>) -> f32 {
+        let angle = local_impact.y.atan2(local_impact.x).abs();
+        if angle <= std::f32::consts::FRAC_PI_4 {
+            self.front
+        } else if angle >= 3.0 * std::f32::consts::FRAC_PI_4 {
+            self.rear
+        } else {
+            self.side
+        }
+    }
+}
+impl Component for HitZone {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cgmath::vec3;
+
+    #[test]
+    fn test_hit_zone_multiplier() {
+        let zone = HitZone::directional(0.5, 1.0, 2.0);
+        assert_eq!(zone.multiplier(vec3(1.0, 0.0, 0.0)), 0.5);
+        assert_eq!(zone.multiplier(vec3(1.0, 0.9, 0.3)), 0.5);
+        assert_eq!(zone.multiplier(vec3(0.0, 1.0, 0.0)), 1.0);
+        assert_eq!(zone.multiplier(vec3(0.2, -1.0, 0.0)), 1.0);
+        assert_eq!(zone.multiplier(vec3(-1.0, 0.0, 0.0)), 2.0);
+        assert_eq!(zone.multiplier(vec3(-1.0, -0.9, 0.0)), 2.0);
+
+        let zone = HitZone::uniform(0.7).with_subsystem(Subsystem::Tracks);
+        assert_eq!(zone.multiplier(vec3(-1.0, 0.0, 0.0)), 0.7);
+        assert_eq!(zone.subsystem, Some(Subsystem::Tracks));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::display::primitives::{Mat4, Vec3};
+use engine::prelude::*;
+
+/// Gyroscope, tracks the angular rates of the entity it is attached to.
+#[derive(Debug, Clone, Copy)]
+pub struct Imu {
+    previous: Option<Mat4>,
+    rates: Vec3,
+}
+
+impl Default for Imu {
+    fn default() -> Self {
+        Imu::new()
+    }
+}
+
+impl Imu {
+    pub fn new() -> Self {
+        Imu {
+            previous: None,
+            rates: Vec3::new(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Update the rates from the current world pose, dt since the previous update.
+    pub fn update(&mut self, pose: &Mat4, dt: f32) {
+        use crate::util::cgmath::prelude::*;
+        if let Some(previous) = self.previous {
+            // Rotation since the previous step, expressed in the body frame.
+            let delta = previous.to_inv_h() * pose;
+            self.rates = delta.to_rpy() / dt;
+        }
+        self.previous = Some(*pose);
+    }
+
+    /// Roll, pitch and yaw rates in the body frame.
+    pub fn rates(&self) -> Vec3 {
+        self.rates
+    }
+}
+impl Component for Imu {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::imu::*;
+
+pub struct ImuModule {
+    entity: EntityId,
+}
+
+impl ImuModule {
+    pub fn new(entity: EntityId) -> Self {
+        ImuModule { entity }
+    }
+}
+
+impl UnitModule for ImuModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(imu) = world.component::<Imu>(self.entity) {
+            let rates = imu.rates();
+            registers.insert(REG_IMU_ROLL_RATE, Register::new_f32("roll_rate", rates.x));
+            registers.insert(REG_IMU_PITCH_RATE, Register::new_f32("pitch_rate", rates.y));
+            registers.insert(REG_IMU_YAW_RATE, Register::new_f32("yaw_rate", rates.z));
+        }
+    }
+}
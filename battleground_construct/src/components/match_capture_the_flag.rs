@@ -0,0 +1,53 @@
+use crate::components;
+use components::team::TeamId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MatchCaptureTheFlag {
+    points: std::collections::HashMap<TeamId, i64>,
+    point_limit: Option<i64>,
+}
+
+impl MatchCaptureTheFlag {
+    pub fn new(point_limit: Option<i64>) -> Self {
+        Self {
+            point_limit,
+            points: Default::default(),
+        }
+    }
+
+    pub fn get_leader(&self) -> Option<(TeamId, i64)> {
+        self.points
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1))
+            .map(|t| (*t.0, *t.1))
+    }
+
+    pub fn point_limit(&self) -> Option<i64> {
+        self.point_limit
+    }
+
+    /// Number of flags captured by each team.
+    pub fn points(&self) -> Vec<(TeamId, i64)> {
+        let mut v: Vec<(TeamId, i64)> = self.points.iter().map(|(t, s)| (*t, *s)).collect();
+        v.sort_by_key(|a| a.0);
+        v
+    }
+
+    pub fn add_capture(&mut self, team: TeamId) {
+        *self.points.entry(team).or_insert(0) += 1;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        if let Some(limit) = self.point_limit {
+            for (_t, v) in self.points.iter() {
+                if *v >= limit {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+impl Component for MatchCaptureTheFlag {}
@@ -0,0 +1,60 @@
+use crate::components;
+use components::team::TeamId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MatchEscort {
+    attackers: TeamId,
+    defenders: Vec<TeamId>,
+    progress: f32,
+    furthest_progress: f32,
+}
+
+impl MatchEscort {
+    pub fn new(attackers: TeamId, defenders: &[TeamId]) -> Self {
+        Self {
+            attackers,
+            defenders: defenders.to_vec(),
+            progress: 0.0,
+            furthest_progress: 0.0,
+        }
+    }
+
+    pub fn attackers(&self) -> TeamId {
+        self.attackers
+    }
+
+    pub fn defenders(&self) -> &[TeamId] {
+        &self.defenders
+    }
+
+    /// Current progress of the payload, from 0.0 to 1.0.
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Furthest progress the payload reached during the match.
+    pub fn furthest_progress(&self) -> f32 {
+        self.furthest_progress
+    }
+
+    pub fn set_progress(&mut self, progress: f32) {
+        self.progress = progress;
+        self.furthest_progress = self.furthest_progress.max(progress);
+    }
+
+    /// The attackers once the payload reached the end, the defenders as long as it hasn't.
+    pub fn get_leader(&self) -> Option<TeamId> {
+        if self.is_finished() {
+            Some(self.attackers)
+        } else {
+            self.defenders.first().copied()
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress >= 1.0
+    }
+}
+impl Component for MatchEscort {}
@@ -1,9 +1,12 @@
 use super::team::TeamId;
 use engine::prelude::*;
 
+use super::match_capture_the_flag::MatchCaptureTheFlag;
 use super::match_domination::MatchDomination;
+use super::match_escort::MatchEscort;
 use super::match_king_of_the_hill::MatchKingOfTheHill;
 use super::match_team_deathmatch::MatchTeamDeathmatch;
+use super::scoreboard::TeamScore;
 
 use serde::{Deserialize, Serialize};
 
@@ -20,13 +23,15 @@ pub enum ObjectiveReport {
     MatchKingOfTheHill(MatchKingOfTheHill),
     MatchTeamDeathmatch(MatchTeamDeathmatch),
     MatchDomination(MatchDomination),
+    MatchCaptureTheFlag(MatchCaptureTheFlag),
+    MatchEscort(MatchEscort),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MatchReport {
-    /// The winner of the match, if any. Technically, this ought to be a list... to support matches
-    /// ending in a draw, but that just makes things complicated.
-    pub winner: Option<TeamId>,
+    /// Score breakdown and placement of all teams, ordered by placement. Tied teams share their
+    /// placement, a draw for first place has multiple teams ranked first.
+    pub scores: Vec<TeamScore>,
     /// Reports by individual objectives.
     pub reports: Vec<ObjectiveReport>,
     /// Cause of the match finish declaration.
@@ -35,6 +40,27 @@ pub struct MatchReport {
     pub duration: f32,
 }
 
+impl MatchReport {
+    /// The winner of the match, None if no team ranked first on its own.
+    pub fn winner(&self) -> Option<TeamId> {
+        let mut first = self.scores.iter().filter(|s| s.rank == 1);
+        match (first.next(), first.next()) {
+            (Some(winner), None) => Some(winner.team),
+            _ => None,
+        }
+    }
+
+    /// Whether multiple teams share first place.
+    pub fn is_draw(&self) -> bool {
+        self.scores.iter().filter(|s| s.rank == 1).count() > 1
+    }
+
+    /// The placement of the team, starting at 1.
+    pub fn placement(&self, team: TeamId) -> Option<usize> {
+        self.scores.iter().find(|s| s.team == team).map(|s| s.rank)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MatchFinished {
     report: Option<MatchReport>,
@@ -7,6 +7,9 @@ use serde::{Deserialize, Serialize};
 pub struct MatchKingOfTheHill {
     points: std::collections::HashMap<TeamId, f32>,
     point_limit: Option<f32>,
+    /// Set when all other teams are out of units and respawns.
+    #[serde(default)]
+    last_team_standing: Option<TeamId>,
 }
 
 impl MatchKingOfTheHill {
@@ -14,6 +17,7 @@ impl MatchKingOfTheHill {
         Self {
             point_limit,
             points: Default::default(),
+            last_team_standing: None,
         }
     }
 
@@ -23,7 +27,18 @@ impl MatchKingOfTheHill {
         }
     }
 
+    pub fn last_team_standing(&self) -> Option<TeamId> {
+        self.last_team_standing
+    }
+
+    pub fn set_last_team_standing(&mut self, team: Option<TeamId>) {
+        self.last_team_standing = team;
+    }
+
     pub fn is_finished(&self) -> bool {
+        if self.last_team_standing.is_some() {
+            return true;
+        }
         if let Some(limit) = self.point_limit {
             for (_t, v) in self.points.iter() {
                 if *v >= limit {
@@ -35,6 +50,9 @@ impl MatchKingOfTheHill {
     }
 
     pub fn get_leader(&self) -> Option<TeamId> {
+        if self.last_team_standing.is_some() {
+            return self.last_team_standing;
+        }
         self.points
             .iter()
             .max_by(|a, b| a.1.total_cmp(b.1))
@@ -9,10 +9,34 @@ use serde::{Deserialize, Serialize};
 pub struct MatchTeamDeathmatchJustDestroyed;
 impl Component for MatchTeamDeathmatchJustDestroyed {}
 
+/// The team that landed the finishing blow on the destroyed entity, with the frag it is awarded.
+/// Friendly fire subtracts a frag.
+pub fn frag_for_destroyed(world: &World, entity: EntityId) -> Option<(TeamId, i64)> {
+    let this_entity_team = world
+        .component::<components::team_member::TeamMember>(entity)
+        .map(|x| x.team());
+    // There ought to be a hit history on this component, with a last hit.
+    let history = world.component::<components::hit_by::HitByHistory>(entity)?;
+    // and the last hit should have a unit id.
+    let unit_source = history.last()?.source()?;
+    // and there should be a unit entity for that unit id.
+    let unit_entity = components::unit::get_unit_entity(world, unit_source)?;
+    // and that unit entity should have a team member component.
+    let team_member = world.component::<components::team_member::TeamMember>(unit_entity)?;
+    if Some(team_member.team()) == this_entity_team {
+        Some((team_member.team(), -1))
+    } else {
+        Some((team_member.team(), 1))
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MatchTeamDeathmatch {
     points: std::collections::HashMap<TeamId, i64>,
     point_limit: Option<i64>,
+    /// Set when all other teams are out of units and respawns.
+    #[serde(default)]
+    last_team_standing: Option<TeamId>,
 }
 
 impl MatchTeamDeathmatch {
@@ -20,10 +44,14 @@ impl MatchTeamDeathmatch {
         Self {
             point_limit,
             points: Default::default(),
+            last_team_standing: None,
         }
     }
 
     pub fn get_leader(&self) -> Option<(TeamId, i64)> {
+        if let Some(team) = self.last_team_standing {
+            return Some((team, self.points.get(&team).copied().unwrap_or(0)));
+        }
         self.points
             .iter()
             .max_by(|a, b| a.1.cmp(b.1))
@@ -46,7 +74,18 @@ impl MatchTeamDeathmatch {
         }
     }
 
+    pub fn last_team_standing(&self) -> Option<TeamId> {
+        self.last_team_standing
+    }
+
+    pub fn set_last_team_standing(&mut self, team: Option<TeamId>) {
+        self.last_team_standing = team;
+    }
+
     pub fn is_finished(&self) -> bool {
+        if self.last_team_standing.is_some() {
+            return true;
+        }
         if let Some(limit) = self.point_limit {
             for (_t, v) in self.points.iter() {
                 if *v >= limit {
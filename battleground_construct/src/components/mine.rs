@@ -0,0 +1,252 @@
+use super::team::TeamId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Mine properties, if present in the world it is used by all constructors.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct MineConfig {
+    /// Number of mines each constructor carries.
+    pub capacity: u32,
+    /// Deducted from the team budget for every mine that is dropped.
+    pub cost: f32,
+    /// Minimum duration between two mines being dropped by the same constructor, in seconds.
+    pub drop_interval: f32,
+    /// Duration after being dropped before the mine can detonate, in seconds.
+    pub arm_time: f32,
+    /// Units of other teams within this distance detonate the mine.
+    pub trigger_radius: f32,
+    /// Damage at the center of the explosion.
+    pub damage: f32,
+    /// Radius of the explosion.
+    pub splash_radius: f32,
+    /// Radars of other teams only detect the mine within this distance.
+    pub detection_range: f32,
+}
+
+impl Default for MineConfig {
+    fn default() -> Self {
+        MineConfig {
+            capacity: 5,
+            cost: 5.0,
+            drop_interval: 1.0,
+            arm_time: 3.0,
+            trigger_radius: 1.0,
+            damage: 0.5,
+            splash_radius: 2.0,
+            detection_range: 4.0,
+        }
+    }
+}
+impl Component for MineConfig {}
+
+/// A mine that was dropped, it detonates when a unit of another team comes within the radius.
+#[derive(Debug, Clone, Copy)]
+pub struct Mine {
+    team: Option<TeamId>,
+    trigger_radius: f32,
+    armed_at: f32,
+}
+
+impl Mine {
+    /// Create a mine that belongs to the team and can detonate from the armed time onwards.
+    pub fn new(team: Option<TeamId>, trigger_radius: f32, armed_at: f32) -> Self {
+        Mine {
+            team,
+            trigger_radius,
+            armed_at,
+        }
+    }
+
+    pub fn team(&self) -> Option<TeamId> {
+        self.team
+    }
+
+    pub fn trigger_radius(&self) -> f32 {
+        self.trigger_radius
+    }
+
+    pub fn is_armed(&self, current_time: f32) -> bool {
+        current_time >= self.armed_at
+    }
+
+    /// Whether a unit of this team detonates the mine, mines without a team are set off by all.
+    pub fn is_triggered_by(&self, team: Option<TeamId>) -> bool {
+        self.team.is_none() || self.team != team
+    }
+}
+impl Component for Mine {}
+
+/// Outcome of the last drop request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MineLayerStatus {
+    Idle,
+    Dropped,
+    InsufficientBudget,
+    NotDeployed,
+    Empty,
+    NotReady,
+}
+
+/// Mine state of a constructor, lives on the same entity as the deploy component.
+#[derive(Debug, Clone, Copy)]
+pub struct MineLayer {
+    config: MineConfig,
+    mines: u32,
+    status: MineLayerStatus,
+    requested: bool,
+    last_drop: Option<f32>,
+    site_entity: EntityId,
+}
+
+impl MineLayer {
+    /// Create a full mine layer, mines are placed relative to the site entity.
+    pub fn new(config: MineConfig, site_entity: EntityId) -> Self {
+        MineLayer {
+            config,
+            mines: config.capacity,
+            status: MineLayerStatus::Idle,
+            requested: false,
+            last_drop: None,
+            site_entity,
+        }
+    }
+
+    pub fn config(&self) -> &MineConfig {
+        &self.config
+    }
+
+    pub fn site_entity(&self) -> EntityId {
+        self.site_entity
+    }
+
+    pub fn mines(&self) -> u32 {
+        self.mines
+    }
+
+    pub fn status(&self) -> MineLayerStatus {
+        self.status
+    }
+
+    pub fn set_status(&mut self, status: MineLayerStatus) {
+        self.status = status;
+    }
+
+    /// Request a mine to be dropped.
+    pub fn request(&mut self) {
+        self.requested = true;
+    }
+
+    pub fn take_request(&mut self) -> bool {
+        std::mem::take(&mut self.requested)
+    }
+
+    /// Whether the drop interval has passed since the last mine was dropped.
+    pub fn is_ready(&self, current_time: f32) -> bool {
+        self.last_drop
+            .map(|t| (current_time - t) >= self.config.drop_interval)
+            .unwrap_or(true)
+    }
+
+    /// Take a mine for dropping it, returns false if there were none left.
+    pub fn take_mine(&mut self, current_time: f32) -> bool {
+        if self.mines == 0 {
+            return false;
+        }
+        self.mines -= 1;
+        self.last_drop = Some(current_time);
+        true
+    }
+}
+impl Component for MineLayer {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::mine_layer::*;
+
+pub struct MineLayerModule {
+    entity: EntityId,
+}
+
+impl MineLayerModule {
+    pub fn new(entity: EntityId) -> Self {
+        MineLayerModule { entity }
+    }
+}
+
+impl UnitModule for MineLayerModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(mine_layer) = world.component::<MineLayer>(self.entity) {
+            let config = mine_layer.config();
+            registers.insert(REG_MINE_LAYER_DROP, Register::new_i32("drop", 0));
+            let status = match mine_layer.status() {
+                MineLayerStatus::Idle => MINE_LAYER_STATUS_IDLE,
+                MineLayerStatus::Dropped => MINE_LAYER_STATUS_DROPPED,
+                MineLayerStatus::InsufficientBudget => MINE_LAYER_STATUS_INSUFFICIENT_BUDGET,
+                MineLayerStatus::NotDeployed => MINE_LAYER_STATUS_NOT_DEPLOYED,
+                MineLayerStatus::Empty => MINE_LAYER_STATUS_EMPTY,
+                MineLayerStatus::NotReady => MINE_LAYER_STATUS_NOT_READY,
+            };
+            registers.insert(REG_MINE_LAYER_STATUS, Register::new_i32("status", status));
+            registers.insert(
+                REG_MINE_LAYER_MINES,
+                Register::new_i32("mines", mine_layer.mines() as i32),
+            );
+            registers.insert(
+                REG_MINE_LAYER_CAPACITY,
+                Register::new_i32("capacity", config.capacity as i32),
+            );
+            registers.insert(REG_MINE_LAYER_COST, Register::new_f32("cost", config.cost));
+            registers.insert(
+                REG_MINE_LAYER_DROP_INTERVAL,
+                Register::new_f32("drop_interval", config.drop_interval),
+            );
+            registers.insert(
+                REG_MINE_LAYER_ARM_TIME,
+                Register::new_f32("arm_time", config.arm_time),
+            );
+            registers.insert(
+                REG_MINE_LAYER_TRIGGER_RADIUS,
+                Register::new_f32("trigger_radius", config.trigger_radius),
+            );
+            registers.insert(
+                REG_MINE_LAYER_DETECTION_RANGE,
+                Register::new_f32("detection_range", config.detection_range),
+            );
+        }
+    }
+
+    fn set_component(&self, world: &mut World, registers: &RegisterMap) {
+        if let Some(mut mine_layer) = world.component_mut::<MineLayer>(self.entity) {
+            let drop = registers
+                .get(&REG_MINE_LAYER_DROP)
+                .expect("register doesnt exist")
+                .value_i32()
+                .expect("wrong value type");
+            if drop != 0 {
+                mine_layer.request();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_mine_layer() {
+        let config = MineConfig {
+            capacity: 2,
+            drop_interval: 1.0,
+            ..Default::default()
+        };
+        let mut mine_layer = MineLayer::new(config, World::new().add_entity());
+        assert!(mine_layer.is_ready(0.0));
+        assert!(mine_layer.take_mine(0.0));
+        assert!(!mine_layer.is_ready(0.5));
+        assert!(mine_layer.is_ready(1.0));
+        assert!(mine_layer.take_mine(1.0));
+        assert!(!mine_layer.take_mine(2.0));
+        assert_eq!(mine_layer.mines(), 0);
+    }
+}
@@ -0,0 +1,403 @@
+use engine::prelude::*;
+
+use crate::display::primitives::{Mat4, Vec3};
+use crate::util::cgmath::prelude::*;
+use cgmath::{InnerSpace, Rotation3};
+
+/// Where a missile steers to while it has fuel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MissileGuidance {
+    /// Fly straight ahead.
+    None,
+    /// Turn towards this position in the world.
+    Position(Vec3),
+    /// Turn towards this direction, yaw around the z axis and pitch above the horizontal plane.
+    Heading { yaw: f32, pitch: f32 },
+}
+
+/// Flight properties of a missile.
+#[derive(Debug, Clone, Copy)]
+pub struct MissileConfig {
+    /// Speed while the missile has fuel, meters per second.
+    pub speed: f32,
+    /// Maximum change in direction, radians per second.
+    pub turn_rate: f32,
+    /// Duration of powered flight, seconds.
+    pub fuel: f32,
+}
+
+/// Direction vector for a yaw and pitch.
+pub fn direction_from_yaw_pitch(yaw: f32, pitch: f32) -> Vec3 {
+    Vec3::new(
+        pitch.cos() * yaw.cos(),
+        pitch.cos() * yaw.sin(),
+        pitch.sin(),
+    )
+}
+
+/// Yaw and pitch of a direction vector.
+pub fn yaw_pitch_from_direction(direction: Vec3) -> (f32, f32) {
+    let direction = direction.normalize();
+    (
+        direction.y.atan2(direction.x),
+        direction.z.clamp(-1.0, 1.0).asin(),
+    )
+}
+
+/// Rotate the current direction towards the desired direction by at most the provided angle.
+pub fn steer(current: Vec3, desired: Vec3, max_angle: f32) -> Vec3 {
+    let current = current.normalize();
+    let desired = desired.normalize();
+    let angle = current.angle(desired).0;
+    if angle <= max_angle {
+        return desired;
+    }
+    let axis = current.cross(desired);
+    let axis = if axis.magnitude2() > 1e-12 {
+        axis.normalize()
+    } else {
+        // Desired is exactly behind us, any perpendicular axis will do, prefer turning sideways.
+        let side = Vec3::unit_z().cross(current);
+        if side.magnitude2() > 1e-12 {
+            Vec3::unit_z()
+        } else {
+            Vec3::unit_y()
+        }
+    };
+    let rotation = cgmath::Quaternion::from_axis_angle(axis, cgmath::Rad(max_angle));
+    (rotation * current).normalize()
+}
+
+/// A guided missile in flight, the world velocity is kept here and the pose of the missile is
+/// aligned with it.
+#[derive(Debug, Clone)]
+pub struct Missile {
+    config: MissileConfig,
+    fuel: f32,
+    guidance: MissileGuidance,
+    velocity: Vec3,
+}
+
+impl Missile {
+    pub fn new(config: MissileConfig, direction: Vec3) -> Self {
+        Missile {
+            config,
+            fuel: config.fuel,
+            guidance: MissileGuidance::None,
+            velocity: direction.normalize() * config.speed,
+        }
+    }
+
+    pub fn config(&self) -> &MissileConfig {
+        &self.config
+    }
+
+    /// Remaining duration of powered flight.
+    pub fn fuel(&self) -> f32 {
+        self.fuel
+    }
+
+    pub fn guidance(&self) -> MissileGuidance {
+        self.guidance
+    }
+
+    pub fn set_guidance(&mut self, guidance: MissileGuidance) {
+        self.guidance = guidance;
+    }
+
+    /// Velocity in the world frame.
+    pub fn velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    /// The pose of a missile at this position, with its x axis along the velocity.
+    pub fn pose(&self, position: Vec3) -> Mat4 {
+        let (yaw, pitch) = yaw_pitch_from_direction(self.velocity);
+        Mat4::from_translation(position)
+            * Mat4::from_angle_z(cgmath::Rad(yaw))
+            * Mat4::from_angle_y(cgmath::Rad(-pitch))
+    }
+
+    /// Advance the missile by a timestep, steering while it has fuel and falling afterwards.
+    pub fn update(&mut self, position: Vec3, dt: f32) {
+        if self.fuel <= 0.0 {
+            self.velocity += crate::components::acceleration::Acceleration::gravity().dv * dt;
+            return;
+        }
+        let desired = match self.guidance {
+            MissileGuidance::None => self.velocity,
+            MissileGuidance::Position(target) => {
+                let offset = target - position;
+                if offset.magnitude2() > 1e-6 {
+                    offset
+                } else {
+                    self.velocity
+                }
+            }
+            MissileGuidance::Heading { yaw, pitch } => direction_from_yaw_pitch(yaw, pitch),
+        };
+        let direction = steer(self.velocity, desired, self.config.turn_rate * dt);
+        self.velocity = direction * self.config.speed;
+        self.fuel = (self.fuel - dt).max(0.0);
+    }
+}
+impl Component for Missile {}
+
+/// Launches missiles and keeps the datalink to the missiles in flight.
+#[derive(Debug, Clone)]
+pub struct MissileLauncher {
+    config: MissileConfig,
+    /// Linked missile and its launch time, per slot.
+    slots: Vec<Option<(EntityId, f32)>>,
+}
+
+impl MissileLauncher {
+    pub fn new(config: MissileConfig, slot_count: usize) -> Self {
+        MissileLauncher {
+            config,
+            slots: vec![None; slot_count],
+        }
+    }
+
+    pub fn config(&self) -> &MissileConfig {
+        &self.config
+    }
+
+    /// The missile linked to each slot, if any.
+    pub fn slots(&self) -> Vec<Option<EntityId>> {
+        self.slots.iter().map(|s| s.map(|(e, _)| e)).collect()
+    }
+
+    /// Drop the links to missiles that are no longer in flight.
+    pub fn unlink<F: Fn(EntityId) -> bool>(&mut self, in_flight: F) {
+        for slot in self.slots.iter_mut() {
+            if let Some((entity, _)) = *slot {
+                if !in_flight(entity) {
+                    *slot = None;
+                }
+            }
+        }
+    }
+
+    /// Link a missile to a free slot, or to the slot of the missile that was launched first.
+    pub fn link(&mut self, missile: EntityId, time: f32) -> Option<usize> {
+        let index = self.slots.iter().position(|s| s.is_none()).or_else(|| {
+            self.slots
+                .iter()
+                .enumerate()
+                .min_by(|a, b| {
+                    let time = |s: &Option<(EntityId, f32)>| s.map(|(_, t)| t).unwrap_or(0.0);
+                    time(a.1)
+                        .partial_cmp(&time(b.1))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(i, _)| i)
+        })?;
+        self.slots[index] = Some((missile, time));
+        Some(index)
+    }
+}
+impl Component for MissileLauncher {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::missile_datalink::*;
+
+pub struct MissileDatalinkModule {
+    entity: EntityId,
+}
+
+impl MissileDatalinkModule {
+    pub fn new(entity: EntityId) -> Self {
+        MissileDatalinkModule { entity }
+    }
+}
+
+impl UnitModule for MissileDatalinkModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(launcher) = world.component::<MissileLauncher>(self.entity) {
+            let config = launcher.config();
+            registers.insert(
+                REG_MISSILE_DATALINK_SPEED,
+                Register::new_f32("speed", config.speed),
+            );
+            registers.insert(
+                REG_MISSILE_DATALINK_TURN_RATE,
+                Register::new_f32("turn_rate", config.turn_rate),
+            );
+            registers.insert(
+                REG_MISSILE_DATALINK_FUEL,
+                Register::new_f32("fuel", config.fuel),
+            );
+            let slots = launcher.slots();
+            registers.insert(
+                REG_MISSILE_DATALINK_COUNT,
+                Register::new_i32("count", slots.len() as i32),
+            );
+            for (i, slot) in slots.iter().enumerate() {
+                let base = REG_MISSILE_DATALINK_START + i as u32 * REG_MISSILE_DATALINK_STRIDE;
+                let missile = slot.and_then(|e| {
+                    world
+                        .component::<Missile>(e)
+                        .map(|m| (m.clone(), super::pose::world_pose(world, e)))
+                });
+                let active = missile.is_some();
+                let (position, (yaw, pitch), fuel, guidance) =
+                    if let Some((missile, pose)) = missile {
+                        (
+                            pose.to_translation(),
+                            yaw_pitch_from_direction(missile.velocity()),
+                            missile.fuel(),
+                            missile.guidance(),
+                        )
+                    } else {
+                        (
+                            Vec3::new(0.0, 0.0, 0.0),
+                            (0.0, 0.0),
+                            0.0,
+                            MissileGuidance::None,
+                        )
+                    };
+                let (mode, target, (target_yaw, target_pitch)) = match guidance {
+                    MissileGuidance::None => {
+                        (MISSILE_GUIDANCE_NONE, Vec3::new(0.0, 0.0, 0.0), (0.0, 0.0))
+                    }
+                    MissileGuidance::Position(target) => {
+                        (MISSILE_GUIDANCE_POSITION, target, (0.0, 0.0))
+                    }
+                    MissileGuidance::Heading { yaw, pitch } => (
+                        MISSILE_GUIDANCE_HEADING,
+                        Vec3::new(0.0, 0.0, 0.0),
+                        (yaw, pitch),
+                    ),
+                };
+                registers.insert(
+                    base + REG_MISSILE_DATALINK_OFFSET_ACTIVE,
+                    Register::new_i32("active", active as i32),
+                );
+                for (offset, name, value) in [
+                    (REG_MISSILE_DATALINK_OFFSET_X, "x", position.x),
+                    (REG_MISSILE_DATALINK_OFFSET_Y, "y", position.y),
+                    (REG_MISSILE_DATALINK_OFFSET_Z, "z", position.z),
+                    (REG_MISSILE_DATALINK_OFFSET_YAW, "yaw", yaw),
+                    (REG_MISSILE_DATALINK_OFFSET_PITCH, "pitch", pitch),
+                    (REG_MISSILE_DATALINK_OFFSET_FUEL, "fuel", fuel),
+                    (REG_MISSILE_DATALINK_OFFSET_TARGET_X, "target_x", target.x),
+                    (REG_MISSILE_DATALINK_OFFSET_TARGET_Y, "target_y", target.y),
+                    (REG_MISSILE_DATALINK_OFFSET_TARGET_Z, "target_z", target.z),
+                    (
+                        REG_MISSILE_DATALINK_OFFSET_TARGET_YAW,
+                        "target_yaw",
+                        target_yaw,
+                    ),
+                    (
+                        REG_MISSILE_DATALINK_OFFSET_TARGET_PITCH,
+                        "target_pitch",
+                        target_pitch,
+                    ),
+                ] {
+                    registers.insert(base + offset, Register::new_f32(name, value));
+                }
+                registers.insert(
+                    base + REG_MISSILE_DATALINK_OFFSET_MODE,
+                    Register::new_i32("mode", mode),
+                );
+            }
+        }
+    }
+
+    fn set_component(&self, world: &mut World, registers: &RegisterMap) {
+        let slots = if let Some(launcher) = world.component::<MissileLauncher>(self.entity) {
+            launcher.slots()
+        } else {
+            return;
+        };
+        let f32_value = |register: u32| {
+            registers
+                .get(&register)
+                .expect("register doesnt exist")
+                .value_f32()
+                .expect("wrong value type")
+        };
+        for (i, slot) in slots.iter().enumerate() {
+            let base = REG_MISSILE_DATALINK_START + i as u32 * REG_MISSILE_DATALINK_STRIDE;
+            let mode = registers
+                .get(&(base + REG_MISSILE_DATALINK_OFFSET_MODE))
+                .expect("register doesnt exist")
+                .value_i32()
+                .expect("wrong value type");
+            let guidance = match mode {
+                MISSILE_GUIDANCE_POSITION => MissileGuidance::Position(Vec3::new(
+                    f32_value(base + REG_MISSILE_DATALINK_OFFSET_TARGET_X),
+                    f32_value(base + REG_MISSILE_DATALINK_OFFSET_TARGET_Y),
+                    f32_value(base + REG_MISSILE_DATALINK_OFFSET_TARGET_Z),
+                )),
+                MISSILE_GUIDANCE_HEADING => MissileGuidance::Heading {
+                    yaw: f32_value(base + REG_MISSILE_DATALINK_OFFSET_TARGET_YAW),
+                    pitch: f32_value(base + REG_MISSILE_DATALINK_OFFSET_TARGET_PITCH),
+                },
+                _ => MissileGuidance::None,
+            };
+            if let Some(mut missile) = slot.and_then(|e| world.component_mut::<Missile>(e)) {
+                missile.set_guidance(guidance);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_missile_turn_rate() {
+        let config = MissileConfig {
+            speed: 10.0,
+            turn_rate: 1.0,
+            fuel: 1.0,
+        };
+        let mut missile = Missile::new(config, Vec3::unit_x());
+        missile.set_guidance(MissileGuidance::Position(Vec3::new(0.0, 10.0, 0.0)));
+
+        // The target is 90 degrees to the left, it can only turn 0.1 radians in this step.
+        missile.update(Vec3::new(0.0, 0.0, 0.0), 0.1);
+        let (yaw, pitch) = yaw_pitch_from_direction(missile.velocity());
+        assert!((yaw - 0.1).abs() < 1e-4);
+        assert!(pitch.abs() < 1e-4);
+        assert!((missile.velocity().magnitude() - 10.0).abs() < 1e-4);
+        assert!((missile.fuel() - 0.9).abs() < 1e-4);
+
+        // Heading guidance, small corrections are reached immediately.
+        missile.set_guidance(MissileGuidance::Heading {
+            yaw: 0.15,
+            pitch: 0.0,
+        });
+        missile.update(Vec3::new(0.0, 0.0, 0.0), 0.1);
+        let (yaw, _) = yaw_pitch_from_direction(missile.velocity());
+        assert!((yaw - 0.15).abs() < 1e-4);
+
+        // Without fuel it falls.
+        missile.update(Vec3::new(0.0, 0.0, 0.0), 1.0);
+        let z = missile.velocity().z;
+        missile.update(Vec3::new(0.0, 0.0, 0.0), 0.1);
+        assert!(missile.velocity().z < z);
+    }
+
+    #[test]
+    fn test_launcher_slots() {
+        let config = MissileConfig {
+            speed: 10.0,
+            turn_rate: 1.0,
+            fuel: 1.0,
+        };
+        let mut launcher = MissileLauncher::new(config, 2);
+        let mut world = World::new();
+        let (a, b, c) = (world.add_entity(), world.add_entity(), world.add_entity());
+        assert_eq!(launcher.link(a, 0.0), Some(0));
+        assert_eq!(launcher.link(b, 1.0), Some(1));
+        // Full, the oldest missile loses its link.
+        assert_eq!(launcher.link(c, 2.0), Some(0));
+        assert_eq!(launcher.slots(), vec![Some(c), Some(b)]);
+        launcher.unlink(|e| e != b);
+        assert_eq!(launcher.slots(), vec![Some(c), None]);
+    }
+}
@@ -1,4 +1,6 @@
 pub mod acceleration;
+pub mod ammunition;
+pub mod bumper;
 pub mod camera_position;
 pub mod camera_target;
 pub mod cannon;
@@ -6,6 +8,9 @@ pub mod capturable;
 pub mod capture_marker;
 pub mod capture_point;
 pub mod clock;
+pub mod collider;
+pub mod collision_damage;
+pub mod construction;
 pub mod damage_hit;
 pub mod damage_splash;
 pub mod deploy;
@@ -24,28 +29,45 @@ pub mod hit_collection;
 pub mod hit_effect;
 pub mod hit_plane;
 pub mod hit_sphere;
+pub mod hit_zone;
 pub mod id_generator;
 pub mod impact;
+pub mod imu;
+pub mod match_capture_the_flag;
 pub mod match_domination;
+pub mod match_escort;
 pub mod match_finished;
 pub mod match_king_of_the_hill;
 pub mod match_team_deathmatch;
 pub mod match_time_limit;
+pub mod mine;
+pub mod missile;
 pub mod objectives_module;
+pub mod odometry;
 pub mod parent;
+pub mod payload;
 pub mod point_projectile;
 pub mod pose;
 pub mod radar;
 pub mod radar_reflector;
 pub mod radio_receiver;
 pub mod radio_transmitter;
+pub mod random;
 pub mod recording;
+pub mod repair;
+pub mod respawn;
+pub mod resupply;
 pub mod revolute;
 pub mod revolute_pair;
+pub mod scoreboard;
 pub mod select_box;
+pub mod subsystem_health;
 pub mod team;
+pub mod team_blackboard;
+pub mod team_flag;
 pub mod team_member;
 pub mod team_module;
+pub mod terrain;
 pub mod timed_function_trigger;
 pub mod tricycle_base;
 pub mod tricycle_front_wheels;
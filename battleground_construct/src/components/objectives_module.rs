@@ -4,6 +4,9 @@ use engine::prelude::*;
 use crate::components;
 use components::capturable::Capturable;
 use components::capture_point::CapturePoint;
+use components::match_escort::MatchEscort;
+use components::payload::Payload;
+use components::team_flag::{FlagState, TeamFlag};
 // use components::match_king_of_the_hill::MatchKingOfTheHill;
 
 use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
@@ -68,5 +71,116 @@ impl UnitModule for ObjectivesModule {
                 Register::new_f32("radius", *radius),
             );
         }
+
+        // And the flags for capture the flag.
+        let mut flags = vec![];
+        for (e, flag) in world.component_iter::<TeamFlag>() {
+            use crate::util::cgmath::ToTranslation;
+            let pose = world_pose(world, e).to_translation();
+            flags.push((pose.x, pose.y, *flag));
+        }
+
+        registers.insert(
+            REG_OBJECTIVES_FLAG_COUNT,
+            Register::new_i32("flag_count", flags.len() as i32),
+        );
+
+        for (i, (x, y, flag)) in flags.iter().enumerate() {
+            let base_offset = REG_OBJECTIVES_FLAG_START + i as u32 * REG_OBJECTIVES_FLAG_STRIDE;
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_X,
+                Register::new_f32("x", *x),
+            );
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_Y,
+                Register::new_f32("y", *y),
+            );
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_TEAM,
+                Register::new_i32("team", flag.team().as_u64() as i32),
+            );
+            let state = match flag.state() {
+                FlagState::AtBase => OBJECTIVES_FLAG_STATE_AT_BASE,
+                FlagState::Carried => OBJECTIVES_FLAG_STATE_CARRIED,
+                FlagState::Dropped => OBJECTIVES_FLAG_STATE_DROPPED,
+            };
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_STATE,
+                Register::new_i32("state", state),
+            );
+            let carrier = flag
+                .carrier()
+                .map(|v| v.as_u64() as i32)
+                .unwrap_or(OBJECTIVES_FLAG_NO_CARRIER);
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_CARRIER,
+                Register::new_i32("carrier", carrier),
+            );
+            let (base_x, base_y) = flag.base();
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_BASE_X,
+                Register::new_f32("base_x", base_x),
+            );
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_BASE_Y,
+                Register::new_f32("base_y", base_y),
+            );
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_RADIUS,
+                Register::new_f32("radius", flag.radius()),
+            );
+        }
+
+        // And the payload for escort matches.
+        let payload = world
+            .component_iter::<Payload>()
+            .next()
+            .map(|(e, p)| (e, p.clone()));
+        registers.insert(
+            REG_OBJECTIVES_PAYLOAD_COUNT,
+            Register::new_i32("payload_count", payload.is_some() as i32),
+        );
+        if let Some((e, payload)) = payload {
+            use crate::util::cgmath::ToTranslation;
+            let position = world_pose(world, e).to_translation();
+            let (_, _, yaw) = payload.pose();
+            registers.insert(REG_OBJECTIVES_PAYLOAD_X, Register::new_f32("x", position.x));
+            registers.insert(REG_OBJECTIVES_PAYLOAD_Y, Register::new_f32("y", position.y));
+            registers.insert(REG_OBJECTIVES_PAYLOAD_YAW, Register::new_f32("yaw", yaw));
+            registers.insert(
+                REG_OBJECTIVES_PAYLOAD_PROGRESS,
+                Register::new_f32("progress", payload.progress()),
+            );
+            registers.insert(
+                REG_OBJECTIVES_PAYLOAD_RADIUS,
+                Register::new_f32("radius", payload.radius()),
+            );
+            let attacker = world
+                .component_iter::<MatchEscort>()
+                .next()
+                .map(|(_, m)| m.attackers().as_u64() as i32)
+                .unwrap_or(OBJECTIVES_CAPTURE_POINT_UNOWNED);
+            registers.insert(
+                REG_OBJECTIVES_PAYLOAD_ATTACKER,
+                Register::new_i32("attacker", attacker),
+            );
+
+            registers.insert(
+                REG_OBJECTIVES_PAYLOAD_WAYPOINT_COUNT,
+                Register::new_i32("waypoint_count", payload.path().len() as i32),
+            );
+            for (i, (x, y)) in payload.path().iter().enumerate() {
+                let base_offset = REG_OBJECTIVES_PAYLOAD_WAYPOINT_START
+                    + i as u32 * REG_OBJECTIVES_PAYLOAD_WAYPOINT_STRIDE;
+                registers.insert(
+                    base_offset + REG_OBJECTIVES_PAYLOAD_WAYPOINT_OFFSET_X,
+                    Register::new_f32("waypoint_x", *x),
+                );
+                registers.insert(
+                    base_offset + REG_OBJECTIVES_PAYLOAD_WAYPOINT_OFFSET_Y,
+                    Register::new_f32("waypoint_y", *y),
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,64 @@
+use engine::prelude::*;
+
+/// Number of encoder ticks per meter travelled by a wheel.
+pub const ODOMETRY_TICKS_PER_METER: f32 = 1000.0;
+
+/// Wheel encoders, accumulates the distance travelled by the left and right wheels.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Odometry {
+    // Kept as f64, tiny increments are added every step.
+    left: f64,
+    right: f64,
+}
+
+impl Odometry {
+    pub fn new() -> Self {
+        Odometry::default()
+    }
+
+    pub fn add_distance(&mut self, left: f32, right: f32) {
+        self.left += left as f64;
+        self.right += right as f64;
+    }
+
+    /// Tick counts of the left and right wheels, these wrap around.
+    pub fn ticks(&self) -> (i32, i32) {
+        let to_ticks = |d: f64| ((d * ODOMETRY_TICKS_PER_METER as f64) as i64) as i32;
+        (to_ticks(self.left), to_ticks(self.right))
+    }
+}
+impl Component for Odometry {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::odometry::*;
+
+pub struct OdometryModule {
+    entity: EntityId,
+}
+
+impl OdometryModule {
+    pub fn new(entity: EntityId) -> Self {
+        OdometryModule { entity }
+    }
+}
+
+impl UnitModule for OdometryModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(odometry) = world.component::<Odometry>(self.entity) {
+            let (left, right) = odometry.ticks();
+            registers.insert(
+                REG_ODOMETRY_LEFT_TICKS,
+                Register::new_i32("left_ticks", left),
+            );
+            registers.insert(
+                REG_ODOMETRY_RIGHT_TICKS,
+                Register::new_i32("right_ticks", right),
+            );
+            registers.insert(
+                REG_ODOMETRY_TICKS_PER_METER,
+                Register::new_f32("ticks_per_meter", ODOMETRY_TICKS_PER_METER),
+            );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The payload of an escort match, moves along the waypoint path.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Payload {
+    path: Vec<(f32, f32)>,
+    speed: f32,
+    radius: f32,
+    /// Distance travelled along the path.
+    distance: f32,
+}
+
+impl Payload {
+    /// Create a payload at the start of the path, it moves with speed when pushed by units within
+    /// radius.
+    pub fn new(path: &[(f32, f32)], speed: f32, radius: f32) -> Self {
+        Payload {
+            path: path.to_vec(),
+            speed,
+            radius,
+            distance: 0.0,
+        }
+    }
+
+    pub fn path(&self) -> &[(f32, f32)] {
+        &self.path
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Total length of the path.
+    pub fn length(&self) -> f32 {
+        self.path
+            .windows(2)
+            .map(|w| (w[1].0 - w[0].0).hypot(w[1].1 - w[0].1))
+            .sum()
+    }
+
+    /// Progress along the path, from 0.0 at the start to 1.0 at the end. A path without length
+    /// can't be completed.
+    pub fn progress(&self) -> f32 {
+        let length = self.length();
+        if length > 0.0 {
+            self.distance / length
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// Move the payload along the path, negative distances move it back towards the start.
+    pub fn advance(&mut self, distance: f32) {
+        self.distance = (self.distance + distance).clamp(0.0, self.length());
+    }
+
+    /// The x, y and yaw of the payload at its current position on the path.
+    pub fn pose(&self) -> (f32, f32, f32) {
+        let mut remaining = self.distance;
+        let mut last = (0.0, 0.0, 0.0);
+        for w in self.path.windows(2) {
+            let (dx, dy) = (w[1].0 - w[0].0, w[1].1 - w[0].1);
+            let segment = dx.hypot(dy);
+            let yaw = dy.atan2(dx);
+            if remaining <= segment && segment > 0.0 {
+                let ratio = remaining / segment;
+                return (w[0].0 + dx * ratio, w[0].1 + dy * ratio, yaw);
+            }
+            remaining -= segment;
+            last = (w[1].0, w[1].1, yaw);
+        }
+        if self.path.len() == 1 {
+            return (self.path[0].0, self.path[0].1, 0.0);
+        }
+        last
+    }
+}
+impl Component for Payload {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_payload_path() {
+        let mut payload = Payload::new(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)], 1.0, 5.0);
+        assert_eq!(payload.length(), 20.0);
+        assert_eq!(payload.pose(), (0.0, 0.0, 0.0));
+        payload.advance(15.0);
+        let (x, y, yaw) = payload.pose();
+        assert_eq!((x, y), (10.0, 5.0));
+        assert!((yaw - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(payload.progress(), 0.75);
+        payload.advance(-20.0);
+        assert_eq!(payload.progress(), 0.0);
+        payload.advance(100.0);
+        assert!(payload.is_finished());
+        assert_eq!(payload.pose().0, 10.0);
+        assert_eq!(payload.pose().1, 10.0);
+    }
+}
@@ -1,5 +1,10 @@
+use super::random::Random;
 use crate::display::primitives::Mat4;
 use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Distance used for the strength of false positives closer than this, keeps it finite.
+const FALSE_POSITIVE_MIN_DISTANCE: f32 = 0.5;
 
 #[derive(Copy, Debug, Clone)]
 pub struct Reflection {
@@ -7,6 +12,9 @@ pub struct Reflection {
     pub pitch: f32,
     pub strength: f32,
     pub distance: f32,
+    /// The reflector entity that caused this reflection, None for false positives. This is not
+    /// exposed to the unit, it allows the viewer to show what a team can see.
+    pub reflector: Option<EntityId>,
 }
 
 #[derive(Debug, Clone)]
@@ -16,9 +24,57 @@ pub struct Radar {
     detection_angle_yaw: f32,
     detection_angle_pitch: f32,
 
+    noise: RadarNoise,
+    range_scale: f32,
+
     reflections: Vec<Reflection>,
 }
 
+/// Sensor model for the radar, the default is a perfect radar.
+#[derive(Deserialize, Serialize, Copy, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct RadarNoise {
+    /// Standard deviation of the gaussian noise on the reported yaw, radians.
+    pub yaw_stddev: f32,
+    /// Standard deviation of the gaussian noise on the reported pitch, radians.
+    pub pitch_stddev: f32,
+    /// Standard deviation of the gaussian noise on the reported distance.
+    pub distance_stddev: f32,
+    /// Reflections with this strength are detected half of the time, weaker reflections less often,
+    /// stronger ones more often. Zero means every reflection is detected.
+    pub detection_strength: f32,
+    /// Steepness of the detection probability curve around `detection_strength`.
+    pub detection_steepness: f32,
+    /// Probability per scan that a reflection is reported where there is nothing.
+    pub false_positive_probability: f32,
+}
+
+impl Default for RadarNoise {
+    fn default() -> Self {
+        RadarNoise {
+            yaw_stddev: 0.0,
+            pitch_stddev: 0.0,
+            distance_stddev: 0.0,
+            detection_strength: 0.0,
+            detection_steepness: 4.0,
+            false_positive_probability: 0.0,
+        }
+    }
+}
+
+impl RadarNoise {
+    /// Probability that a reflection of this strength is detected.
+    pub fn detection_probability(&self, strength: f32) -> f32 {
+        if self.detection_strength <= 0.0 {
+            return 1.0;
+        }
+        // Logistic curve in the ratio of strengths, 0.5 at the detection strength.
+        1.0 / (1.0 + (self.detection_strength / strength).powf(self.detection_steepness))
+    }
+}
+/// If present in the world, this sensor model is applied to all radars that are spawned.
+impl Component for RadarNoise {}
+
 #[derive(Copy, Debug, Clone)]
 pub struct RadarConfig {
     /// Maximum range of this radar, distances beyond this can never be seen.
@@ -29,6 +85,8 @@ pub struct RadarConfig {
     pub detection_angle_pitch: f32,
     /// Signal strength that's emitted by the radar (follows inverse square law, and respects reflectivity.
     pub signal_strength: f32,
+    /// Sensor model of the radar.
+    pub noise: RadarNoise,
 }
 impl Default for RadarConfig {
     fn default() -> Self {
@@ -37,6 +95,7 @@ impl Default for RadarConfig {
             detection_angle_yaw: 1.0f32.to_radians(),
             detection_angle_pitch: 180f32.to_radians(),
             signal_strength: 1.0,
+            noise: Default::default(),
         }
     }
 }
@@ -49,9 +108,21 @@ impl Radar {
             detection_angle_yaw: config.detection_angle_yaw,
             detection_angle_pitch: config.detection_angle_pitch,
             signal_strength: config.signal_strength,
+            noise: config.noise,
+            range_scale: 1.0,
         }
     }
 
+    /// The effective maximum range of the radar.
+    pub fn range_max(&self) -> f32 {
+        self.range_max * self.range_scale
+    }
+
+    /// Scale the maximum range, used to degrade the radar when it is damaged.
+    pub fn set_range_scale(&mut self, scale: f32) {
+        self.range_scale = scale;
+    }
+
     pub fn reflections(&self) -> Vec<Reflection> {
         self.reflections.clone()
     }
@@ -63,14 +134,41 @@ impl Radar {
         self.detection_angle_pitch
     }
 
-    pub fn update_reflections(&mut self, radar_pose: &Mat4, reflectors: &[(Mat4, f32)]) {
+    pub fn noise(&self) -> RadarNoise {
+        self.noise
+    }
+
+    pub fn set_noise(&mut self, noise: RadarNoise) {
+        self.noise = noise;
+    }
+
+    pub fn update_reflections(
+        &mut self,
+        radar_pose: &Mat4,
+        reflectors: &[(Mat4, f32)],
+        random: &mut Random,
+    ) {
+        let reflectors = reflectors
+            .iter()
+            .map(|(pos, reflectivity)| (*pos, *reflectivity, None))
+            .collect::<Vec<_>>();
+        self.update_reflections_from(radar_pose, &reflectors, random);
+    }
+
+    /// Update the reflections, with the reflector entity of each pose and reflectivity.
+    pub fn update_reflections_from(
+        &mut self,
+        radar_pose: &Mat4,
+        reflectors: &[(Mat4, f32, Option<EntityId>)],
+        random: &mut Random,
+    ) {
         use crate::util::cgmath::prelude::*;
         self.reflections.clear();
-        for (pos, reflectivity) in reflectors.iter() {
+        for (pos, reflectivity, reflector) in reflectors.iter() {
             let pos_v = pos.to_translation();
             let radar_v = radar_pose.to_translation();
             let distance = radar_v.distance2(pos_v).sqrt();
-            if distance >= self.range_max {
+            if distance >= self.range_max() {
                 continue; // so far away, it's out of range, easy optimisation.
             }
 
@@ -98,18 +196,79 @@ impl Radar {
                 let reflected = reflectivity * ratio_towards;
                 let ratio_back = 1.0 / distance.powi(2);
                 let strength = ratio_back * reflected * self.signal_strength;
+                if !self.is_detected(strength, random) {
+                    continue;
+                }
                 self.reflections.push(Reflection {
-                    yaw,
-                    pitch,
+                    yaw: yaw + random.normal(self.noise.yaw_stddev),
+                    pitch: pitch + random.normal(self.noise.pitch_stddev),
                     strength,
-                    distance,
+                    distance: (distance + random.normal(self.noise.distance_stddev)).max(0.0),
+                    reflector: *reflector,
                 });
             }
         }
+
+        if self.noise.false_positive_probability > 0.0
+            && random.chance(self.noise.false_positive_probability)
+        {
+            // Something that doesn't exist, with the strength a real reflector would have.
+            let distance = random.uniform_range(0.0, self.range_max());
+            self.reflections.push(Reflection {
+                yaw: random.uniform_range(-self.detection_angle_yaw, self.detection_angle_yaw),
+                pitch: random
+                    .uniform_range(-self.detection_angle_pitch, self.detection_angle_pitch)
+                    .clamp(-std::f32::consts::FRAC_PI_2, std::f32::consts::FRAC_PI_2),
+                strength: self.signal_strength / distance.max(FALSE_POSITIVE_MIN_DISTANCE).powi(4),
+                distance,
+                reflector: None,
+            });
+        }
+    }
+
+    fn is_detected(&self, strength: f32, random: &mut Random) -> bool {
+        let probability = self.noise.detection_probability(strength);
+        probability >= 1.0 || random.chance(probability)
     }
 }
 impl Component for Radar {}
 
+/// The detection cone and the reflectors a radar detected in its last scan. This is recorded, so
+/// the viewer can show what a team could see during playback as well.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct RadarDetections {
+    range: f32,
+    detection_angle_yaw: f32,
+    reflectors: Vec<EntityId>,
+}
+
+impl RadarDetections {
+    pub fn from_radar(radar: &Radar) -> Self {
+        RadarDetections {
+            range: radar.range_max(),
+            detection_angle_yaw: radar.detection_angle_yaw(),
+            reflectors: radar
+                .reflections
+                .iter()
+                .filter_map(|r| r.reflector)
+                .collect(),
+        }
+    }
+
+    pub fn range(&self) -> f32 {
+        self.range
+    }
+
+    pub fn detection_angle_yaw(&self) -> f32 {
+        self.detection_angle_yaw
+    }
+
+    pub fn reflectors(&self) -> &[EntityId] {
+        &self.reflectors
+    }
+}
+impl Component for RadarDetections {}
+
 use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
 use battleground_unit_control::modules::radar::*;
 
@@ -130,7 +289,7 @@ impl UnitModule for RadarModule {
             let reflections = radar.reflections();
             registers.insert(
                 REG_RADAR_RANGE_MAX,
-                Register::new_f32("range_max", radar.range_max),
+                Register::new_f32("range_max", radar.range_max()),
             );
             registers.insert(
                 REG_RADAR_DETECTION_ANGLE_YAW,
@@ -182,6 +341,7 @@ mod test {
             detection_angle_yaw: 1.0f32.to_radians(),
             detection_angle_pitch: 180f32.to_radians(),
             signal_strength: 1.0,
+            ..Default::default()
         });
         let reflections = vec![
             (Mat4::from_translation(vec3(5.0f32, 3.0, 0.0)), 1.0), // seen! 10m
@@ -191,7 +351,7 @@ mod test {
             (Mat4::from_translation(vec3(-4.99f32, 3.00, -25.0)), 1.0), // Seen ~25m, low pitch.
         ];
         let radar_pose = Mat4::from_translation(vec3(-5.0f32, 3.0, 0.0));
-        radar.update_reflections(&radar_pose, &reflections);
+        radar.update_reflections(&radar_pose, &reflections, &mut Random::new(0));
 
         let expected = vec![
             (0.0f32, 0.0f32, 10.0f32),
@@ -215,6 +375,7 @@ mod test {
             detection_angle_yaw: 180f32.to_radians(),
             detection_angle_pitch: 180f32.to_radians(),
             signal_strength: 1.0,
+            ..Default::default()
         });
         let radar_pose = Mat4::from_translation(vec3(0.0, 0.0, 0.0));
         let reflections = vec![
@@ -223,7 +384,7 @@ mod test {
             (Mat4::from_translation(vec3(0.00001f32, 0.0, 5.0)), 1.0), // Above the radar.
         ];
 
-        radar.update_reflections(&radar_pose, &reflections);
+        radar.update_reflections(&radar_pose, &reflections, &mut Random::new(0));
         let obtained = radar.reflections();
         println!("Obtained: {obtained:?}");
         let expected = vec![
@@ -243,6 +404,7 @@ mod test {
             detection_angle_yaw: 60.0f32.to_radians(),
             detection_angle_pitch: 180f32.to_radians(),
             signal_strength: 1.0,
+            ..Default::default()
         });
         let reflections = vec![
             (Mat4::from_translation(vec3(-5.0f32, 10.0, 0.0)), 1.0), // seen! 7m
@@ -251,7 +413,7 @@ mod test {
         ];
         let radar_pose =
             Mat4::from_translation(vec3(-5.0f32, 3.0, 0.0)) * Mat4::from_angle_z(cgmath::Deg(90.0));
-        radar.update_reflections(&radar_pose, &reflections);
+        radar.update_reflections(&radar_pose, &reflections, &mut Random::new(0));
         use crate::util::cgmath::EuclideanNorm;
         let expected = vec![
             (0.0f32, 0.0f32, 7.0f32),
@@ -275,4 +437,62 @@ mod test {
             approx_equal!(obtain.distance, expect.2, 0.001);
         }
     }
+
+    #[test]
+    fn test_radar_noise() {
+        let noise = RadarNoise {
+            yaw_stddev: 0.01,
+            distance_stddev: 0.5,
+            ..Default::default()
+        };
+        let mut radar = Radar::new_with_config(RadarConfig {
+            noise,
+            ..Default::default()
+        });
+        let radar_pose = Mat4::from_translation(vec3(0.0, 0.0, 0.0));
+        let reflections = vec![(Mat4::from_translation(vec3(10.0f32, 0.0, 0.0)), 1.0)];
+        let mut random = Random::new(1);
+        let mut distances = vec![];
+        for _ in 0..1000 {
+            radar.update_reflections(&radar_pose, &reflections, &mut random);
+            let obtained = radar.reflections();
+            assert_eq!(obtained.len(), 1);
+            assert!(obtained[0].yaw.abs() < 0.1);
+            distances.push(obtained[0].distance);
+        }
+        let mean = distances.iter().sum::<f32>() / distances.len() as f32;
+        approx_equal!(mean, 10.0, 0.1);
+        assert!(distances.iter().any(|d| (d - 10.0).abs() > 0.25));
+
+        // Detection strength halfway, roughly half of the scans find the reflector.
+        let strength = 1.0 / 10.0f32.powi(4);
+        radar.set_noise(RadarNoise {
+            detection_strength: strength,
+            ..Default::default()
+        });
+        approx_equal!(radar.noise().detection_probability(strength), 0.5, 0.001);
+        assert!(radar.noise().detection_probability(strength * 2.0) > 0.9);
+        let detected = (0..1000)
+            .filter(|_| {
+                radar.update_reflections(&radar_pose, &reflections, &mut random);
+                !radar.reflections().is_empty()
+            })
+            .count();
+        assert!((400..600).contains(&detected), "detected {detected}");
+
+        // Nothing to see, but false positives show up.
+        radar.set_noise(RadarNoise {
+            false_positive_probability: 0.1,
+            ..Default::default()
+        });
+        let false_positives = (0..1000)
+            .filter(|_| {
+                radar.update_reflections(&radar_pose, &[], &mut random);
+                let reflections = radar.reflections();
+                assert!(reflections.iter().all(|r| r.strength.is_finite()));
+                !reflections.is_empty()
+            })
+            .count();
+        assert!((50..150).contains(&false_positives));
+    }
 }
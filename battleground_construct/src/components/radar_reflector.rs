@@ -3,14 +3,29 @@ use engine::prelude::*;
 #[derive(Debug, Clone)]
 pub struct RadarReflector {
     pub reflectivity: f32,
+    /// If set, radars of other teams only detect this reflector within this distance.
+    pub enemy_range: Option<f32>,
 }
 
 impl RadarReflector {
     pub fn new(reflectivity: f32) -> Self {
-        Self { reflectivity }
+        Self {
+            reflectivity,
+            enemy_range: None,
+        }
+    }
+    /// Limit the distance at which radars of other teams detect this reflector.
+    pub fn with_enemy_range(self, range: f32) -> Self {
+        Self {
+            enemy_range: Some(range),
+            ..self
+        }
     }
     pub fn reflectivity(&self) -> f32 {
         self.reflectivity
     }
+    pub fn enemy_range(&self) -> Option<f32> {
+        self.enemy_range
+    }
 }
 impl Component for RadarReflector {}
@@ -0,0 +1,77 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Deterministic pseudo random number generator, seeded from the scenario such that matches can
+/// be reproduced exactly. This is a splitmix64 generator, small and good enough for sensor noise.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed value in [0, 1).
+    pub fn uniform(&mut self) -> f32 {
+        // Use the upper 24 bits, that's all the precision an f32 mantissa holds.
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniformly distributed value in [min, max).
+    pub fn uniform_range(&mut self, min: f32, max: f32) -> f32 {
+        min + self.uniform() * (max - min)
+    }
+
+    /// Normally distributed value with zero mean and the provided standard deviation.
+    pub fn normal(&mut self, stddev: f32) -> f32 {
+        if stddev == 0.0 {
+            return 0.0;
+        }
+        // Box-Muller transform, 1.0 - uniform to avoid taking the logarithm of zero.
+        let u1 = 1.0 - self.uniform();
+        let u2 = self.uniform();
+        stddev * (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+    }
+
+    /// Returns true with the provided probability.
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.uniform() < probability
+    }
+}
+impl Component for Random {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_random() {
+        let mut a = Random::new(3);
+        let mut b = Random::new(3);
+        let values = (0..10).map(|_| a.next_u64()).collect::<Vec<_>>();
+        assert_eq!(values, (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(values[0], Random::new(4).next_u64());
+
+        let count = 10000;
+        let uniform = (0..count).map(|_| a.uniform()).collect::<Vec<_>>();
+        assert!(uniform.iter().all(|v| (0.0..1.0).contains(v)));
+        let mean = uniform.iter().sum::<f32>() / count as f32;
+        assert!((mean - 0.5).abs() < 0.02);
+
+        let normal = (0..count).map(|_| a.normal(2.0)).collect::<Vec<_>>();
+        let mean = normal.iter().sum::<f32>() / count as f32;
+        let variance = normal.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / count as f32;
+        assert!(mean.abs() < 0.1);
+        assert!((variance.sqrt() - 2.0).abs() < 0.1);
+    }
+}
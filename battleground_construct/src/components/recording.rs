@@ -365,6 +365,8 @@ impl Record {
 
         // Projectile visualisation.
         self.register_type::<display::tank_bullet::TankBullet>("tank_bullet");
+        self.register_type::<display::missile::Missile>("missile");
+        self.register_type::<display::mine::Mine>("mine");
 
         // Visualisation emitters that are not trivially recreated like meshes.
         self.register_type::<display::particle_emitter::ParticleEmitter>("particle_emitter");
@@ -372,17 +374,35 @@ impl Record {
 
         // History of hits.
         self.register_type::<components::hit_by::HitByHistory>("hit_by_history");
+        self.register_type::<components::repair::RepairHistory>("repair_history");
 
         // For units, we use the unit, and the health component to track whether they should have
         // bodies.
         self.register_type::<components::health::Health>("health");
 
         self.register_type::<components::unit::Unit>("unit");
+        self.register_type::<components::unit_member::UnitMember>("unit_member");
+        self.register_type::<components::unit_source::UnitSource>("unit_source");
         self.register_type::<crate::units::tank::UnitTank>("unit_tank");
         self.register_type::<crate::units::artillery::UnitArtillery>("unit_artillery");
+        self.register_type::<crate::units::scout::UnitScout>("unit_scout");
+        self.register_type::<crate::units::missile_launcher::UnitMissileLauncher>(
+            "unit_missile_launcher",
+        );
+        // The tricycle base is part of the tricycle units, it is used to color their body.
+        self.register_type::<crate::units::base_tricycle::BaseTricycle>("base_tricycle");
         self.register_type::<crate::units::capturable_flag::UnitCapturableFlag>(
             "unit_capturable_flag",
         );
+        self.register_type::<crate::units::obstacle::UnitObstacle>("unit_obstacle");
+        self.register_type::<crate::units::terrain::UnitTerrain>("unit_terrain");
+        self.register_type::<crate::units::resupply_zone::UnitResupplyZone>("unit_resupply_zone");
+        self.register_type::<crate::units::structure::UnitStructure>("unit_structure");
+        self.register_type::<crate::units::team_flag::UnitTeamFlag>("unit_team_flag");
+        self.register_type::<crate::units::payload::UnitPayload>("unit_payload");
+
+        // Radar detections, to show the perspective of a single team.
+        self.register_type::<components::radar::RadarDetections>("radar_detections");
 
         // Team information, to color vehicles.
         self.register_type::<components::team::Team>("team");
@@ -391,13 +411,19 @@ impl Record {
         // Capturables
         self.register_type::<components::capturable::Capturable>("capturable");
         self.register_type::<components::capture_point::CapturePoint>("capture_point");
+        self.register_type::<components::team_flag::TeamFlag>("team_flag");
 
         // Match info.
         self.register_type::<components::match_finished::MatchFinished>("match_finished");
         self.register_type::<components::match_king_of_the_hill::MatchKingOfTheHill>(
             "match_king_of_the_hill",
         );
+        self.register_type::<components::match_capture_the_flag::MatchCaptureTheFlag>(
+            "match_capture_the_flag",
+        );
+        self.register_type::<components::match_escort::MatchEscort>("match_escort");
         self.register_type::<components::match_time_limit::MatchTimeLimit>("match_time_limit");
+        self.register_type::<components::scoreboard::Scoreboard>("scoreboard");
 
         self.current_state.ensure_components(&self.component_map);
     }
@@ -0,0 +1,156 @@
+use super::team::TeamId;
+use super::unit::UnitId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Repair rates of repair stations and deployed constructors, if present in the world it is used
+/// when these are spawned.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct RepairConfig {
+    /// Health restored per second by a repair station.
+    pub station_rate: f32,
+    /// Radius around a repair station in which units are repaired.
+    pub station_radius: f32,
+    /// Health restored per second by a deployed constructor.
+    pub constructor_rate: f32,
+    /// Radius around a deployed constructor in which units are repaired.
+    pub constructor_radius: f32,
+    /// Duration between repairs, the health is restored in steps of rate times interval.
+    pub interval: f32,
+}
+
+impl Default for RepairConfig {
+    fn default() -> Self {
+        RepairConfig {
+            station_rate: 0.02,
+            station_radius: 4.0,
+            constructor_rate: 0.01,
+            constructor_radius: 4.0,
+            interval: 1.0,
+        }
+    }
+}
+impl Component for RepairConfig {}
+
+/// Restores the health of units within the radius around this entity's pose.
+#[derive(Debug, Clone, Copy)]
+pub struct Repair {
+    radius: f32,
+    amount: f32,
+    interval: f32,
+    team: Option<TeamId>,
+    source: Option<UnitId>,
+    active: bool,
+    last_repair: f32,
+}
+
+impl Repair {
+    /// Create a repair that restores rate health per second, in steps of interval. If a team is
+    /// specified only units of that team are repaired.
+    pub fn new(radius: f32, rate: f32, interval: f32, team: Option<TeamId>) -> Self {
+        Repair {
+            radius,
+            amount: rate * interval,
+            interval,
+            team,
+            source: None,
+            active: true,
+            last_repair: 0.0,
+        }
+    }
+
+    /// The unit performing the repairs, recorded in the repair history.
+    pub fn with_source(self, source: Option<UnitId>) -> Self {
+        Repair { source, ..self }
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Health restored per repair.
+    pub fn amount(&self) -> f32 {
+        self.amount
+    }
+
+    pub fn team(&self) -> Option<TeamId> {
+        self.team
+    }
+
+    pub fn source(&self) -> Option<UnitId> {
+        self.source
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+
+    /// Returns true if it's time to repair again.
+    pub fn is_due(&mut self, current_time: f32) -> bool {
+        if (current_time - self.last_repair) >= self.interval {
+            self.last_repair = current_time;
+            true
+        } else {
+            false
+        }
+    }
+}
+impl Component for Repair {}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RepairRecord {
+    pub amount: f32,
+    pub source: Option<UnitId>,
+    pub time: f32,
+}
+
+impl RepairRecord {
+    pub fn amount(&self) -> f32 {
+        self.amount
+    }
+    pub fn source(&self) -> Option<UnitId> {
+        self.source
+    }
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+}
+
+/// History of repairs received by a unit, lives on the unit entity.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RepairHistory {
+    repairs: Vec<RepairRecord>,
+}
+
+impl RepairHistory {
+    pub fn new() -> Self {
+        RepairHistory { repairs: vec![] }
+    }
+
+    pub fn repairs(&self) -> &[RepairRecord] {
+        &self.repairs
+    }
+
+    pub fn add_repair(&mut self, amount: f32, source: Option<UnitId>, time: f32) {
+        self.repairs.push(RepairRecord {
+            amount,
+            source,
+            time,
+        });
+    }
+
+    pub fn last(&self) -> Option<&RepairRecord> {
+        self.repairs.last()
+    }
+
+    /// Total health restored.
+    pub fn total(&self) -> f32 {
+        self.repairs.iter().map(|r| r.amount).sum()
+    }
+}
+impl Component for RepairHistory {}
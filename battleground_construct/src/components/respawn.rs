@@ -0,0 +1,273 @@
+use super::health::Health;
+use super::team::TeamId;
+use super::team_member::TeamMember;
+use super::unit::Unit;
+use crate::config::setup::scenario_config;
+use engine::prelude::*;
+
+/// Spawns a unit at the provided x, y and yaw, returns the unit entity.
+pub type SpawnFunction = std::rc::Rc<
+    dyn for<'a> Fn(&'a mut World, f32, f32, f32) -> Result<EntityId, Box<dyn std::error::Error>>,
+>;
+
+/// Everything needed to spawn a unit again, with its original spawn location.
+#[derive(Clone)]
+pub struct UnitSpawner {
+    function: SpawnFunction,
+    team: Option<TeamId>,
+    x: f32,
+    y: f32,
+    yaw: f32,
+}
+
+impl UnitSpawner {
+    pub fn new(function: SpawnFunction, team: Option<TeamId>, x: f32, y: f32, yaw: f32) -> Self {
+        UnitSpawner {
+            function,
+            team,
+            x,
+            y,
+            yaw,
+        }
+    }
+
+    pub fn team(&self) -> Option<TeamId> {
+        self.team
+    }
+
+    /// Spawn the unit at its original location.
+    pub fn spawn(&self, world: &mut World) -> Result<EntityId, Box<dyn std::error::Error>> {
+        (self.function)(world, self.x, self.y, self.yaw)
+    }
+
+    /// Spawn the unit at the provided location.
+    pub fn spawn_at(
+        &self,
+        world: &mut World,
+        x: f32,
+        y: f32,
+        yaw: f32,
+    ) -> Result<EntityId, Box<dyn std::error::Error>> {
+        (self.function)(world, x, y, yaw)
+    }
+}
+
+impl std::fmt::Debug for UnitSpawner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnitSpawner")
+            .field("team", &self.team)
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("yaw", &self.yaw)
+            .finish()
+    }
+}
+
+/// Circular zone in which destroyed units respawn.
+#[derive(Debug, Clone, Copy)]
+pub struct RespawnZone {
+    /// Only units of this team respawn here, any team if None.
+    pub team: Option<TeamId>,
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+    pub yaw: f32,
+}
+
+/// Respawn rules, if present in the world units respawn after being destroyed.
+#[derive(Debug, Clone, Default)]
+pub struct RespawnConfig {
+    /// Duration between destruction and respawn, in seconds.
+    pub delay: f32,
+    /// Total number of lives of each unit, unlimited if None.
+    pub lives: Option<u32>,
+    pub zones: Vec<RespawnZone>,
+}
+
+impl RespawnConfig {
+    /// The zone to respawn a unit of this team in, zones for the specific team take precedence.
+    pub fn zone(&self, team: Option<TeamId>) -> Option<RespawnZone> {
+        self.zones
+            .iter()
+            .find(|z| z.team.is_some() && z.team == team)
+            .or_else(|| self.zones.iter().find(|z| z.team.is_none()))
+            .copied()
+    }
+}
+impl Component for RespawnConfig {}
+
+/// Respawns the unit after it is destroyed, lives on the unit entity. Removed once the unit has
+/// respawned, the new unit entity gets it if it has lives remaining.
+#[derive(Debug, Clone)]
+pub struct Respawn {
+    spawner: UnitSpawner,
+    /// Remaining respawns, unlimited if None.
+    respawns: Option<u32>,
+    destroyed_at: Option<f32>,
+}
+
+impl Respawn {
+    pub fn new(spawner: UnitSpawner, respawns: Option<u32>) -> Self {
+        Respawn {
+            spawner,
+            respawns,
+            destroyed_at: None,
+        }
+    }
+
+    pub fn spawner(&self) -> &UnitSpawner {
+        &self.spawner
+    }
+
+    pub fn respawns(&self) -> Option<u32> {
+        self.respawns
+    }
+
+    /// Returns true once the delay since the destruction has passed.
+    pub fn is_due(&mut self, current_time: f32, delay: f32) -> bool {
+        let destroyed_at = *self.destroyed_at.get_or_insert(current_time);
+        (current_time - destroyed_at) >= delay
+    }
+
+    /// The respawn component for the respawned unit, None if no lives remain.
+    pub fn next(&self) -> Option<Respawn> {
+        let respawns = match self.respawns {
+            Some(0) | Some(1) => return None,
+            v => v.map(|r| r - 1),
+        };
+        Some(Respawn::new(self.spawner.clone(), respawns))
+    }
+}
+impl Component for Respawn {}
+
+/// Adds the respawn component to a freshly spawned unit, if respawns are configured.
+pub fn add_respawn(world: &mut World, unit_entity: EntityId, spawner: UnitSpawner) {
+    let lives = if let Some(config) = scenario_config::<RespawnConfig>(world) {
+        config.lives
+    } else {
+        return;
+    };
+    let respawns = lives.map(|l| l.saturating_sub(1));
+    if respawns != Some(0) {
+        world.add_component(unit_entity, Respawn::new(spawner, respawns));
+    }
+}
+
+/// Timed wave of units that join the match.
+#[derive(Debug, Clone)]
+pub struct Reinforcement {
+    next: f32,
+    interval: Option<f32>,
+    remaining: Option<u32>,
+    spawners: Vec<UnitSpawner>,
+}
+
+impl Reinforcement {
+    /// Spawn the units at time, and then every interval for the number of waves. A single wave if
+    /// no interval is provided, unlimited waves if no wave count is provided.
+    pub fn new(
+        time: f32,
+        interval: Option<f32>,
+        waves: Option<u32>,
+        spawners: Vec<UnitSpawner>,
+    ) -> Self {
+        let remaining = if interval.is_some() { waves } else { Some(1) };
+        Reinforcement {
+            next: time,
+            interval,
+            remaining,
+            spawners,
+        }
+    }
+
+    pub fn spawners(&self) -> &[UnitSpawner] {
+        &self.spawners
+    }
+
+    /// Whether waves are still to come.
+    pub fn is_pending(&self) -> bool {
+        self.remaining != Some(0)
+    }
+
+    /// Returns true if the wave is to be spawned now, advances to the next wave.
+    pub fn is_due(&mut self, current_time: f32) -> bool {
+        if !self.is_pending() || current_time < self.next {
+            return false;
+        }
+        self.remaining = self.remaining.map(|r| r - 1);
+        if let Some(interval) = self.interval {
+            self.next += interval;
+        } else {
+            self.remaining = Some(0);
+        }
+        true
+    }
+}
+impl Component for Reinforcement {}
+
+/// The only team that still has units alive, respawns or reinforcements pending, if more than one
+/// team took part in the match. Always None if neither respawns nor reinforcements are configured,
+/// such matches keep ending on their own conditions only.
+pub fn last_team_standing(world: &World) -> Option<TeamId> {
+    if scenario_config::<RespawnConfig>(world).is_none()
+        && world.component_iter::<Reinforcement>().next().is_none()
+    {
+        return None;
+    }
+    let mut participants: std::collections::HashSet<TeamId> = Default::default();
+    let mut in_play: std::collections::HashSet<TeamId> = Default::default();
+    for (entity, _unit) in world.component_iter::<Unit>() {
+        if let Some(team_member) = world.component::<TeamMember>(entity) {
+            participants.insert(team_member.team());
+            if world.component::<Health>(entity).is_some()
+                || world.component::<Respawn>(entity).is_some()
+            {
+                in_play.insert(team_member.team());
+            }
+        }
+    }
+    for (_entity, reinforcement) in world.component_iter::<Reinforcement>() {
+        if reinforcement.is_pending() {
+            for team in reinforcement.spawners().iter().filter_map(|s| s.team()) {
+                participants.insert(team);
+                in_play.insert(team);
+            }
+        }
+    }
+    if participants.len() > 1 && in_play.len() == 1 {
+        in_play.into_iter().next()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_reinforcement_waves() {
+        let spawner = UnitSpawner::new(
+            std::rc::Rc::new(|_: &mut World, _, _, _| Err("unused".into())),
+            None,
+            0.0,
+            0.0,
+            0.0,
+        );
+        let mut once = Reinforcement::new(5.0, None, Some(3), vec![spawner.clone()]);
+        assert!(!once.is_due(4.0));
+        assert!(once.is_due(5.0));
+        assert!(!once.is_due(10.0));
+        assert!(!once.is_pending());
+
+        let mut repeated = Reinforcement::new(5.0, Some(10.0), Some(2), vec![spawner.clone()]);
+        assert!(repeated.is_due(5.0));
+        assert!(!repeated.is_due(10.0));
+        assert!(repeated.is_due(15.0));
+        assert!(!repeated.is_due(25.0));
+
+        let respawn = Respawn::new(spawner, Some(2));
+        let next = respawn.next().unwrap();
+        assert_eq!(next.respawns(), Some(1));
+        assert!(next.next().is_none());
+    }
+}
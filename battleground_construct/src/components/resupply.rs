@@ -0,0 +1,53 @@
+use super::team::TeamId;
+use engine::prelude::*;
+
+/// Replenishes the ammunition of weapons within the radius around this entity's pose.
+#[derive(Debug, Clone, Copy)]
+pub struct Resupply {
+    radius: f32,
+    interval: f32,
+    team: Option<TeamId>,
+    active: bool,
+    last_resupply: f32,
+}
+
+impl Resupply {
+    /// Create a resupply, it provides one round every interval to all weapons in range. If a team
+    /// is specified only weapons of that team are resupplied.
+    pub fn new(radius: f32, interval: f32, team: Option<TeamId>) -> Self {
+        Resupply {
+            radius,
+            interval,
+            team,
+            active: true,
+            last_resupply: 0.0,
+        }
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    pub fn team(&self) -> Option<TeamId> {
+        self.team
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+
+    /// Returns true if it's time to hand out another round.
+    pub fn is_due(&mut self, current_time: f32) -> bool {
+        if (current_time - self.last_resupply) >= self.interval {
+            self.last_resupply = current_time;
+            true
+        } else {
+            false
+        }
+    }
+}
+impl Component for Resupply {}
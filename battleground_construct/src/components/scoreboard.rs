@@ -0,0 +1,268 @@
+use super::team::TeamId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The statistics tracked per team by the scoreboard.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScoreObjective {
+    /// Enemy units destroyed, destroying units of the own team subtracts one.
+    Kills,
+    /// Seconds of capture point ownership, summed over all capture points.
+    CaptureTime,
+    /// Damage dealt to units of other teams.
+    DamageDealt,
+    /// Number of units of the team that are alive.
+    Survival,
+}
+
+impl ScoreObjective {
+    pub const ALL: [ScoreObjective; 4] = [
+        ScoreObjective::Kills,
+        ScoreObjective::CaptureTime,
+        ScoreObjective::DamageDealt,
+        ScoreObjective::Survival,
+    ];
+}
+
+/// Weight of an objective in the total score.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ObjectiveWeight {
+    pub objective: ScoreObjective,
+    pub weight: f32,
+}
+
+/// Scoring of a match, the total score of a team is the weighted sum of its objectives.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ScoringConfig {
+    /// The objectives that contribute to the total score.
+    pub objectives: Vec<ObjectiveWeight>,
+    /// Objectives compared in order if teams have the same total score, teams that are still
+    /// tied share their rank.
+    #[serde(default)]
+    pub tie_breakers: Vec<ScoreObjective>,
+    /// The match finishes when a team reaches this total score.
+    #[serde(default)]
+    pub point_limit: Option<f32>,
+}
+
+/// The value of a single objective in a team's score.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ScoreEntry {
+    pub objective: ScoreObjective,
+    pub value: f32,
+    pub weight: f32,
+}
+
+impl ScoreEntry {
+    /// Contribution of this entry to the total score.
+    pub fn score(&self) -> f32 {
+        self.value * self.weight
+    }
+}
+
+/// Score breakdown of a team.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TeamScore {
+    pub team: TeamId,
+    /// Place of the team, starting at 1, teams that are tied share the rank.
+    pub rank: usize,
+    pub total: f32,
+    pub entries: Vec<ScoreEntry>,
+}
+
+impl TeamScore {
+    pub fn value(&self, objective: ScoreObjective) -> f32 {
+        self.entries
+            .iter()
+            .find(|e| e.objective == objective)
+            .map(|e| e.value)
+            .unwrap_or(0.0)
+    }
+}
+
+/// Tracks the objectives of each team throughout the match.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Scoreboard {
+    config: ScoringConfig,
+    teams: Vec<TeamId>,
+    values: std::collections::HashMap<TeamId, std::collections::HashMap<ScoreObjective, f32>>,
+    last_update: f32,
+}
+
+impl Scoreboard {
+    pub fn new(teams: &[TeamId], config: ScoringConfig) -> Self {
+        Scoreboard {
+            config,
+            teams: teams.to_vec(),
+            values: Default::default(),
+            last_update: 0.0,
+        }
+    }
+
+    pub fn teams(&self) -> &[TeamId] {
+        &self.teams
+    }
+
+    pub fn config(&self) -> &ScoringConfig {
+        &self.config
+    }
+
+    /// Whether the scenario specified objectives to score, if not the ranking is up to the match
+    /// type.
+    pub fn is_scored(&self) -> bool {
+        !self.config.objectives.is_empty()
+    }
+
+    /// Time up to which hits have been counted.
+    pub fn last_update(&self) -> f32 {
+        self.last_update
+    }
+
+    pub fn set_last_update(&mut self, time: f32) {
+        self.last_update = time;
+    }
+
+    pub fn add(&mut self, team: TeamId, objective: ScoreObjective, amount: f32) {
+        *self
+            .values
+            .entry(team)
+            .or_default()
+            .entry(objective)
+            .or_insert(0.0) += amount;
+    }
+
+    pub fn set(&mut self, team: TeamId, objective: ScoreObjective, value: f32) {
+        self.values
+            .entry(team)
+            .or_default()
+            .insert(objective, value);
+    }
+
+    pub fn value(&self, team: TeamId, objective: ScoreObjective) -> f32 {
+        self.values
+            .get(&team)
+            .and_then(|v| v.get(&objective))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn weight(&self, objective: ScoreObjective) -> f32 {
+        self.config
+            .objectives
+            .iter()
+            .filter(|o| o.objective == objective)
+            .map(|o| o.weight)
+            .sum()
+    }
+
+    pub fn total(&self, team: TeamId) -> f32 {
+        ScoreObjective::ALL
+            .iter()
+            .map(|o| self.value(team, *o) * self.weight(*o))
+            .sum()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        if let Some(limit) = self.config.point_limit {
+            return self.teams.iter().any(|t| self.total(*t) >= limit);
+        }
+        false
+    }
+
+    fn team_score(&self, team: TeamId) -> TeamScore {
+        TeamScore {
+            team,
+            rank: 1,
+            total: self.total(team),
+            entries: ScoreObjective::ALL
+                .iter()
+                .map(|o| ScoreEntry {
+                    objective: *o,
+                    value: self.value(team, *o),
+                    weight: self.weight(*o),
+                })
+                .collect(),
+        }
+    }
+
+    /// The scores ranked by their total, then by the tie breakers.
+    pub fn scores(&self) -> Vec<TeamScore> {
+        self.scores_by(|team| {
+            std::iter::once(self.total(team))
+                .chain(
+                    self.config
+                        .tie_breakers
+                        .iter()
+                        .map(|o| self.value(team, *o)),
+                )
+                .collect()
+        })
+    }
+
+    /// The scores ranked by the provided key of each team, compared in order with the highest
+    /// ranked first. Teams with equal keys share their rank.
+    pub fn scores_by<F: Fn(TeamId) -> Vec<f32>>(&self, key: F) -> Vec<TeamScore> {
+        let mut scores = self
+            .teams
+            .iter()
+            .map(|t| (key(*t), self.team_score(*t)))
+            .collect::<Vec<_>>();
+        scores.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        for i in 1..scores.len() {
+            scores[i].1.rank = if scores[i].0 == scores[i - 1].0 {
+                scores[i - 1].1.rank
+            } else {
+                i + 1
+            };
+        }
+        scores.into_iter().map(|(_, s)| s).collect()
+    }
+}
+impl Component for Scoreboard {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+    #[test]
+    fn test_scoreboard_ranking() {
+        let (a, b, c) = (make_team_id(1), make_team_id(2), make_team_id(3));
+        let config = ScoringConfig {
+            objectives: vec![
+                ObjectiveWeight {
+                    objective: ScoreObjective::Kills,
+                    weight: 10.0,
+                },
+                ObjectiveWeight {
+                    objective: ScoreObjective::CaptureTime,
+                    weight: 1.0,
+                },
+            ],
+            tie_breakers: vec![ScoreObjective::DamageDealt],
+            point_limit: Some(100.0),
+        };
+        let mut scoreboard = Scoreboard::new(&[a, b, c], config);
+        scoreboard.add(a, ScoreObjective::Kills, 2.0);
+        scoreboard.add(b, ScoreObjective::CaptureTime, 20.0);
+        scoreboard.add(b, ScoreObjective::DamageDealt, 0.5);
+        scoreboard.add(c, ScoreObjective::Kills, 1.0);
+        scoreboard.add(c, ScoreObjective::CaptureTime, 10.0);
+        scoreboard.add(c, ScoreObjective::DamageDealt, 0.5);
+        assert_eq!(scoreboard.total(a), 20.0);
+        assert!(!scoreboard.is_finished());
+
+        // All at 20 points, the damage tie breaker puts b and c first.
+        let scores = scoreboard.scores();
+        assert_eq!(scores[0].rank, 1);
+        assert_eq!(scores[1].rank, 1);
+        assert_eq!(scores[2].team, a);
+        assert_eq!(scores[2].rank, 3);
+
+        let scores = scoreboard.scores_by(|t| vec![(t == c) as u8 as f32]);
+        assert_eq!(scores[0].team, c);
+        assert!(scores[1..].iter().all(|s| s.rank == 2));
+
+        scoreboard.add(a, ScoreObjective::Kills, 8.0);
+        assert!(scoreboard.is_finished());
+    }
+}
@@ -0,0 +1,62 @@
+use super::hit_zone::Subsystem;
+use engine::prelude::*;
+
+/// Damage dealt to a hit zone is multiplied by this before it is subtracted from the subsystem.
+pub const SUBSYSTEM_DAMAGE_SCALE: f32 = 1.5;
+
+/// Fraction of the capability that remains when a subsystem is fully destroyed.
+pub const SUBSYSTEM_MINIMUM_CAPABILITY: f32 = 0.25;
+
+/// Tracks the health of the individual subsystems of a unit, lives on the unit entity.
+#[derive(Debug, Clone, Copy)]
+pub struct SubsystemHealth {
+    tracks: f32,
+    radar: f32,
+    weapon: f32,
+}
+
+impl Default for SubsystemHealth {
+    fn default() -> Self {
+        SubsystemHealth::new()
+    }
+}
+
+impl SubsystemHealth {
+    pub fn new() -> Self {
+        SubsystemHealth {
+            tracks: 1.0,
+            radar: 1.0,
+            weapon: 1.0,
+        }
+    }
+
+    fn value_mut(&mut self, subsystem: Subsystem) -> &mut f32 {
+        match subsystem {
+            Subsystem::Tracks => &mut self.tracks,
+            Subsystem::Radar => &mut self.radar,
+            Subsystem::Weapon => &mut self.weapon,
+        }
+    }
+
+    /// Health of the subsystem, between 0.0 and 1.0.
+    pub fn health(&self, subsystem: Subsystem) -> f32 {
+        match subsystem {
+            Subsystem::Tracks => self.tracks,
+            Subsystem::Radar => self.radar,
+            Subsystem::Weapon => self.weapon,
+        }
+    }
+
+    /// Subtract damage from a subsystem, returning the new health.
+    pub fn subtract(&mut self, subsystem: Subsystem, damage: f32) -> f32 {
+        let v = self.value_mut(subsystem);
+        *v = (*v - damage * SUBSYSTEM_DAMAGE_SCALE).clamp(0.0, 1.0);
+        *v
+    }
+
+    /// The fraction of the capability that remains for this subsystem.
+    pub fn capability(&self, subsystem: Subsystem) -> f32 {
+        SUBSYSTEM_MINIMUM_CAPABILITY + (1.0 - SUBSYSTEM_MINIMUM_CAPABILITY) * self.health(subsystem)
+    }
+}
+impl Component for SubsystemHealth {}
@@ -0,0 +1,140 @@
+use super::team::TeamId;
+use super::unit::UnitId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Enables the team blackboard, if present in the world each team gets a blackboard.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct TeamBlackboardConfig {
+    /// Size of the blackboard of each team, in bytes.
+    pub size: usize,
+}
+
+impl Default for TeamBlackboardConfig {
+    fn default() -> Self {
+        TeamBlackboardConfig { size: 64 }
+    }
+}
+impl Component for TeamBlackboardConfig {}
+
+/// Byte region shared by all units of a team. Writes are collected while the controllers run and
+/// applied afterwards, in the order of the unit id.
+#[derive(Debug, Clone)]
+pub struct TeamBlackboard {
+    team: TeamId,
+    data: Vec<u8>,
+    pending: Vec<(UnitId, usize, u8)>,
+}
+
+impl TeamBlackboard {
+    pub fn new(team: TeamId, size: usize) -> Self {
+        TeamBlackboard {
+            team,
+            data: vec![0; size],
+            pending: vec![],
+        }
+    }
+
+    pub fn team(&self) -> TeamId {
+        self.team
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Queue a write of the bytes that differ from the current contents, starting at the first
+    /// byte of the blackboard.
+    pub fn write(&mut self, unit: UnitId, values: &[u8]) {
+        for (index, (new, old)) in values.iter().zip(self.data.iter()).enumerate() {
+            if new != old {
+                self.pending.push((unit, index, *new));
+            }
+        }
+    }
+
+    /// Apply the queued writes, writes by units with a higher id take precedence.
+    pub fn apply_writes(&mut self) {
+        self.pending.sort_by_key(|(unit, _, _)| *unit);
+        for (_unit, index, value) in self.pending.drain(..) {
+            self.data[index] = value;
+        }
+    }
+}
+impl Component for TeamBlackboard {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::team_blackboard::*;
+
+pub struct TeamBlackboardModule {
+    entity: EntityId,
+}
+
+impl TeamBlackboardModule {
+    pub fn new(entity: EntityId) -> Self {
+        TeamBlackboardModule { entity }
+    }
+}
+
+impl UnitModule for TeamBlackboardModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        let team = world
+            .component::<super::team_member::TeamMember>(self.entity)
+            .map(|t| t.team());
+        let data = world
+            .component_iter::<TeamBlackboard>()
+            .find(|(_, b)| Some(b.team()) == team)
+            .map(|(_, b)| b.data().to_vec())
+            .unwrap_or_default();
+        registers.insert(
+            REG_TEAM_BLACKBOARD_SIZE,
+            Register::new_i32("size", data.len() as i32),
+        );
+        let mut register = Register::new_bytes_max("data", data.len());
+        *register.value_bytes_mut().unwrap() = data;
+        registers.insert(REG_TEAM_BLACKBOARD_DATA, register);
+    }
+
+    fn set_component(&self, world: &mut World, registers: &RegisterMap) {
+        let team = world
+            .component::<super::team_member::TeamMember>(self.entity)
+            .map(|t| t.team());
+        let unit = if let Some(unit) = world.component::<super::unit::Unit>(self.entity) {
+            unit.id()
+        } else {
+            return;
+        };
+        let values = registers
+            .get(&REG_TEAM_BLACKBOARD_DATA)
+            .expect("register doesnt exist")
+            .value_bytes()
+            .expect("wrong value type");
+        if let Some((_, mut blackboard)) = world
+            .component_iter_mut::<TeamBlackboard>()
+            .find(|(_, b)| Some(b.team()) == team)
+        {
+            blackboard.write(unit, values);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+    use crate::components::unit::make_unit_id;
+    #[test]
+    fn test_write_order() {
+        let mut blackboard = TeamBlackboard::new(make_team_id(1), 4);
+        // Unchanged bytes are not written, the higher unit id wins on conflicts.
+        blackboard.write(make_unit_id(5), &[1, 0, 0, 2]);
+        blackboard.write(make_unit_id(3), &[9, 7]);
+        blackboard.apply_writes();
+        assert_eq!(blackboard.data(), &[1, 7, 0, 2]);
+
+        blackboard.write(make_unit_id(3), &[1, 7, 0, 2, 8]);
+        blackboard.apply_writes();
+        assert_eq!(blackboard.data(), &[1, 7, 0, 2]);
+    }
+}
@@ -0,0 +1,76 @@
+use super::team::TeamId;
+use super::unit::UnitId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FlagState {
+    /// The flag is at its base.
+    AtBase,
+    /// An enemy unit is carrying the flag.
+    Carried,
+    /// The carrier got destroyed, the flag lies where it was dropped.
+    Dropped,
+}
+
+/// The flag of a team in a capture the flag match, lives on the flag entity.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct TeamFlag {
+    team: TeamId,
+    base_x: f32,
+    base_y: f32,
+    radius: f32,
+    state: FlagState,
+    carrier: Option<UnitId>,
+}
+
+impl TeamFlag {
+    /// Create a flag for the team, the base is at x, y. Units pick up the flag or capture at the
+    /// base when within radius.
+    pub fn new(team: TeamId, base_x: f32, base_y: f32, radius: f32) -> Self {
+        TeamFlag {
+            team,
+            base_x,
+            base_y,
+            radius,
+            state: FlagState::AtBase,
+            carrier: None,
+        }
+    }
+
+    pub fn team(&self) -> TeamId {
+        self.team
+    }
+
+    pub fn base(&self) -> (f32, f32) {
+        (self.base_x, self.base_y)
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    pub fn state(&self) -> FlagState {
+        self.state
+    }
+
+    pub fn carrier(&self) -> Option<UnitId> {
+        self.carrier
+    }
+
+    pub fn pick_up(&mut self, carrier: UnitId) {
+        self.state = FlagState::Carried;
+        self.carrier = Some(carrier);
+    }
+
+    pub fn set_dropped(&mut self) {
+        self.state = FlagState::Dropped;
+        self.carrier = None;
+    }
+
+    pub fn return_to_base(&mut self) {
+        self.state = FlagState::AtBase;
+        self.carrier = None;
+    }
+}
+impl Component for TeamFlag {}
@@ -0,0 +1,30 @@
+use crate::util::heightmap::Heightmap;
+use engine::prelude::*;
+
+/// The terrain surface, in world coordinates. Projectiles impact on it and it blocks radar.
+#[derive(Debug, Clone)]
+pub struct Terrain {
+    heightmap: Heightmap,
+}
+
+impl Terrain {
+    pub fn new(heightmap: Heightmap) -> Self {
+        Terrain { heightmap }
+    }
+
+    pub fn heightmap(&self) -> &Heightmap {
+        &self.heightmap
+    }
+}
+impl Component for Terrain {}
+
+/// Marker for entities that drive on the terrain, their pose follows the surface.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TerrainFollower {}
+
+impl TerrainFollower {
+    pub fn new() -> Self {
+        TerrainFollower {}
+    }
+}
+impl Component for TerrainFollower {}
@@ -29,6 +29,13 @@ pub struct TricycleBase {
 
     // #[serde(skip)]
     steering_joint: EntityId,
+
+    #[serde(skip, default = "default_velocity_scale")]
+    velocity_scale: f32,
+}
+
+fn default_velocity_scale() -> f32 {
+    1.0
 }
 
 impl TricycleBase {
@@ -38,14 +45,18 @@ impl TricycleBase {
             config,
             wheel_velocity_cmd: 0.0,
             wheel_velocity_vel: 0.0,
+            velocity_scale: 1.0,
         }
     }
 
     pub fn set_velocity(&mut self, desired: f32) {
-        self.wheel_velocity_cmd = desired.clamp(
-            self.config.wheel_velocity_bounds.0,
-            self.config.wheel_velocity_bounds.1,
-        );
+        let bounds = self.wheel_velocity_bounds();
+        self.wheel_velocity_cmd = desired.clamp(bounds.0, bounds.1);
+    }
+
+    /// Scale the velocity bounds, used to degrade the base when its wheels are damaged.
+    pub fn set_velocity_scale(&mut self, scale: f32) {
+        self.velocity_scale = scale;
     }
 
     pub fn wheel_base(&self) -> f32 {
@@ -68,7 +79,10 @@ impl TricycleBase {
     }
 
     pub fn wheel_velocity_bounds(self) -> (f32, f32) {
-        self.config.wheel_velocity_bounds
+        (
+            self.config.wheel_velocity_bounds.0 * self.velocity_scale,
+            self.config.wheel_velocity_bounds.1 * self.velocity_scale,
+        )
     }
 
     pub fn wheel_acceleration_bounds(&self) -> Option<(f32, f32)> {
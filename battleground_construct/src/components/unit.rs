@@ -93,6 +93,39 @@ impl UnitModule for UnitModuleComponent {
             battleground_unit_control::modules::unit::REG_UNIT_UNIT_TYPE,
             Register::new_i32("unit_type", value),
         );
+        use battleground_unit_control::modules::unit::*;
+        let value = world
+            .component::<super::health::Health>(self.unit_entity)
+            .map(|h| h.health())
+            .unwrap_or(1.0);
+        registers.insert(REG_UNIT_HEALTH, Register::new_f32("health", value));
+        let subsystems = world
+            .component::<super::subsystem_health::SubsystemHealth>(self.unit_entity)
+            .map(|v| *v)
+            .unwrap_or_default();
+        use super::hit_zone::Subsystem;
+        for (register, name, subsystem) in [
+            (
+                REG_UNIT_SUBSYSTEM_TRACKS,
+                "subsystem_tracks",
+                Subsystem::Tracks,
+            ),
+            (
+                REG_UNIT_SUBSYSTEM_RADAR,
+                "subsystem_radar",
+                Subsystem::Radar,
+            ),
+            (
+                REG_UNIT_SUBSYSTEM_WEAPON,
+                "subsystem_weapon",
+                Subsystem::Weapon,
+            ),
+        ] {
+            registers.insert(
+                register,
+                Register::new_f32(name, subsystems.health(subsystem)),
+            );
+        }
     }
 }
 
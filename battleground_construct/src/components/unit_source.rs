@@ -1,7 +1,9 @@
 use super::unit::UnitId;
 use engine::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy)]
+/// The unit that created this entity, like the projectiles it fires or the effects it causes.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
 pub struct UnitSource {
     source: UnitId,
 }
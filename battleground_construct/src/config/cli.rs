@@ -95,6 +95,11 @@ pub struct Scenario {
     /// Overwrite or apply the time limit.
     #[arg(short = 'l', long)]
     time_limit: Option<f32>,
+
+    /// Trace all register access by a unit's controller to a file, for offline replay.
+    /// Use with --trace unit_id:path_to_trace.txt
+    #[arg(long, verbatim_doc_comment)]
+    trace: Option<String>,
 }
 
 /// This creates a config struct handled by the wrap up functionality
@@ -119,7 +124,7 @@ pub fn command_to_wrap_up(command: &Commands) -> Result<WrapUpConfig, Box<dyn st
     };
 
     let scenario = match setup {
-        Setup::Scenario(config) => Some(config),
+        Setup::Scenario(config) => Some(*config),
         _ => None,
     };
 
@@ -132,7 +137,7 @@ pub fn command_to_wrap_up(command: &Commands) -> Result<WrapUpConfig, Box<dyn st
 }
 
 pub enum Setup {
-    Scenario(ScenarioConfig),
+    Scenario(Box<ScenarioConfig>),
     Play(String),
     PlayBytes(Vec<u8>),
 }
@@ -174,6 +179,16 @@ pub fn command_to_setup(command: &Commands) -> Result<Setup, Box<dyn std::error:
                 specification.recording = true;
             }
 
+            if let Some(trace) = scenario.trace.as_ref() {
+                let (unit_id, path) = trace.split_once(':').ok_or_else(|| {
+                    Box::<dyn std::error::Error>::from("expected ':' between unit id and path")
+                })?;
+                specification.trace = Some(super::specification::TraceConfig {
+                    unit_id: unit_id.parse()?,
+                    path: path.to_owned(),
+                });
+            }
+
             #[cfg(not(feature = "unit_control_wasm"))]
             let extra_config: Vec<String> = vec![];
 
@@ -214,7 +229,7 @@ pub fn command_to_setup(command: &Commands) -> Result<Setup, Box<dyn std::error:
                 .chain(extra_config.iter())
                 .map(|v| v.as_str())
                 .collect();
-            apply_config(&config_strs, specification).map(Setup::Scenario)
+            apply_config(&config_strs, specification).map(|v| Setup::Scenario(Box::new(v)))
         }
         Commands::Play(play) => Ok(Setup::Play(play.file.clone())),
         Commands::Recording(subcommand) => {
@@ -519,12 +534,14 @@ mod test {
                         color: (255, 0, 0),
                         controller: None,
                         comment: None,
+                        budget: None,
                     },
                     Team {
                         name: "blue".to_owned(),
                         color: (0, 255, 0),
                         controller: None,
                         comment: None,
+                        budget: None,
                     },
                 ],
                 ..Default::default()
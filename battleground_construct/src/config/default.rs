@@ -15,6 +15,10 @@ pub fn add_components(world: &mut World) {
     // Add the generator for ids
     let generator_id = world.add_entity();
     world.add_component(generator_id, components::id_generator::IdGenerator::new());
+
+    // And the source of randomness, seeded by the scenario.
+    let random_id = world.add_entity();
+    world.add_component(random_id, components::random::Random::new(0));
 }
 
 pub fn add_systems(systems: &mut Systems) {
@@ -29,9 +33,16 @@ pub fn add_systems(systems: &mut Systems) {
     systems.add_system(Box::new(
         systems::match_logic_king_of_the_hill::MatchLogicKingOfTheHill {},
     ));
+    systems.add_system(Box::new(
+        systems::match_logic_scoreboard::MatchLogicScoreboard {},
+    ));
     systems.add_system(Box::new(
         systems::match_logic_team_deathmatch::MatchLogicTeamDeathmatch {},
     ));
+    systems.add_system(Box::new(
+        systems::match_logic_capture_the_flag::MatchLogicCaptureTheFlag {},
+    ));
+    systems.add_system(Box::new(systems::match_logic_escort::MatchLogicEscort {}));
     systems.add_system(Box::new(
         systems::match_logic_domination::MatchLogicDomination {},
     ));
@@ -52,7 +63,13 @@ pub fn add_systems(systems: &mut Systems) {
     systems.add_system(Box::new(
         systems::acceleration_velocity::AccelerationVelocity {},
     ));
+    // Steer the missiles, this sets their velocity.
+    systems.add_system(Box::new(systems::missile_flight::MissileFlight {}));
     systems.add_system(Box::new(systems::velocity_pose::VelocityPose {}));
+    // Resolve any penetration caused by the velocity integration.
+    systems.add_system(Box::new(systems::collision::Collision {}));
+    // Keep vehicles on the terrain surface.
+    systems.add_system(Box::new(systems::terrain_follow::TerrainFollow {}));
 
     // Update performs revolute integration, pose and velocity set.
     systems.add_system(Box::new(systems::revolute_update::RevoluteUpdate {}));
@@ -61,6 +78,8 @@ pub fn add_systems(systems: &mut Systems) {
 
     // Projectile system handling, hit calculation, impact processing
     systems.add_system(Box::new(systems::projectile_hit::ProjectileHit {}));
+    // Detonated mines become impacts, before those are processed.
+    systems.add_system(Box::new(systems::mine_trigger::MineTrigger {}));
     systems.add_system(Box::new(systems::process_impact::ProcessImpact {}));
     // ProcessHitBy MUST go after the hit calculation.
     systems.add_system(Box::new(systems::process_hit_by::ProcessHitBy {}));
@@ -71,6 +90,11 @@ pub fn add_systems(systems: &mut Systems) {
     // Destroy anything marked as destroyed by the health check.
     systems.add_system(Box::new(systems::destroy::Destroy {}));
 
+    // Bring back destroyed units and spawn reinforcements, after the destroy such that the
+    // destroyed unit is cleaned up.
+    systems.add_system(Box::new(systems::respawn::RespawnSystem {}));
+    systems.add_system(Box::new(systems::respawn::ReinforcementSystem {}));
+
     // Coloring / display systems, they don't really matter when they go.
     // systems.add_system(Box::new(systems::health_tank_body::HealthTankBody {}));
     systems.add_system(Box::new(systems::team_color_body::TeamColorBody {}));
@@ -92,10 +116,17 @@ pub fn add_systems(systems: &mut Systems) {
     // Calculate the radio messagse.
     systems.add_system(Box::new(systems::radio_transmission::RadioTransmission {}));
 
+    // Update the gps and imu readings.
+    systems.add_system(Box::new(systems::sensor_update::SensorUpdate {}));
+
     // Calculate the radar hits
     systems.add_system(Box::new(systems::radar_scan::RadarScan {}));
     // Run the unit controllers
     systems.add_system(Box::new(systems::unit_control::UnitControl {}));
+    // Apply the team blackboard writes of the controllers, in order of the unit id.
+    systems.add_system(Box::new(
+        systems::team_blackboard_update::TeamBlackboardUpdate {},
+    ));
 
     // After the unit controller, check if any controllers errored.
     systems.add_system(Box::new(
@@ -111,4 +142,8 @@ pub fn add_systems(systems: &mut Systems) {
 
     // Run other systems
     systems.add_system(Box::new(systems::deploy::Deploy {}));
+    systems.add_system(Box::new(systems::construction::ConstructionSystem {}));
+    systems.add_system(Box::new(systems::mine_layer::MineLayerSystem {}));
+    systems.add_system(Box::new(systems::resupply::ResupplySystem {}));
+    systems.add_system(Box::new(systems::repair::RepairSystem {}));
 }
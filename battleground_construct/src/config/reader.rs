@@ -28,7 +28,7 @@ fn load_yaml_config(
     }
 }
 
-static BUILTINS_SCENARIO: [(&str, &[u8]); 13] = [
+static BUILTINS_SCENARIO: [(&str, &[u8]); 16] = [
     ("test", include_bytes!("scenario/test.yaml")),
     ("playground", b"pre_setup: playground\n"),
     (
@@ -60,6 +60,15 @@ static BUILTINS_SCENARIO: [(&str, &[u8]); 13] = [
     ("match_demo", include_bytes!("scenario/match_demo.yaml")),
     ("unit_lineup", include_bytes!("scenario/unit_lineup.yaml")),
     ("unit_arm", include_bytes!("scenario/unit_arm.yaml")),
+    (
+        "test_obstacles",
+        include_bytes!("scenario/test_obstacles.yaml"),
+    ),
+    ("test_terrain", include_bytes!("scenario/test_terrain.yaml")),
+    (
+        "test_free_for_all",
+        include_bytes!("scenario/test_free_for_all.yaml"),
+    ),
 ];
 
 pub fn get_builtin_scenario(
@@ -4,6 +4,7 @@ use super::specification;
 use crate::components;
 use crate::systems;
 use crate::units;
+use crate::util::heightmap::Heightmap;
 use crate::Construct;
 use battleground_unit_control::UnitControl;
 
@@ -70,6 +71,155 @@ fn setup_playback_common(
     Ok(construct)
 }
 
+fn controller_type_to_control(
+    controller_type: &specification::ControllerType,
+    control_config: &std::collections::HashMap<String, specification::ControllerType>,
+    team_config: &std::collections::HashMap<String, specification::Team>,
+) -> Result<Box<dyn UnitControl>, Box<dyn std::error::Error>> {
+    Ok(match controller_type {
+        specification::ControllerType::SwivelShoot => {
+            Box::new(unit_control_builtin::tank_swivel_shoot::TankSwivelShoot::new())
+        }
+        specification::ControllerType::Idle => {
+            Box::new(unit_control_builtin::idle::Idle {})
+        }
+        specification::ControllerType::RadioPosition => {
+            Box::new(unit_control_builtin::radio_position::RadioPosition {})
+        }
+        specification::ControllerType::InterfacePrinter => {
+            Box::new(unit_control_builtin::interface_printer::InterfacePrinter {})
+        }
+        specification::ControllerType::NaiveShoot => {
+            Box::new(unit_control_builtin::naive_shoot::NaiveShoot::new())
+        }
+        specification::ControllerType::DiffDriveForwardsBackwards{velocities, duration} => {
+            Box::new(unit_control_builtin::diff_drive_forwards_backwards::DiffDriveForwardsBackwardsControl::new(*velocities, *duration))
+        }
+        specification::ControllerType::DiffDriveCapturable => {
+            Box::new(unit_control_builtin::diff_drive_capturable::DiffDriveCapturable {})
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        specification::ControllerType::LibraryLoad { name } => {
+            unit_control_builtin::dynamic_load_control::DynamicLoadControl::new(name)?
+        }
+        #[cfg(feature = "unit_control_wasm")]
+        specification::ControllerType::Wasm(wasmconfig) => {
+            let config = unit_control_wasm::UnitControlWasmConfig {
+                wasm_path: wasmconfig.path.clone().into(),
+                fuel_per_update: wasmconfig.fuel_per_update,
+                reload: wasmconfig.reload,
+                fuel_for_setup: wasmconfig.fuel_for_setup,
+            };
+            Box::new(unit_control_wasm::UnitControlWasm::new_with_config(config)?)
+        }
+        specification::ControllerType::SequenceControl { controllers } => {
+            let mut v = vec![];
+            for t in controllers.iter() {
+                v.push(controller_type_to_control(t, control_config, team_config)?);
+            }
+            Box::new(unit_control_builtin::sequence_control::SequenceControl::new(v))
+        }
+        specification::ControllerType::Function ( f ) => {
+            f()
+        }
+        specification::ControllerType::FromControlConfig{ name } => {
+            let subcontrol = control_config.get(name).ok_or_else(|| {
+                SetupError::new(&format!("requested controller {name} not found"))})?;
+            controller_type_to_control(subcontrol, control_config, team_config)?
+        }
+        specification::ControllerType::TeamController{ name } => {
+            let subcontrol = team_config.get(name).ok_or_else(|| {
+                SetupError::new(&format!("controlller for requested team {name} not found"))})?;
+            let subcontrol = subcontrol.controller.as_ref().ok_or_else(||{
+                SetupError::new(&format!("team {name} doesn't have a controller but is necessary"))})?;
+            controller_type_to_control(subcontrol, control_config, team_config)?
+        }
+    })
+}
+
+fn spawn_unit(
+    world: &mut engine::World,
+    spawn: &specification::Spawn,
+    team_member: Option<components::team_member::TeamMember>,
+    (x, y, yaw): (f32, f32, f32),
+    control_config: &std::collections::HashMap<String, specification::ControllerType>,
+    team_config: &std::collections::HashMap<String, specification::Team>,
+) -> Result<engine::EntityId, Box<dyn std::error::Error>> {
+    let controller: Box<dyn UnitControl> =
+        controller_type_to_control(&spawn.controller, control_config, team_config)?;
+    Ok(match spawn.unit {
+        specification::Unit::Tank => {
+            let unit_config = units::tank::TankSpawnConfig {
+                x,
+                y,
+                yaw,
+                controller,
+                team_member,
+                radio_config: Some(spawn.radio),
+            };
+            units::tank::spawn_tank(world, unit_config)
+        }
+        specification::Unit::Artillery => {
+            let unit_config = units::artillery::ArtillerySpawnConfig {
+                x,
+                y,
+                yaw,
+                controller,
+                team_member,
+                radio_config: Some(spawn.radio),
+            };
+            units::artillery::spawn_artillery(world, unit_config)
+        }
+        specification::Unit::Arm => {
+            let unit_config = units::arm::ArmSpawnConfig {
+                x,
+                y,
+                yaw,
+                controller,
+            };
+            units::arm::spawn_arm(world, unit_config)
+        }
+        specification::Unit::Constructor => {
+            let unit_config = units::constructor::ConstructorSpawnConfig {
+                x,
+                y,
+                yaw,
+                controller,
+                team_member,
+                radio_config: Some(spawn.radio),
+            };
+            units::constructor::spawn_constructor(world, unit_config)
+        }
+        specification::Unit::Scout => {
+            let unit_config = units::scout::ScoutSpawnConfig {
+                x,
+                y,
+                yaw,
+                controller,
+                team_member,
+                radio_config: Some(spawn.radio),
+            };
+            units::scout::spawn_scout(world, unit_config)
+        }
+        specification::Unit::MissileLauncher => {
+            let unit_config = units::missile_launcher::MissileLauncherSpawnConfig {
+                x,
+                y,
+                yaw,
+                controller,
+                team_member,
+                radio_config: Some(spawn.radio),
+            };
+            units::missile_launcher::spawn_missile_launcher(world, unit_config)
+        }
+    })
+}
+
+/// The scenario-wide config of type `T`, None if the scenario doesn't specify it.
+pub fn scenario_config<T: engine::Component + Clone + 'static>(world: &engine::World) -> Option<T> {
+    world.component_iter::<T>().next().map(|(_, c)| c.clone())
+}
+
 pub fn setup_scenario(
     config: &super::specification::ScenarioConfig,
 ) -> Result<Construct, Box<dyn std::error::Error>> {
@@ -108,6 +258,38 @@ pub fn setup_scenario(
 
     let world = &mut construct.world;
 
+    // Seed the randomness.
+    if let Some((_, mut random)) = world
+        .component_iter_mut::<components::random::Random>()
+        .next()
+    {
+        *random = components::random::Random::new(config.seed);
+    }
+
+    // Scenario-wide configs, these must be present before the units are spawned.
+    let config_entity = world.add_entity();
+    if let Some(radar_noise) = config.radar_noise {
+        world.add_component(config_entity, radar_noise);
+    }
+    if let Some(ammunition) = config.ammunition {
+        world.add_component(config_entity, ammunition);
+    }
+    if let Some(construction) = config.construction {
+        world.add_component(config_entity, construction);
+    }
+    if let Some(mines) = config.mines {
+        world.add_component(config_entity, mines);
+    }
+    if let Some(repair) = config.repair {
+        world.add_component(config_entity, repair);
+    }
+    if let Some(gps) = config.gps.clone() {
+        world.add_component(config_entity, gps);
+    }
+    if let Some(team_blackboard) = config.team_blackboard {
+        world.add_component(config_entity, team_blackboard);
+    }
+
     // Add teams
     let mut team_set = std::collections::HashMap::<String, specification::Team>::new();
     let mut teams = vec![];
@@ -126,11 +308,59 @@ pub fn setup_scenario(
         }
         team_set.insert(team.name.to_owned(), team.clone());
         teams.push(team_component.id());
+        if let Some(team_blackboard) = config.team_blackboard {
+            world.add_component(
+                team_entity,
+                components::team_blackboard::TeamBlackboard::new(
+                    team_component.id(),
+                    team_blackboard.size,
+                ),
+            );
+        }
         world.add_component(team_entity, team_component);
+        if let Some(budget) = team.budget {
+            world.add_component(
+                team_entity,
+                components::construction::TeamBudget::new(budget),
+            );
+        }
     }
 
-    // Spawn units
-    for spawn in config.spawn_config.spawns.iter() {
+    // Add the respawn rules, before the units are spawned.
+    if let Some(respawn) = &config.spawn_config.respawn {
+        let mut zones = vec![];
+        for zone in respawn.zones.iter() {
+            let team = if let Some(team_index) = zone.team {
+                let team_id = teams
+                    .get(team_index)
+                    .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?;
+                Some(*team_id)
+            } else {
+                None
+            };
+            zones.push(components::respawn::RespawnZone {
+                team,
+                x: zone.x,
+                y: zone.y,
+                radius: zone.radius,
+                yaw: zone.yaw,
+            });
+        }
+        world.add_component(
+            config_entity,
+            components::respawn::RespawnConfig {
+                delay: respawn.delay,
+                lives: respawn.lives,
+                zones,
+            },
+        );
+    }
+
+    // Creates the spawner that can spawn this unit, also used to respawn it later.
+    let make_spawner = |spawn: &specification::Spawn| -> Result<
+        components::respawn::UnitSpawner,
+        Box<dyn std::error::Error>,
+    > {
         let optional_team_component = if let Some(team_index) = spawn.team {
             let team_entity = teams
                 .get(team_index)
@@ -139,122 +369,119 @@ pub fn setup_scenario(
         } else {
             None
         };
+        let spec = spawn.clone();
+        let control_config = config.spawn_config.control_config.clone();
+        let team_config = team_set.clone();
+        let function: components::respawn::SpawnFunction =
+            std::rc::Rc::new(move |world, x, y, yaw| {
+                spawn_unit(
+                    world,
+                    &spec,
+                    optional_team_component,
+                    (x, y, yaw),
+                    &control_config,
+                    &team_config,
+                )
+            });
+        Ok(components::respawn::UnitSpawner::new(
+            function,
+            optional_team_component.map(|t| t.team()),
+            spawn.x,
+            spawn.y,
+            spawn.yaw,
+        ))
+    };
 
-        fn controller_type_to_control(
-            controller_type: &specification::ControllerType,
-            control_config: &std::collections::HashMap<String, specification::ControllerType>,
-            team_config: &std::collections::HashMap<String, specification::Team>,
-        ) -> Result<Box<dyn UnitControl>, Box<dyn std::error::Error>> {
-            Ok(match controller_type {
-                specification::ControllerType::SwivelShoot => {
-                    Box::new(unit_control_builtin::tank_swivel_shoot::TankSwivelShoot::new())
-                }
-                specification::ControllerType::Idle => {
-                    Box::new(unit_control_builtin::idle::Idle {})
-                }
-                specification::ControllerType::RadioPosition => {
-                    Box::new(unit_control_builtin::radio_position::RadioPosition {})
-                }
-                specification::ControllerType::InterfacePrinter => {
-                    Box::new(unit_control_builtin::interface_printer::InterfacePrinter {})
-                }
-                specification::ControllerType::NaiveShoot => {
-                    Box::new(unit_control_builtin::naive_shoot::NaiveShoot::new())
-                }
-                specification::ControllerType::DiffDriveForwardsBackwards{velocities, duration} => {
-                    Box::new(unit_control_builtin::diff_drive_forwards_backwards::DiffDriveForwardsBackwardsControl::new(*velocities, *duration))
-                }
-                specification::ControllerType::DiffDriveCapturable => {
-                    Box::new(unit_control_builtin::diff_drive_capturable::DiffDriveCapturable {})
-                }
-                #[cfg(not(target_arch = "wasm32"))]
-                specification::ControllerType::LibraryLoad { name } => {
-                    unit_control_builtin::dynamic_load_control::DynamicLoadControl::new(name)?
-                }
-                #[cfg(feature = "unit_control_wasm")]
-                specification::ControllerType::Wasm(wasmconfig) => {
-                    let config = unit_control_wasm::UnitControlWasmConfig {
-                        wasm_path: wasmconfig.path.clone().into(),
-                        fuel_per_update: wasmconfig.fuel_per_update,
-                        reload: wasmconfig.reload,
-                        fuel_for_setup: wasmconfig.fuel_for_setup,
-                    };
-                    Box::new(unit_control_wasm::UnitControlWasm::new_with_config(config)?)
-                }
-                specification::ControllerType::SequenceControl { controllers } => {
-                    let mut v = vec![];
-                    for t in controllers.iter() {
-                        v.push(controller_type_to_control(t, control_config, team_config)?);
-                    }
-                    Box::new(unit_control_builtin::sequence_control::SequenceControl::new(v))
-                }
-                specification::ControllerType::Function ( f ) => {
-                    f()
-                }
-                specification::ControllerType::FromControlConfig{ name } => {
-                    let subcontrol = control_config.get(name).ok_or_else(|| {
-                        SetupError::new(&format!("requested controller {name} not found"))})?;
-                    controller_type_to_control(subcontrol, control_config, team_config)?
-                }
-                specification::ControllerType::TeamController{ name } => {
-                    let subcontrol = team_config.get(name).ok_or_else(|| {
-                        SetupError::new(&format!("controlller for requested team {name} not found"))})?;
-                    let subcontrol = subcontrol.controller.as_ref().ok_or_else(||{
-                        SetupError::new(&format!("team {name} doesn't have a controller but is necessary"))})?;
-                    controller_type_to_control(subcontrol, control_config, team_config)?
-                }
-            })
+    // Spawn units
+    for spawn in config.spawn_config.spawns.iter() {
+        let spawner = make_spawner(spawn)?;
+        let unit_entity = spawner.spawn(world)?;
+        components::respawn::add_respawn(world, unit_entity, spawner);
+    }
+
+    // Schedule the reinforcement waves.
+    for wave in config.spawn_config.reinforcements.iter() {
+        // Waves spawn during the match, create their controllers once now to surface errors here.
+        for spawn in wave.spawns.iter() {
+            controller_type_to_control(
+                &spawn.controller,
+                &config.spawn_config.control_config,
+                &team_set,
+            )?;
         }
+        let spawners = wave
+            .spawns
+            .iter()
+            .map(make_spawner)
+            .collect::<Result<Vec<_>, _>>()?;
+        let wave_entity = world.add_entity();
+        world.add_component(
+            wave_entity,
+            components::respawn::Reinforcement::new(wave.time, wave.repeat, wave.waves, spawners),
+        );
+    }
 
-        let controller: Box<dyn UnitControl> = controller_type_to_control(
-            &spawn.controller,
-            &config.spawn_config.control_config,
-            &team_set,
-        )?;
-        match spawn.unit {
-            specification::Unit::Tank => {
-                let unit_config = units::tank::TankSpawnConfig {
-                    x: spawn.x,
-                    y: spawn.y,
-                    yaw: spawn.yaw,
-                    controller,
-                    team_member: optional_team_component,
-                    radio_config: Some(spawn.radio),
-                };
-                units::tank::spawn_tank(world, unit_config);
-            }
-            specification::Unit::Artillery => {
-                let unit_config = units::artillery::ArtillerySpawnConfig {
-                    x: spawn.x,
-                    y: spawn.y,
-                    yaw: spawn.yaw,
-                    controller,
-                    team_member: optional_team_component,
-                    radio_config: Some(spawn.radio),
-                };
-                units::artillery::spawn_artillery(world, unit_config);
+    // Spawn the terrain, before anything that is placed on it.
+    if let Some(terrain) = &config.terrain {
+        let heightmap = match &terrain.source {
+            specification::TerrainSource::Grid { heights } => {
+                Heightmap::from_rows(terrain.x, terrain.y, terrain.cell_size, heights)
             }
-            specification::Unit::Arm => {
-                let unit_config = units::arm::ArmSpawnConfig {
-                    x: spawn.x,
-                    y: spawn.y,
-                    yaw: spawn.yaw,
-                    controller,
-                };
-                units::arm::spawn_arm(world, unit_config);
-            }
-            specification::Unit::Constructor => {
-                let unit_config = units::constructor::ConstructorSpawnConfig {
-                    x: spawn.x,
-                    y: spawn.y,
-                    yaw: spawn.yaw,
-                    controller,
-                    team_member: optional_team_component,
-                    radio_config: Some(spawn.radio),
-                };
-                units::constructor::spawn_constructor(world, unit_config);
+            specification::TerrainSource::Png { path, height_scale } => {
+                let data = std::fs::read(path).map_err(|e| {
+                    Box::new(SetupError::new(&format!(
+                        "failed to read terrain {path}: {e}"
+                    )))
+                })?;
+                Heightmap::from_png(
+                    terrain.x,
+                    terrain.y,
+                    terrain.cell_size,
+                    *height_scale,
+                    &data,
+                )
             }
         }
+        .map_err(|e| Box::new(SetupError::new(&format!("invalid terrain: {e}"))))?;
+        units::terrain::spawn_terrain(world, heightmap);
+    }
+
+    // Spawn obstacles
+    for obstacle in config.obstacles.iter() {
+        let obstacle_config = units::obstacle::ObstacleConfig {
+            x: obstacle.x,
+            y: obstacle.y,
+            yaw: obstacle.yaw,
+            shape: obstacle.shape,
+        };
+        let obstacle_entity = units::obstacle::spawn_obstacle(world, obstacle_config);
+        units::terrain::place_on_terrain(world, obstacle_entity);
+    }
+
+    for zone in config.resupply_zones.iter() {
+        let team = if let Some(team_index) = zone.team {
+            let team_id = teams
+                .get(team_index)
+                .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?;
+            Some(*team_id)
+        } else {
+            None
+        };
+        units::resupply_zone::spawn_resupply_zone(
+            world,
+            units::resupply_zone::ResupplyZoneConfig {
+                x: zone.x,
+                y: zone.y,
+                radius: zone.radius,
+                interval: zone.interval,
+                team,
+            },
+        );
+    }
+
+    if let Some(collision_damage) = config.collision_damage {
+        let collision_damage_entity = world.add_entity();
+        world.add_component(collision_damage_entity, collision_damage);
     }
 
     let setup_king_of_the_hill = |world: &mut engine::World,
@@ -279,7 +506,8 @@ pub fn setup_scenario(
                 initial_owner: optional_team_component.copied(),
                 ..Default::default()
             };
-            crate::units::capturable_flag::spawn_capturable_flag(world, config);
+            let flag_entity = crate::units::capturable_flag::spawn_capturable_flag(world, config);
+            units::terrain::place_on_terrain(world, flag_entity);
         }
         // Spawn the king of the hill component.
         let entity = world.add_entity();
@@ -324,8 +552,102 @@ pub fn setup_scenario(
         } => {
             setup_king_of_the_hill(world, &capture_points, point_limit)?;
         }
+        specification::MatchType::Escort {
+            attackers,
+            path,
+            speed,
+            radius,
+        } => {
+            let length: f32 = path
+                .windows(2)
+                .map(|w| (w[1].x - w[0].x).hypot(w[1].y - w[0].y))
+                .sum();
+            if path.len() < 2 || length <= 0.0 {
+                return Err(Box::new(SetupError::new(
+                    "escort path needs at least two distinct waypoints",
+                )));
+            }
+            // Defenders win when the time runs out, without a limit they can't win.
+            if config.match_config.time_limit.is_none() {
+                return Err(Box::new(SetupError::new("escort match needs a time limit")));
+            }
+            let attacking_team = *teams
+                .get(attackers)
+                .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?;
+            let defending_teams = teams
+                .iter()
+                .copied()
+                .filter(|t| *t != attacking_team)
+                .collect::<Vec<_>>();
+            let payload_entity = units::payload::spawn_payload(
+                world,
+                units::payload::PayloadConfig {
+                    path: path.iter().map(|w| (w.x, w.y)).collect(),
+                    speed,
+                    radius,
+                },
+            );
+            units::terrain::place_on_terrain(world, payload_entity);
+            let entity = world.add_entity();
+            world.add_component(
+                entity,
+                components::match_escort::MatchEscort::new(attacking_team, &defending_teams),
+            );
+        }
+        specification::MatchType::CaptureTheFlag { flags, point_limit } => {
+            for flag in flags.iter() {
+                let team = teams
+                    .get(flag.team)
+                    .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?;
+                let flag_entity = units::team_flag::spawn_team_flag(
+                    world,
+                    units::team_flag::TeamFlagConfig {
+                        x: flag.x,
+                        y: flag.y,
+                        radius: flag.radius,
+                        team: *team,
+                    },
+                );
+                units::terrain::place_on_terrain(world, flag_entity);
+            }
+            let entity = world.add_entity();
+            world.add_component(
+                entity,
+                components::match_capture_the_flag::MatchCaptureTheFlag::new(point_limit),
+            );
+        }
+    }
+
+    // Attach the tracer to the interface of the requested unit.
+    if let Some(trace) = config.trace.as_ref() {
+        use components::unit_interface::RegisterInterfaceContainer;
+        let interface = world
+            .component_iter::<RegisterInterfaceContainer>()
+            .find(|(e, _i)| {
+                world
+                    .component::<components::unit_member::UnitMember>(*e)
+                    .map(|m| m.unit().as_u64() == trace.unit_id)
+                    .unwrap_or(false)
+            })
+            .map(|(_e, i)| i.clone())
+            .ok_or_else(|| {
+                SetupError::new(&format!("unit {} to trace not found", trace.unit_id))
+            })?;
+        let tracer =
+            battleground_unit_control::trace::Tracer::create(std::path::Path::new(&trace.path))?;
+        interface.get_mut().set_tracer(Some(tracer));
     }
 
+    // Scoreboard, always tracked, it only decides the ranking if objectives are specified.
+    let entity = world.add_entity();
+    world.add_component(
+        entity,
+        components::scoreboard::Scoreboard::new(
+            &teams,
+            config.match_config.scoring.clone().unwrap_or_default(),
+        ),
+    );
+
     // Configure time limit
     if let Some(time_limit) = config.match_config.time_limit {
         let entity = world.add_entity();
@@ -25,6 +25,66 @@ pub struct CapturePoint {
     pub team: Option<usize>,
 }
 
+// Function to return a default for the resupply interval.
+fn default_resupply_interval() -> f32 {
+    1.0
+}
+
+/// Definition for a zone in which weapons get their ammunition replenished.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct ResupplyZone {
+    /// x coordinate of the zone.
+    pub x: f32,
+    /// y coordinate of the zone.
+    pub y: f32,
+    /// Radius of the circular zone.
+    pub radius: f32,
+    /// Duration between rounds handed to each weapon in the zone.
+    #[serde(default = "default_resupply_interval")]
+    pub interval: f32,
+    /// Only resupply units of this team, index to a team. Resupplies everyone if not specified.
+    #[serde(default)]
+    pub team: Option<usize>,
+}
+
+// Function to return a default for the flag radius.
+fn default_flag_radius() -> f32 {
+    1.5
+}
+
+/// Definition for the base of a team's flag in capture the flag.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct FlagBase {
+    /// The team owning this flag, index to a team.
+    pub team: usize,
+    /// x coordinate of the base.
+    pub x: f32,
+    /// y coordinate of the base.
+    pub y: f32,
+    /// Radius in which units pick up the flag, or capture a flag at their own base.
+    #[serde(default = "default_flag_radius")]
+    pub radius: f32,
+}
+
+// Function to return a default for the payload speed.
+fn default_payload_speed() -> f32 {
+    0.5
+}
+
+// Function to return a default for the payload radius.
+fn default_payload_radius() -> f32 {
+    5.0
+}
+
+/// Definition for a waypoint of the payload path.
+#[derive(Serialize, Deserialize, Debug, Copy, Default, Clone)]
+pub struct Waypoint {
+    /// x coordinate of the waypoint.
+    pub x: f32,
+    /// y coordinate of the waypoint.
+    pub y: f32,
+}
+
 /// Specification for the match type.
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[serde(tag = "type")]
@@ -48,6 +108,67 @@ pub enum MatchType {
         capture_points: Vec<CapturePoint>,
         point_limit: Option<f32>,
     },
+    /// Capture the flag, enemy units pick up a team's flag by driving over it and score by
+    /// bringing it to their own base, first team to capture point_limit flags wins.
+    CaptureTheFlag {
+        flags: Vec<FlagBase>,
+        point_limit: Option<i64>,
+    },
+    /// Escort, the attacking team pushes the payload along the path by staying near it, the
+    /// payload moves back if only defenders are near. Attackers win if the payload reaches the
+    /// end, defenders win if it doesn't reach it within the time limit, which is required.
+    Escort {
+        /// The attacking team, index to a team, all other teams defend.
+        attackers: usize,
+        /// The path of the payload, it starts at the first waypoint, at least two are required.
+        path: Vec<Waypoint>,
+        /// Speed of the payload, in meters per second.
+        #[serde(default = "default_payload_speed")]
+        speed: f32,
+        /// Radius around the payload in which units push or stop it.
+        #[serde(default = "default_payload_radius")]
+        radius: f32,
+    },
+}
+
+/// Source for the heights of the terrain.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum TerrainSource {
+    /// Heights provided inline as seen from above; the first row is at the highest y coordinate,
+    /// each row goes along positive x.
+    Grid { heights: Vec<Vec<f32>> },
+    /// Grayscale png image as seen from above, black is zero height and white is `height_scale`.
+    Png { path: String, height_scale: f32 },
+}
+
+/// Specification of the terrain.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Terrain {
+    /// x coordinate of the center of the terrain.
+    #[serde(default)]
+    pub x: f32,
+    /// y coordinate of the center of the terrain.
+    #[serde(default)]
+    pub y: f32,
+    /// Distance between the height samples.
+    pub cell_size: f32,
+    /// The source of the height samples.
+    pub source: TerrainSource,
+}
+
+/// Definition for a static obstacle.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub struct Obstacle {
+    /// x coordinate of the obstacle.
+    pub x: f32,
+    /// y coordinate of the obstacle.
+    pub y: f32,
+    /// yaw of the obstacle (radians).
+    #[serde(default)]
+    pub yaw: f32,
+    /// The shape of the obstacle, placed on the floor.
+    pub shape: crate::units::obstacle::ObstacleShape,
 }
 
 /// Specification for a particular match
@@ -58,6 +179,18 @@ pub struct MatchConfig {
     pub mode: MatchType,
     /// Optional time limit.
     pub time_limit: Option<f32>,
+    /// Optional weighted scoring, if specified it ranks the teams instead of the match type.
+    #[serde(default)]
+    pub scoring: Option<crate::components::scoreboard::ScoringConfig>,
+}
+
+/// Specification for tracing the register access of a single unit.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct TraceConfig {
+    /// The unit id of the unit to trace.
+    pub unit_id: u64,
+    /// Path of the file to write the trace to.
+    pub path: String,
 }
 
 /// Specification of a team in the scenario.
@@ -74,6 +207,10 @@ pub struct Team {
 
     /// The controller to use for this team.
     pub controller: Option<ControllerType>,
+
+    /// Resources the team can spend on structures, unlimited if not specified.
+    #[serde(default)]
+    pub budget: Option<f32>,
 }
 
 /// Configuration for the wasm controller.
@@ -156,6 +293,10 @@ pub enum Unit {
     Artillery,
     Arm,
     Constructor,
+    /// Fast and lightly armored, carries a long range radar but no weapon.
+    Scout,
+    /// Fires guided missiles, their target can be changed while they are in flight.
+    MissileLauncher,
 }
 
 /// Configures a unit spawn.
@@ -183,6 +324,68 @@ pub struct Spawn {
     pub radio: crate::units::common::RadioConfig,
 }
 
+// Function to return a default for the respawn delay.
+fn default_respawn_delay() -> f32 {
+    5.0
+}
+
+/// Definition for a zone in which destroyed units respawn.
+#[derive(Serialize, Deserialize, Debug, Copy, Default, Clone)]
+pub struct SpawnZone {
+    /// Only units of this team respawn here, index to a team. Used by any team if not specified.
+    #[serde(default)]
+    pub team: Option<usize>,
+    /// x coordinate of the zone.
+    pub x: f32,
+    /// y coordinate of the zone.
+    pub y: f32,
+    /// Radius of the circular zone, units respawn at a random position within it.
+    #[serde(default)]
+    pub radius: f32,
+    /// The yaw orientation to respawn with, radians.
+    #[serde(default)]
+    pub yaw: f32,
+}
+
+/// Specification for respawning destroyed units.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Respawn {
+    /// Duration between the destruction of a unit and its respawn, in seconds.
+    #[serde(default = "default_respawn_delay")]
+    pub delay: f32,
+    /// Total number of lives of each unit, including the first, unlimited if not specified.
+    #[serde(default)]
+    pub lives: Option<u32>,
+    /// Zones to respawn in, units without a zone for their team respawn at their original spawn.
+    #[serde(default)]
+    pub zones: Vec<SpawnZone>,
+}
+
+impl Default for Respawn {
+    fn default() -> Self {
+        Respawn {
+            delay: default_respawn_delay(),
+            lives: None,
+            zones: vec![],
+        }
+    }
+}
+
+/// Specification for a timed wave of units joining the match.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ReinforcementWave {
+    /// Time at which the first wave spawns, in seconds.
+    pub time: f32,
+    /// Interval between waves, only a single wave spawns if not specified.
+    #[serde(default)]
+    pub repeat: Option<f32>,
+    /// Number of waves when repeating, unlimited if not specified.
+    #[serde(default)]
+    pub waves: Option<u32>,
+    /// Units to spawn with each wave.
+    pub spawns: Vec<Spawn>,
+}
+
 /// Specification for spawnables.
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct SpawnConfig {
@@ -193,6 +396,12 @@ pub struct SpawnConfig {
     pub teams: Vec<Team>,
     /// List of units to spawn.
     pub spawns: Vec<Spawn>,
+    /// Respawn destroyed units, destroyed units stay destroyed if not specified.
+    #[serde(default)]
+    pub respawn: Option<Respawn>,
+    /// Waves of units that join the match later on.
+    #[serde(default)]
+    pub reinforcements: Vec<ReinforcementWave>,
 }
 
 /// Specification for a scenario.
@@ -213,6 +422,60 @@ pub struct ScenarioConfig {
     /// Spawn of vehicles.
     #[serde(default)]
     pub spawn_config: SpawnConfig,
+
+    /// Optional terrain, the floor is flat if not specified.
+    #[serde(default)]
+    pub terrain: Option<Terrain>,
+
+    /// Static obstacles, these stop projectiles and block radar.
+    #[serde(default)]
+    pub obstacles: Vec<Obstacle>,
+
+    /// Damage units take when they bump into something, no collision damage if not specified.
+    #[serde(default)]
+    pub collision_damage: Option<crate::components::collision_damage::CollisionDamage>,
+
+    /// Ammunition per unit type, unlimited if not specified.
+    #[serde(default)]
+    pub ammunition: Option<crate::components::ammunition::AmmunitionConfig>,
+
+    /// Cost and build time of the structures constructors can build, defaults if not specified.
+    #[serde(default)]
+    pub construction: Option<crate::components::construction::ConstructionConfig>,
+
+    /// Number, cost and effect of the mines constructors can drop, defaults if not specified.
+    #[serde(default)]
+    pub mines: Option<crate::components::mine::MineConfig>,
+
+    /// Repair rates of repair stations and deployed constructors, defaults if not specified.
+    #[serde(default)]
+    pub repair: Option<crate::components::repair::RepairConfig>,
+
+    /// Zones where weapons are resupplied with ammunition.
+    #[serde(default)]
+    pub resupply_zones: Vec<ResupplyZone>,
+
+    /// Sensor model used by all radars, radars are perfect if not specified.
+    #[serde(default)]
+    pub radar_noise: Option<crate::components::radar::RadarNoise>,
+
+    /// Error model of the gps, the gps is perfect if not specified.
+    #[serde(default)]
+    pub gps: Option<crate::components::gps::GpsConfig>,
+
+    /// Gives each team a shared blackboard that all its units can read and write, disabled if not
+    /// specified.
+    #[serde(default)]
+    pub team_blackboard: Option<crate::components::team_blackboard::TeamBlackboardConfig>,
+
+    /// Seed for the random number generator, used by sensor noise.
+    #[serde(default)]
+    pub seed: u64,
+
+    /// Trace all register access of a unit to a file, for replay with the
+    /// [`battleground_unit_control::trace::Replay`].
+    #[serde(default)]
+    pub trace: Option<TraceConfig>,
 }
 
 /// This struct specifies the steps to be done after a scenario wraps up.
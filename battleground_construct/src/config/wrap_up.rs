@@ -11,9 +11,26 @@ use serde::{Deserialize, Serialize};
 // game logic, so this here is the externally-readable output that contains everything an outside
 // system would need to know.
 
+/// Placement of a team in the final ranking.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WrapUpPlacement {
+    /// Placement of the team, starting at 1, tied teams share their placement.
+    pub placement: usize,
+    pub team: specification::Team,
+    /// Total score, only meaningful if the match specified weighted scoring.
+    pub score: f32,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct WrapUpReport {
+    /// The winning team, None if the match ended in a draw or without a winner.
     pub winning_team: Option<specification::Team>,
+    /// Whether multiple teams share first place.
+    #[serde(default)]
+    pub draw: bool,
+    /// All teams ordered by their placement.
+    #[serde(default)]
+    pub ranking: Vec<WrapUpPlacement>,
     pub match_report: Option<MatchReport>,
     pub teams: std::collections::HashMap<TeamId, specification::Team>,
 }
@@ -38,6 +55,7 @@ pub fn create_wrap_up_report(world: &World) -> WrapUpReport {
                     color: (team_color.r, team_color.g, team_color.b),
                     controller: None,
                     comment: team.comment().map(|x| x.to_owned()),
+                    budget: None,
                 },
             );
         }
@@ -45,13 +63,33 @@ pub fn create_wrap_up_report(world: &World) -> WrapUpReport {
 
     // determine the winning taem.
     let winning_team = match_report.as_ref().and_then(|rp| {
-        rp.winner
+        rp.winner()
             .map(|t| teams.get(&t).expect("team must exist").clone())
     });
 
+    let draw = match_report
+        .as_ref()
+        .map(|rp| rp.is_draw())
+        .unwrap_or(false);
+    let ranking = match_report
+        .as_ref()
+        .map(|rp| {
+            rp.scores
+                .iter()
+                .map(|s| WrapUpPlacement {
+                    placement: s.rank,
+                    team: teams.get(&s.team).expect("team must exist").clone(),
+                    score: s.total,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     // Cool, now we can construct the wrap up report.
     WrapUpReport {
         winning_team,
+        draw,
+        ranking,
         match_report,
         teams,
     }
@@ -1,4 +1,5 @@
 use super::primitives::*;
+use crate::components::hit_box::HitBox;
 use cgmath::Deg;
 use engine::prelude::*;
 
@@ -14,6 +15,26 @@ impl ArtilleryTurret {
     pub fn new() -> Self {
         ArtilleryTurret {}
     }
+
+    /// Hit boxes for the base plate and the two risers that hold the barrel.
+    pub fn hit_boxes(&self) -> Vec<(Mat4, HitBox)> {
+        let standing_height = 0.75;
+        let riser = HitBox::new(0.3, 0.05, standing_height);
+        vec![
+            (
+                Mat4::from_translation(Vec3::new(0.0, 0.0, 0.05)),
+                HitBox::new(0.8, 0.8, 0.1),
+            ),
+            (
+                Mat4::from_translation(Vec3::new(0.0, 0.34, standing_height / 2.0)),
+                riser,
+            ),
+            (
+                Mat4::from_translation(Vec3::new(0.0, -0.34, standing_height / 2.0)),
+                riser,
+            ),
+        ]
+    }
 }
 impl Component for ArtilleryTurret {}
 
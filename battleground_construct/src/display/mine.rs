@@ -0,0 +1,62 @@
+use super::primitives::*;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A mine lying on the ground, a flat disc with a trigger on top.
+#[derive(Deserialize, Serialize, Copy, Debug, Clone)]
+pub struct Mine {
+    pub radius: f32,
+    pub height: f32,
+}
+impl Default for Mine {
+    fn default() -> Self {
+        Mine::new()
+    }
+}
+
+impl Mine {
+    pub fn new() -> Self {
+        Mine {
+            radius: 0.2,
+            height: 0.06,
+        }
+    }
+}
+impl Component for Mine {}
+
+impl Drawable for Mine {
+    fn drawables(&self) -> Vec<Element> {
+        vec![
+            // Body
+            Element {
+                transform: Mat4::from_angle_y(cgmath::Deg(-90.0)),
+                primitive: Primitive::Cylinder(Cylinder {
+                    radius: self.radius,
+                    height: self.height,
+                }),
+                material: Color {
+                    r: 70,
+                    g: 80,
+                    b: 50,
+                    a: 255,
+                }
+                .into(),
+            },
+            // Trigger
+            Element {
+                transform: Mat4::from_angle_y(cgmath::Deg(-90.0)),
+                primitive: Primitive::Cylinder(Cylinder {
+                    radius: self.radius * 0.25,
+                    height: self.height * 1.5,
+                }),
+                material: Color {
+                    r: 40,
+                    g: 40,
+                    b: 40,
+                    a: 255,
+                }
+                .into(),
+            },
+        ]
+    }
+}
@@ -0,0 +1,67 @@
+use super::primitives::*;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A guided missile, the body extends along the x axis with the nose at the front.
+#[derive(Deserialize, Serialize, Copy, Debug, Clone)]
+pub struct Missile {
+    pub radius: f32,
+    pub length: f32,
+}
+impl Default for Missile {
+    fn default() -> Self {
+        Missile::new()
+    }
+}
+
+impl Missile {
+    pub fn new() -> Self {
+        Missile {
+            radius: 0.04,
+            length: 0.4,
+        }
+    }
+}
+impl Component for Missile {}
+
+impl Drawable for Missile {
+    fn drawables(&self) -> Vec<Element> {
+        let nose_length = self.radius * 3.0;
+        let body_length = self.length - nose_length;
+        let material: Material = Color {
+            r: 200,
+            g: 200,
+            b: 200,
+            a: 255,
+        }
+        .into();
+        vec![
+            Element {
+                transform: Mat4::from_translation(Vec3::new(-self.length / 2.0, 0.0, 0.0)),
+                primitive: Primitive::Cylinder(Cylinder {
+                    radius: self.radius,
+                    height: body_length,
+                }),
+                material,
+            },
+            Element {
+                transform: Mat4::from_translation(Vec3::new(
+                    -self.length / 2.0 + body_length,
+                    0.0,
+                    0.0,
+                )),
+                primitive: Primitive::Cone(Cone {
+                    radius: self.radius,
+                    height: nose_length,
+                }),
+                material: Color {
+                    r: 180,
+                    g: 30,
+                    b: 30,
+                    a: 255,
+                }
+                .into(),
+            },
+        ]
+    }
+}
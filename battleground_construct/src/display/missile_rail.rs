@@ -0,0 +1,51 @@
+use super::primitives::*;
+use crate::components::hit_box::HitBox;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The launch rail of the missile launcher, the rail is centered on the origin and extends along x.
+#[derive(Deserialize, Serialize, Copy, Debug, Clone)]
+pub struct MissileRail {
+    pub length: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl MissileRail {
+    pub fn new(length: f32) -> Self {
+        MissileRail {
+            length,
+            width: 0.2,
+            height: 0.1,
+        }
+    }
+
+    pub fn hit_boxes(&self) -> Vec<(Mat4, HitBox)> {
+        vec![(
+            Mat4::from_translation(Vec3::new(0.0, 0.0, -self.height / 2.0)),
+            HitBox::new(self.length, self.width, self.height),
+        )]
+    }
+}
+impl Component for MissileRail {}
+
+impl Drawable for MissileRail {
+    fn drawables(&self) -> Vec<Element> {
+        let material: Material = Color {
+            r: 60,
+            g: 60,
+            b: 60,
+            a: 255,
+        }
+        .into();
+        vec![Element {
+            transform: Mat4::from_translation(Vec3::new(0.0, 0.0, -self.height / 2.0)),
+            primitive: Primitive::Cuboid(Cuboid {
+                length: self.length,
+                width: self.width,
+                height: self.height,
+            }),
+            material,
+        }]
+    }
+}
@@ -20,13 +20,19 @@ pub mod draw_module;
 pub mod fireworks;
 pub mod flag;
 pub mod health_bar;
+pub mod mine;
+pub mod missile;
+pub mod missile_rail;
+pub mod obstacle;
 pub mod particle_emitter;
 pub mod primitives;
 pub mod radar_model;
+pub mod scout_mast;
 pub mod tank_barrel;
 pub mod tank_body;
 pub mod tank_bullet;
 pub mod tank_turret;
+pub mod terrain;
 pub mod tracks_side;
 pub mod wheel;
 pub mod wheeled_body;
@@ -0,0 +1,67 @@
+use super::primitives::*;
+use engine::prelude::*;
+
+#[derive(Copy, Debug, Clone)]
+enum Shape {
+    Cuboid(Cuboid),
+    Cylinder(Cylinder),
+}
+
+/// Static obstacle, drawn on top of the floor.
+#[derive(Copy, Debug, Clone)]
+pub struct Obstacle {
+    shape: Shape,
+    color: Color,
+}
+
+impl Obstacle {
+    fn new(shape: Shape) -> Self {
+        Obstacle {
+            shape,
+            color: Color {
+                r: 110,
+                g: 100,
+                b: 90,
+                a: 255,
+            },
+        }
+    }
+
+    pub fn cuboid(length: f32, width: f32, height: f32) -> Self {
+        Self::new(Shape::Cuboid(Cuboid {
+            length,
+            width,
+            height,
+        }))
+    }
+
+    pub fn cylinder(radius: f32, height: f32) -> Self {
+        Self::new(Shape::Cylinder(Cylinder { radius, height }))
+    }
+
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+}
+impl Component for Obstacle {}
+
+impl Drawable for Obstacle {
+    fn drawables(&self) -> Vec<Element> {
+        let (transform, primitive) = match self.shape {
+            Shape::Cuboid(cuboid) => (
+                Mat4::from_translation(Vec3::new(0.0, 0.0, cuboid.height / 2.0)),
+                Primitive::Cuboid(cuboid),
+            ),
+            // Cylinders extend along x, rotate such that it points up.
+            Shape::Cylinder(cylinder) => (
+                Mat4::from_angle_y(cgmath::Deg(-90.0)),
+                Primitive::Cylinder(cylinder),
+            ),
+        };
+        vec![Element {
+            transform,
+            primitive,
+            material: self.color.into(),
+        }]
+    }
+}
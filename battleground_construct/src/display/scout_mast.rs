@@ -0,0 +1,60 @@
+use super::primitives::*;
+use crate::components::hit_box::HitBox;
+use engine::prelude::*;
+
+/// The mast on the scout's payload that carries the radar.
+#[derive(Copy, Debug, Clone)]
+pub struct ScoutMast {
+    pub height: f32,
+    pub radius: f32,
+}
+
+impl ScoutMast {
+    pub fn new(height: f32) -> Self {
+        ScoutMast {
+            height,
+            radius: 0.03,
+        }
+    }
+
+    pub fn hit_boxes(&self) -> Vec<(Mat4, HitBox)> {
+        vec![(
+            Mat4::from_translation(Vec3::new(0.0, 0.0, self.height / 2.0)),
+            HitBox::new(self.radius * 2.0, self.radius * 2.0, self.height),
+        )]
+    }
+}
+impl Component for ScoutMast {}
+
+impl Drawable for ScoutMast {
+    fn drawables(&self) -> Vec<Element> {
+        let material: Material = Color {
+            r: 60,
+            g: 60,
+            b: 60,
+            a: 255,
+        }
+        .into();
+        vec![
+            // Plate on the payload
+            Element {
+                transform: Mat4::from_translation(Vec3::new(0.0, 0.0, 0.01)),
+                primitive: Primitive::Cuboid(Cuboid {
+                    length: 0.3,
+                    width: 0.3,
+                    height: 0.02,
+                }),
+                material,
+            },
+            // Vertical pole
+            Element {
+                transform: Mat4::from_angle_y(cgmath::Deg(-90.0)),
+                primitive: Primitive::Cylinder(Cylinder {
+                    radius: self.radius,
+                    height: self.height,
+                }),
+                material,
+            },
+        ]
+    }
+}
@@ -0,0 +1,86 @@
+use super::primitives::*;
+use crate::util::heightmap::Heightmap;
+use engine::prelude::*;
+
+/// Terrain surface, it can't be expressed in primitives so the viewer turns it into a mesh
+/// using the vertices and indices.
+#[derive(Debug, Clone)]
+pub struct TerrainMesh {
+    heightmap: Heightmap,
+    color: Color,
+}
+
+impl TerrainMesh {
+    pub fn new(heightmap: Heightmap) -> Self {
+        TerrainMesh {
+            heightmap,
+            color: Color {
+                r: 128,
+                g: 128,
+                b: 128,
+                a: 255,
+            },
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    /// Vertices of the mesh, one for each sample of the heightmap.
+    pub fn vertices(&self) -> Vec<Vec3> {
+        let (size_x, size_y) = self.heightmap.size();
+        let mut vertices = Vec::with_capacity(size_x * size_y);
+        for iy in 0..size_y {
+            for ix in 0..size_x {
+                vertices.push(self.heightmap.sample_position(ix, iy));
+            }
+        }
+        vertices
+    }
+
+    /// Indices into the vertices, three per triangle, counter clockwise seen from above.
+    pub fn indices(&self) -> Vec<u32> {
+        let (size_x, size_y) = self.heightmap.size();
+        let mut indices = Vec::with_capacity((size_x - 1) * (size_y - 1) * 6);
+        for iy in 0..(size_y - 1) {
+            for ix in 0..(size_x - 1) {
+                let i00 = (iy * size_x + ix) as u32;
+                let i10 = i00 + 1;
+                let i01 = i00 + size_x as u32;
+                let i11 = i01 + 1;
+                indices.extend([i00, i10, i11, i00, i11, i01]);
+            }
+        }
+        indices
+    }
+}
+impl Component for TerrainMesh {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_terrain_mesh() {
+        use cgmath::InnerSpace;
+        let heightmap =
+            Heightmap::from_rows(0.0, 0.0, 1.0, &[vec![0.0, 1.0, 0.0], vec![0.0, 0.0, 0.0]])
+                .unwrap();
+        let mesh = TerrainMesh::new(heightmap);
+        let vertices = mesh.vertices();
+        let indices = mesh.indices();
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(indices.len(), 2 * 6);
+        // All triangles face upwards.
+        for t in indices.chunks(3) {
+            let a = vertices[t[0] as usize];
+            let b = vertices[t[1] as usize];
+            let c = vertices[t[2] as usize];
+            assert!((b - a).cross(c - a).normalize().z > 0.0);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::components::ammunition::Ammunition;
 use super::components::cannon::Cannon;
 
 use super::Clock;
@@ -17,9 +18,14 @@ impl System for CannonTrigger {
         for cannon_entity in world.component_entities::<Cannon>() {
             let fired = {
                 let mut cannon = world.component_mut::<Cannon>(cannon_entity).unwrap();
+                let mut ammunition = world.component_mut::<Ammunition>(cannon_entity);
                 cannon.update(current);
-                if cannon.is_triggered() && cannon.is_ready() {
+                let loaded = ammunition.as_ref().map(|a| !a.is_empty()).unwrap_or(true);
+                if cannon.is_triggered() && cannon.is_ready() && loaded {
                     cannon.fired(current);
+                    if let Some(ammunition) = ammunition.as_mut() {
+                        ammunition.consume();
+                    }
                     true
                 } else {
                     false
@@ -0,0 +1,128 @@
+use super::components;
+use super::components::bumper::Bumper;
+use super::components::collider::Collider;
+use super::components::collision_damage::CollisionDamage;
+use super::components::pose::{world_pose, Pose};
+use super::components::velocity::Velocity;
+use crate::display::primitives::{Mat4, Vec3};
+use crate::util::cgmath::prelude::*;
+use engine::prelude::*;
+
+/// Pushes colliders that have a velocity out of any other collider they penetrate, records the
+/// contacts in the bumpers and applies collision damage if configured.
+///
+/// Entities with a collider and velocity must not have a parent; the correction is applied to
+/// their pose directly.
+pub struct Collision {}
+impl System for Collision {
+    fn update(&mut self, world: &mut World) {
+        use cgmath::InnerSpace;
+        let t = world
+            .component_iter::<components::clock::Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .elapsed_as_f32();
+        let collision_damage = world
+            .component_iter::<CollisionDamage>()
+            .next()
+            .map(|(_, v)| *v);
+
+        for (_entity, mut bumper) in world.component_iter_mut::<Bumper>() {
+            bumper.clear();
+        }
+
+        // Collect all colliders, with their world pose and world velocity, if they can move.
+        let mut colliders: Vec<(EntityId, Mat4, Collider, Option<Vec3>)> = vec![];
+        for (entity, collider) in world.component_iter::<Collider>() {
+            let pose = *world_pose(world, entity).transform();
+            let velocity = world
+                .component::<Velocity>(entity)
+                .map(|v| (pose * v.v.extend(0.0)).truncate());
+            colliders.push((entity, pose, collider.clone(), velocity));
+        }
+
+        // Determine the corrections and contacts.
+        let mut corrections = vec![Vec3::new(0.0, 0.0, 0.0); colliders.len()];
+        let mut contacts: Vec<(EntityId, EntityId, Vec3, f32)> = vec![];
+        for (i, (entity, pose, collider, velocity)) in colliders.iter().enumerate() {
+            let velocity = if let Some(velocity) = velocity {
+                *velocity
+            } else {
+                // Static colliders never get moved.
+                continue;
+            };
+            for (j, (other_entity, other_pose, other_collider, other_velocity)) in
+                colliders.iter().enumerate()
+            {
+                // Pairs of moving colliders are only handled once.
+                if i == j || (other_velocity.is_some() && j < i) {
+                    continue;
+                }
+                let penetration =
+                    if let Some(p) = collider.penetration(*pose, other_collider, *other_pose) {
+                        p
+                    } else {
+                        continue;
+                    };
+                if other_velocity.is_some() {
+                    corrections[i] += penetration / 2.0;
+                    corrections[j] -= penetration / 2.0;
+                } else {
+                    corrections[i] += penetration;
+                }
+
+                let direction = -penetration.normalize();
+                let relative = velocity - other_velocity.unwrap_or(Vec3::new(0.0, 0.0, 0.0));
+                let closing_speed = relative.dot(direction).max(0.0);
+                contacts.push((*entity, *other_entity, direction, closing_speed));
+                if other_velocity.is_some() {
+                    contacts.push((*other_entity, *entity, -direction, closing_speed));
+                }
+            }
+        }
+
+        for ((entity, _, _, _), correction) in colliders.iter().zip(corrections.iter()) {
+            if correction.magnitude2() == 0.0 {
+                continue;
+            }
+            if let Some(mut pose) = world.component_mut::<Pose>(*entity) {
+                pose.transform_mut().w += correction.extend(0.0);
+            }
+        }
+
+        for (entity, other_entity, direction, closing_speed) in contacts {
+            let is_new = if let Some(mut bumper) = world.component_mut::<Bumper>(entity) {
+                let is_new = bumper.is_new_contact(other_entity);
+                bumper.add_contact(other_entity, direction);
+                is_new
+            } else {
+                false
+            };
+
+            // Only the first step of a contact can cause damage.
+            let damage = match collision_damage {
+                Some(config) if is_new => config.damage(closing_speed),
+                _ => continue,
+            };
+            if damage <= 0.0 {
+                continue;
+            }
+            let source = world
+                .component::<components::unit_member::UnitMember>(other_entity)
+                .map(|v| v.unit());
+            let position = Mat4::from_translation(world_pose(world, entity).to_translation());
+            let impact = components::impact::Impact::new(Some(entity), position, source);
+            if world
+                .component_mut::<components::hit_by::HitBy>(entity)
+                .is_none()
+            {
+                world.add_component(entity, components::hit_by::HitBy::new());
+            }
+            let mut hit_by = world
+                .component_mut::<components::hit_by::HitBy>(entity)
+                .unwrap();
+            hit_by.add_hit(damage, impact, source, t);
+        }
+    }
+}
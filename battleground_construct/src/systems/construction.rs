@@ -0,0 +1,158 @@
+use super::components::construction::{
+    BuildJob, BuildStatus, Construction, StructureType, TeamBudget,
+};
+use super::components::deploy::{Deploy, DeployState};
+use super::components::team::get_team_entity;
+use super::components::team_member::TeamMember;
+use super::Clock;
+use crate::units::structure::{spawn_structure, structure_shape, StructureConfig, UnitStructure};
+use engine::prelude::*;
+
+/// Distance behind the site entity at which structures are placed.
+const STRUCTURE_PLACEMENT_OFFSET: f32 = -2.5;
+
+pub struct ConstructionSystem {}
+impl System for ConstructionSystem {
+    fn update(&mut self, world: &mut World) {
+        let dt = {
+            let (_entity, clock) = world
+                .component_iter::<Clock>()
+                .next()
+                .expect("Should have one clock");
+            clock.step_as_f32()
+        };
+
+        let mut finished = vec![];
+        for (entity, mut construction) in world.component_iter_mut::<Construction>() {
+            let deployed = world
+                .component::<Deploy>(entity)
+                .map(|d| d.get_state() == DeployState::Deployed)
+                .unwrap_or(false);
+            let team_member = world.component::<TeamMember>(entity).map(|t| *t);
+            let mut budget = team_member
+                .and_then(|t| get_team_entity(world, t.team()))
+                .and_then(|e| world.component_mut::<TeamBudget>(e));
+
+            match construction.take_request() {
+                Some(None) => {
+                    // Cancel the build and refund its cost.
+                    if let Some(job) = construction.job() {
+                        if let Some(budget) = budget.as_mut() {
+                            budget.refund(job.cost.cost);
+                        }
+                    }
+                    construction.set_job(None);
+                    construction.set_status(BuildStatus::Idle);
+                }
+                Some(Some(structure)) if construction.job().is_none() => {
+                    let cost = construction.costs().cost(structure);
+                    let site = construction.site_entity();
+                    if !deployed {
+                        construction.set_status(BuildStatus::NotDeployed);
+                    } else if is_occupied(world, structure, structure_placement(world, site)) {
+                        construction.set_status(BuildStatus::Occupied);
+                    } else if budget.as_mut().map(|b| b.spend(cost.cost)).unwrap_or(true) {
+                        construction.set_job(Some(BuildJob {
+                            structure,
+                            cost,
+                            elapsed: 0.0,
+                        }));
+                        construction.set_status(BuildStatus::Building);
+                    } else {
+                        construction.set_status(BuildStatus::InsufficientBudget);
+                    }
+                }
+                // A build is already in progress, it has to finish or be cancelled first.
+                Some(Some(_)) | None => {}
+            }
+
+            if let Some(mut job) = construction.job() {
+                if deployed {
+                    job.elapsed += dt;
+                }
+                if job.is_finished() {
+                    finished.push((entity, job, team_member));
+                    construction.set_job(None);
+                    construction.set_status(BuildStatus::Idle);
+                } else {
+                    construction.set_job(Some(job));
+                }
+            }
+            construction.set_budget(budget.map(|b| b.remaining()));
+        }
+
+        for (entity, job, team_member) in finished {
+            let site_entity = world
+                .component::<Construction>(entity)
+                .expect("just iterated over it")
+                .site_entity();
+            let placement = structure_placement(world, site_entity);
+            // Something may have been built on the spot since the build started, refund it then.
+            if is_occupied(world, job.structure, placement) {
+                if let Some(mut budget) = team_member
+                    .and_then(|t| get_team_entity(world, t.team()))
+                    .and_then(|e| world.component_mut::<TeamBudget>(e))
+                {
+                    budget.refund(job.cost.cost);
+                }
+                if let Some(mut construction) = world.component_mut::<Construction>(entity) {
+                    construction.set_status(BuildStatus::Occupied);
+                }
+                continue;
+            }
+            let structure_entity = place_structure(world, placement, job.structure, team_member);
+            if job.structure == StructureType::RadarTower {
+                let radar_entity = world
+                    .component::<UnitStructure>(structure_entity)
+                    .expect("structure was just spawned")
+                    .radar_entity;
+                if let Some(mut construction) = world.component_mut::<Construction>(entity) {
+                    construction.set_tower_radar(Some(radar_entity));
+                }
+            }
+        }
+    }
+}
+
+/// The x, y and yaw at which a structure built from this site is placed.
+fn structure_placement(world: &World, site_entity: EntityId) -> (f32, f32, f32) {
+    use crate::util::cgmath::prelude::*;
+    let site = super::components::pose::world_pose(world, site_entity);
+    let placement = site.transform()
+        * cgmath::Matrix4::from_translation(cgmath::vec3(STRUCTURE_PLACEMENT_OFFSET, 0.0, 0.0));
+    let position = placement.to_translation();
+    (position.x, position.y, placement.to_rpy().z)
+}
+
+/// Whether the structure would overlap with an existing structure at this placement.
+fn is_occupied(world: &World, structure: StructureType, placement: (f32, f32, f32)) -> bool {
+    let radius = structure_shape(structure).footprint_radius();
+    world
+        .component_iter::<UnitStructure>()
+        .any(|(_, existing)| {
+            let pose = super::components::pose::world_pose(world, existing.structure_entity);
+            let distance = (pose.w.x - placement.0).hypot(pose.w.y - placement.1);
+            distance < radius + structure_shape(existing.structure).footprint_radius()
+        })
+}
+
+fn place_structure(
+    world: &mut World,
+    placement: (f32, f32, f32),
+    structure: StructureType,
+    team_member: Option<TeamMember>,
+) -> EntityId {
+    let (x, y, yaw) = placement;
+    let structure_entity = spawn_structure(
+        world,
+        StructureConfig {
+            x,
+            y,
+            yaw,
+            structure,
+            team_member,
+        },
+    );
+    crate::units::terrain::place_on_terrain(world, structure_entity);
+    structure_entity
+}
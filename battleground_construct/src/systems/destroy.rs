@@ -72,6 +72,12 @@ impl System for Destroy {
             }
             world.add_component(thingy, destructor);
             world.add_component(thingy, crate::components::expiry::Expiry::lifetime(50.0));
+            if let Some(unit) = world
+                .component::<components::unit_member::UnitMember>(*root_entity)
+                .map(|m| m.unit())
+            {
+                world.add_component(thingy, components::unit_source::UnitSource::new(unit));
+            }
 
             all_to_be_removed.append(&mut elements_here);
         }
@@ -1,7 +1,9 @@
 use super::components;
 use super::display;
 use crate::components::team::{get_team_entity, Team};
+use crate::units::team_flag::UnitTeamFlag;
 use components::capturable::Capturable;
+use components::team_flag::TeamFlag;
 use display::display_control_point::DisplayControlPoint;
 use display::flag::Flag;
 
@@ -34,5 +36,22 @@ impl System for DisplayCaptureFlag {
                 }
             }
         }
+
+        // The flags for capture the flag always show the color of their team, at their base too.
+        for (flag_entity, unit_flag) in world.component_iter::<UnitTeamFlag>() {
+            let color = world
+                .component::<TeamFlag>(flag_entity)
+                .and_then(|flag| get_team_entity(world, flag.team()))
+                .and_then(|team_entity| world.component::<Team>(team_entity))
+                .map_or(display::Color::rgb(30, 30, 30), |team| *team.color());
+            if let Some(mut flag) = world.component_mut::<Flag>(flag_entity) {
+                flag.set_color(color);
+            }
+            if let Some(mut area) =
+                world.component_mut::<DisplayControlPoint>(unit_flag.base_entity)
+            {
+                area.set_color(color);
+            }
+        }
     }
 }
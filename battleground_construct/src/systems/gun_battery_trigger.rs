@@ -1,3 +1,4 @@
+use super::components::ammunition::Ammunition;
 use super::components::gun_battery::GunBattery;
 
 use super::Clock;
@@ -18,8 +19,12 @@ impl System for GunBatteryTrigger {
             let mut fire_poses = vec![];
             {
                 let mut gun_battery = world.component_mut::<GunBattery>(gun_entity).unwrap();
+                let mut ammunition = world.component_mut::<Ammunition>(gun_entity);
                 gun_battery.update(current);
-                while gun_battery.is_triggered() && gun_battery.is_ready() {
+                while gun_battery.is_triggered()
+                    && gun_battery.is_ready()
+                    && ammunition.as_mut().map(|a| a.consume()).unwrap_or(true)
+                {
                     fire_poses.push(gun_battery.fired(current))
                 }
             };
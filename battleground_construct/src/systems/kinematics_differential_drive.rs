@@ -1,5 +1,6 @@
 use super::components;
 use super::components::differential_drive_base::DifferentialDriveBase;
+use super::components::odometry::Odometry;
 use super::components::velocity::Velocity;
 
 use engine::prelude::*;
@@ -24,6 +25,11 @@ impl System for KinematicsDifferentialDrive {
                 let angular_velocity = (wheel_velocities.1 - wheel_velocities.0) / track_width;
                 *vel = Velocity::from_se2(linear_velocity, 0.0, angular_velocity);
             }
+            // Wheel encoders count what the wheels do.
+            if let Some(mut odometry) = world.component_mut::<Odometry>(entity) {
+                let wheel_velocities = base.wheel_velocities();
+                odometry.add_distance(wheel_velocities.0 * dt, wheel_velocities.1 * dt);
+            }
         }
     }
 }
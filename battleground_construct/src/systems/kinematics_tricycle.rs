@@ -1,4 +1,5 @@
 use super::components;
+use super::components::odometry::Odometry;
 use super::components::revolute::Revolute;
 use super::components::tricycle_base::TricycleBase;
 use super::components::velocity::Velocity;
@@ -26,6 +27,11 @@ impl System for KinematicsTricycle {
             base.update(dt);
 
             let wheel_vel = base.wheel_velocity();
+            // Encoders are on the rear axle.
+            if let Some(mut odometry) = world.component_mut::<Odometry>(entity) {
+                let rear_distance = wheel_vel * angle.cos() * dt;
+                odometry.add_distance(rear_distance, rear_distance);
+            }
             // try to see if we can find a velocity for this entity.
             if let Some(mut vel) = world.component_mut::<Velocity>(entity) {
                 let angular_velocity = (wheel_vel / wheel_base) * angle.sin();
@@ -0,0 +1,130 @@
+use crate::components;
+use crate::units::terrain::place_on_terrain;
+use components::capture_marker::CaptureMarker;
+use components::match_capture_the_flag::MatchCaptureTheFlag;
+use components::pose::{world_pose, Pose};
+use components::team::TeamId;
+use components::team_flag::{FlagState, TeamFlag};
+use components::team_member::TeamMember;
+use components::unit::UnitId;
+use components::unit_member::UnitMember;
+
+use crate::util::cgmath::prelude::*;
+use engine::prelude::*;
+
+/// Height above the capture marker of the carrier at which the flag is carried.
+const CARRY_HEIGHT: f32 = 0.5;
+
+fn set_pose(world: &mut World, entity: EntityId, new_pose: Pose) {
+    if let Some(mut pose) = world.component_mut::<Pose>(entity) {
+        *pose = new_pose;
+    }
+}
+
+/// Puts the flag on the floor at x, y.
+fn place_flag(world: &mut World, entity: EntityId, x: f32, y: f32) {
+    set_pose(world, entity, Pose::from_se2(x, y, 0.0));
+    place_on_terrain(world, entity);
+}
+
+pub struct MatchLogicCaptureTheFlag {}
+impl System for MatchLogicCaptureTheFlag {
+    fn update(&mut self, world: &mut World) {
+        let flags = world
+            .component_iter::<TeamFlag>()
+            .map(|(e, f)| (e, *f))
+            .collect::<Vec<(EntityId, TeamFlag)>>();
+        if flags.is_empty() {
+            return;
+        }
+
+        // Units that can interact with flags, markers are removed when the unit is destroyed.
+        let mut markers: Vec<(UnitId, TeamId, cgmath::Vector3<f32>)> = vec![];
+        for (marker_entity, _marker) in world.component_iter::<CaptureMarker>() {
+            let team = world
+                .component::<TeamMember>(marker_entity)
+                .map(|t| t.team());
+            let unit = world
+                .component::<UnitMember>(marker_entity)
+                .map(|u| u.unit());
+            if let (Some(team), Some(unit)) = (team, unit) {
+                let position = world_pose(world, marker_entity).to_translation();
+                markers.push((unit, team, position));
+            }
+        }
+
+        let mut carriers = flags
+            .iter()
+            .filter_map(|(_, f)| f.carrier())
+            .collect::<std::collections::HashSet<UnitId>>();
+        let mut captures = vec![];
+
+        for (flag_entity, mut flag) in flags.iter().copied() {
+            let flag_position = world_pose(world, flag_entity).to_translation();
+            match flag.state() {
+                FlagState::Carried => {
+                    let carrier = flag.carrier().expect("carried flag has a carrier");
+                    if let Some((_, team, position)) = markers.iter().find(|m| m.0 == carrier) {
+                        let own_base = flags
+                            .iter()
+                            .find(|(_, f)| f.team() == *team)
+                            .map(|(_, f)| (f.base(), f.radius()));
+                        let at_own_base = own_base
+                            .map(|((x, y), radius)| (position.x - x).hypot(position.y - y) < radius)
+                            .unwrap_or(false);
+                        if at_own_base {
+                            flag.return_to_base();
+                            captures.push(*team);
+                        } else {
+                            let carried =
+                                Pose::from_xyz(position.x, position.y, position.z + CARRY_HEIGHT);
+                            set_pose(world, flag_entity, carried);
+                        }
+                    } else {
+                        // The carrier got destroyed, drop the flag where it was.
+                        flag.set_dropped();
+                        place_flag(world, flag_entity, flag_position.x, flag_position.y);
+                    }
+                }
+                FlagState::AtBase | FlagState::Dropped => {
+                    for (unit, team, position) in markers.iter() {
+                        let distance =
+                            (position.x - flag_position.x).hypot(position.y - flag_position.y);
+                        if distance >= flag.radius() {
+                            continue;
+                        }
+                        if *team != flag.team() && !carriers.contains(unit) {
+                            carriers.insert(*unit);
+                            flag.pick_up(*unit);
+                            break;
+                        } else if *team == flag.team() && flag.state() == FlagState::Dropped {
+                            flag.return_to_base();
+                            break;
+                        }
+                    }
+                }
+            }
+
+            // Flags that got returned go back to their base.
+            let (previous_state, new_state) = (
+                world.component::<TeamFlag>(flag_entity).map(|f| f.state()),
+                flag.state(),
+            );
+            if new_state == FlagState::AtBase && previous_state != Some(FlagState::AtBase) {
+                let (x, y) = flag.base();
+                place_flag(world, flag_entity, x, y);
+            }
+            if let Some(mut team_flag) = world.component_mut::<TeamFlag>(flag_entity) {
+                *team_flag = flag;
+            }
+        }
+
+        if let Some((_e, mut capture_the_flag)) =
+            world.component_iter_mut::<MatchCaptureTheFlag>().next()
+        {
+            for team in captures {
+                capture_the_flag.add_capture(team);
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::components;
+use crate::units::terrain::place_on_terrain;
+use components::capture_marker::CaptureMarker;
+use components::clock::Clock;
+use components::match_escort::MatchEscort;
+use components::payload::Payload;
+use components::pose::{world_pose, Pose};
+use components::team_member::TeamMember;
+
+use crate::util::cgmath::prelude::*;
+use engine::prelude::*;
+
+/// Moves the payload forward while only attackers are near it, and back while only defenders are.
+pub struct MatchLogicEscort {}
+impl System for MatchLogicEscort {
+    fn update(&mut self, world: &mut World) {
+        let attackers = if let Some((_e, escort)) = world.component_iter::<MatchEscort>().next() {
+            escort.attackers()
+        } else {
+            return;
+        };
+        let dt = world
+            .component_iter::<Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .step_as_f32();
+
+        let mut progress = None;
+        for payload_entity in world.component_entities::<Payload>() {
+            let payload_position = world_pose(world, payload_entity).to_translation();
+            let radius = world.component::<Payload>(payload_entity).unwrap().radius();
+
+            let (mut attackers_near, mut defenders_near) = (0, 0);
+            for (marker_entity, _marker) in world.component_iter::<CaptureMarker>() {
+                if let Some(team_member) = world.component::<TeamMember>(marker_entity) {
+                    let marker_position = world_pose(world, marker_entity).to_translation();
+                    let distance = (marker_position.x - payload_position.x)
+                        .hypot(marker_position.y - payload_position.y);
+                    if distance < radius {
+                        if team_member.team() == attackers {
+                            attackers_near += 1;
+                        } else {
+                            defenders_near += 1;
+                        }
+                    }
+                }
+            }
+
+            let (x, y, yaw) = {
+                let mut payload = world.component_mut::<Payload>(payload_entity).unwrap();
+                let step = payload.speed() * dt;
+                if attackers_near > 0 && defenders_near == 0 {
+                    payload.advance(step);
+                } else if attackers_near == 0 && defenders_near > 0 {
+                    payload.advance(-step);
+                }
+                progress = Some(payload.progress());
+                payload.pose()
+            };
+            if let Some(mut pose) = world.component_mut::<Pose>(payload_entity) {
+                *pose = Pose::from_se2(x, y, yaw);
+            }
+            place_on_terrain(world, payload_entity);
+        }
+
+        if let Some(progress) = progress {
+            if let Some((_e, mut escort)) = world.component_iter_mut::<MatchEscort>().next() {
+                escort.set_progress(progress);
+            }
+        }
+    }
+}
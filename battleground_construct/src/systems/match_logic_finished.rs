@@ -1,10 +1,13 @@
 use crate::components;
 use crate::components::team::TeamId;
+use components::match_capture_the_flag::MatchCaptureTheFlag;
 use components::match_domination::MatchDomination;
+use components::match_escort::MatchEscort;
 use components::match_finished::{MatchConclusion, MatchFinished, MatchReport, ObjectiveReport};
 use components::match_king_of_the_hill::MatchKingOfTheHill;
 use components::match_team_deathmatch::MatchTeamDeathmatch;
 use components::match_time_limit::MatchTimeLimit;
+use components::scoreboard::Scoreboard;
 
 use engine::prelude::*;
 
@@ -43,6 +46,31 @@ impl System for MatchLogicFinished {
             }
         }
 
+        for (_e, match_capture_the_flag) in world.component_iter::<MatchCaptureTheFlag>() {
+            if match_capture_the_flag.is_finished() {
+                is_finished = true;
+                conclusion = Some(MatchConclusion::Objective);
+                break;
+            }
+        }
+
+        for (_e, match_escort) in world.component_iter::<MatchEscort>() {
+            if match_escort.is_finished() {
+                is_finished = true;
+                conclusion = Some(MatchConclusion::Objective);
+                break;
+            }
+        }
+
+        // Check the scoreboard point limit.
+        for (_e, scoreboard) in world.component_iter::<Scoreboard>() {
+            if scoreboard.is_finished() {
+                is_finished = true;
+                conclusion = Some(MatchConclusion::Objective);
+                break;
+            }
+        }
+
         // Check time limit criteria.
         for (_e, match_time_limit) in world.component_iter::<MatchTimeLimit>() {
             if match_time_limit.is_finished() {
@@ -60,73 +88,93 @@ impl System for MatchLogicFinished {
                 .1
                 .elapsed_as_f32();
 
-            // collect the reports.
+            // collect the reports, and the standing of each team under the match type. Standings are
+            // compared in order; won by the objective, then the points of king of the hill, team
+            // deathmatch and capture the flag, then holding off the escort.
             let mut reports = vec![];
-            let mut winners: std::collections::HashSet<TeamId> = Default::default();
-            let mut leaders: std::collections::HashSet<TeamId> = Default::default();
+            let mut standings: std::collections::HashMap<TeamId, [f32; 5]> = Default::default();
             {
                 for (_e, match_koth) in world.component_iter::<MatchKingOfTheHill>() {
                     let report = match_koth.clone();
                     if let Some(leader) = report.get_leader() {
                         if report.is_finished() {
-                            winners.insert(leader);
-                        } else {
-                            leaders.insert(leader);
+                            standings.entry(leader).or_default()[0] = 1.0;
                         }
                     }
+                    for (team, points) in report.points() {
+                        standings.entry(team).or_default()[1] += points;
+                    }
                     reports.push(ObjectiveReport::MatchKingOfTheHill(report));
                 }
                 for (_e, match_team_deathmatch) in world.component_iter::<MatchTeamDeathmatch>() {
                     let report = match_team_deathmatch.clone();
                     if let Some(leader) = report.get_leader() {
                         if report.is_finished() {
-                            winners.insert(leader.0);
-                        } else {
-                            leaders.insert(leader.0);
+                            standings.entry(leader.0).or_default()[0] = 1.0;
                         }
                     }
+                    for (team, points) in report.points() {
+                        standings.entry(team).or_default()[2] += points as f32;
+                    }
                     reports.push(ObjectiveReport::MatchTeamDeathmatch(report));
                 }
-                for (_e, match_domination) in world.component_iter::<MatchDomination>() {
-                    let report = match_domination.clone();
+                for (_e, match_capture_the_flag) in world.component_iter::<MatchCaptureTheFlag>() {
+                    let report = match_capture_the_flag.clone();
                     if let Some(leader) = report.get_leader() {
                         if report.is_finished() {
-                            winners.insert(leader);
-                        } else {
-                            leaders.insert(leader);
+                            standings.entry(leader.0).or_default()[0] = 1.0;
+                        }
+                    }
+                    for (team, points) in report.points() {
+                        standings.entry(team).or_default()[3] += points as f32;
+                    }
+                    reports.push(ObjectiveReport::MatchCaptureTheFlag(report));
+                }
+                for (_e, match_escort) in world.component_iter::<MatchEscort>() {
+                    let report = match_escort.clone();
+                    if report.is_finished() {
+                        standings.entry(report.attackers()).or_default()[0] = 1.0;
+                    } else {
+                        for team in report.defenders() {
+                            standings.entry(*team).or_default()[4] = 1.0;
                         }
                     }
+                    reports.push(ObjectiveReport::MatchEscort(report));
+                }
+                for (_e, match_domination) in world.component_iter::<MatchDomination>() {
+                    let report = match_domination.clone();
+                    if let Some(leader) = report.get_leader() {
+                        standings.entry(leader).or_default()[0] = 1.0;
+                    }
                     reports.push(ObjectiveReport::MatchDomination(report));
                 }
             }
 
-            // We are actually finished... lets collect the information for the match report.
-            if winners.len() > 1 {
-                println!("Got multiple winners: {winners:?}, logic error or draw??");
-            }
-
-            // Lets... get the winner.
-            let mut final_winner: Option<TeamId> = None;
-
-            // If there is a true winner...
-            if !winners.is_empty() {
-                final_winner = winners.iter().copied().collect::<Vec<_>>().first().copied();
-            }
-
-            // Check if we don't have a winner, if not, it is decided by time limit, use the runner
-            // up.
-            if final_winner.is_none() {
-                if leaders.len() > 1 {
-                    println!(
-                        "Got multiple runner ups: {leaders:?}... logic error? Draw? Taking first."
-                    );
-                }
-                final_winner = leaders.iter().copied().collect::<Vec<_>>().first().copied();
-            }
+            // With weighted scoring the scoreboard decides the ranking, otherwise the standings of
+            // the match type do. Teams that can't be told apart share their placement.
+            let standing =
+                |team: TeamId| standings.get(&team).copied().unwrap_or_default().to_vec();
+            let scores = world
+                .component_iter::<Scoreboard>()
+                .next()
+                .map(|(_e, scoreboard)| {
+                    if scoreboard.is_scored() {
+                        scoreboard.scores()
+                    } else {
+                        scoreboard.scores_by(standing)
+                    }
+                })
+                .unwrap_or_else(|| {
+                    let teams = world
+                        .component_iter::<components::team::Team>()
+                        .map(|(_e, t)| t.id())
+                        .collect::<Vec<_>>();
+                    Scoreboard::new(&teams, Default::default()).scores_by(standing)
+                });
 
             // Now, we can create the match report.
             let report = MatchReport {
-                winner: final_winner,
+                scores,
                 conclusion: conclusion.unwrap(),
                 reports,
                 duration,
@@ -30,7 +30,11 @@ impl System for MatchLogicKingOfTheHill {
             }
         }
 
+        // Destroyed units may respawn, a team is only out once it has nothing left to field.
+        let last_team_standing = components::respawn::last_team_standing(world);
+
         if let Some((_e, mut koth)) = world.component_iter_mut::<MatchKingOfTheHill>().next() {
+            koth.set_last_team_standing(last_team_standing);
             let update_pairs = owners
                 .iter()
                 .map(|(t, v)| (*t, *v))
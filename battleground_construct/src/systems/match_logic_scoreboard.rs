@@ -0,0 +1,98 @@
+use crate::components;
+use components::capturable::Capturable;
+use components::capture_point::CapturePoint;
+use components::clock::Clock;
+use components::health::Health;
+use components::hit_by::HitByHistory;
+use components::match_team_deathmatch::{frag_for_destroyed, MatchTeamDeathmatchJustDestroyed};
+use components::scoreboard::{ScoreObjective, Scoreboard};
+use components::team::TeamId;
+use components::team_member::TeamMember;
+use components::unit::Unit;
+
+use engine::prelude::*;
+
+/// Tracks the objectives of the scoreboard, needs to run before the team deathmatch logic removes
+/// the just destroyed markers.
+pub struct MatchLogicScoreboard {}
+impl System for MatchLogicScoreboard {
+    fn update(&mut self, world: &mut World) {
+        let (dt, current) = {
+            let (_e, clock) = world
+                .component_iter_mut::<Clock>()
+                .next()
+                .expect("Should have one clock");
+            (clock.step_as_f32(), clock.elapsed_as_f32())
+        };
+
+        let last_update =
+            if let Some((_e, scoreboard)) = world.component_iter::<Scoreboard>().next() {
+                scoreboard.last_update()
+            } else {
+                return;
+            };
+
+        let mut kills: std::collections::HashMap<TeamId, f32> = Default::default();
+        for entity in world.component_entities::<MatchTeamDeathmatchJustDestroyed>() {
+            if let Some((team, frag)) = frag_for_destroyed(world, entity) {
+                *kills.entry(team).or_insert(0.0) += frag as f32;
+            }
+        }
+
+        let mut capture_time: std::collections::HashMap<TeamId, f32> = Default::default();
+        for (e, capturable) in world.component_iter::<Capturable>() {
+            if world.component::<CapturePoint>(e).is_some() {
+                if let Some(team) = capturable.owner() {
+                    *capture_time.entry(team).or_insert(0.0) += dt;
+                }
+            }
+        }
+
+        // Only count the hits since the last update, hits between teammates don't count.
+        let mut damage: std::collections::HashMap<TeamId, f32> = Default::default();
+        for (entity, history) in world.component_iter::<HitByHistory>() {
+            let target_team = world.component::<TeamMember>(entity).map(|t| t.team());
+            for hit in history
+                .hits()
+                .iter()
+                .filter(|h| h.time() >= last_update && h.time() < current)
+            {
+                let source_team = hit
+                    .source()
+                    .and_then(|u| components::unit::get_unit_entity(world, u))
+                    .and_then(|e| world.component::<TeamMember>(e).map(|t| t.team()));
+                if let Some(source_team) = source_team {
+                    if Some(source_team) != target_team {
+                        *damage.entry(source_team).or_insert(0.0) += hit.damage();
+                    }
+                }
+            }
+        }
+
+        let mut alive: std::collections::HashMap<TeamId, f32> = Default::default();
+        for (entity, _unit) in world.component_iter::<Unit>() {
+            if world.component::<Health>(entity).is_none() {
+                continue;
+            }
+            if let Some(team_member) = world.component::<TeamMember>(entity) {
+                *alive.entry(team_member.team()).or_insert(0.0) += 1.0;
+            }
+        }
+
+        let (_e, mut scoreboard) = world.component_iter_mut::<Scoreboard>().next().unwrap();
+        for (objective, values) in [
+            (ScoreObjective::Kills, kills),
+            (ScoreObjective::CaptureTime, capture_time),
+            (ScoreObjective::DamageDealt, damage),
+        ] {
+            for (team, value) in values {
+                scoreboard.add(team, objective, value);
+            }
+        }
+        for team in scoreboard.teams().to_vec() {
+            let value = alive.get(&team).copied().unwrap_or(0.0);
+            scoreboard.set(team, ScoreObjective::Survival, value);
+        }
+        scoreboard.set_last_update(current);
+    }
+}
@@ -0,0 +1,81 @@
+use super::components::construction::TeamBudget;
+use super::components::deploy::{Deploy, DeployState};
+use super::components::mine::{MineLayer, MineLayerStatus};
+use super::components::team::get_team_entity;
+use super::components::team_member::TeamMember;
+use super::components::unit_member::UnitMember;
+use super::Clock;
+use crate::units::mine::{spawn_mine, MineSpawnConfig};
+use engine::prelude::*;
+
+/// Distance behind the site entity at which mines are placed.
+const MINE_PLACEMENT_OFFSET: f32 = -1.0;
+
+pub struct MineLayerSystem {}
+impl System for MineLayerSystem {
+    fn update(&mut self, world: &mut World) {
+        let current = {
+            let (_entity, clock) = world
+                .component_iter::<Clock>()
+                .next()
+                .expect("Should have one clock");
+            clock.elapsed_as_f32()
+        };
+
+        let mut dropped = vec![];
+        for (entity, mut mine_layer) in world.component_iter_mut::<MineLayer>() {
+            if !mine_layer.take_request() {
+                continue;
+            }
+            let deployed = world
+                .component::<Deploy>(entity)
+                .map(|d| d.get_state() == DeployState::Deployed)
+                .unwrap_or(false);
+            let team_member = world.component::<TeamMember>(entity).map(|t| *t);
+            let mut budget = team_member
+                .and_then(|t| get_team_entity(world, t.team()))
+                .and_then(|e| world.component_mut::<TeamBudget>(e));
+
+            let cost = mine_layer.config().cost;
+            let status = if !deployed {
+                MineLayerStatus::NotDeployed
+            } else if mine_layer.mines() == 0 {
+                MineLayerStatus::Empty
+            } else if !mine_layer.is_ready(current) {
+                MineLayerStatus::NotReady
+            } else if !budget.as_mut().map(|b| b.spend(cost)).unwrap_or(true) {
+                MineLayerStatus::InsufficientBudget
+            } else {
+                mine_layer.take_mine(current);
+                dropped.push((
+                    entity,
+                    mine_layer.site_entity(),
+                    *mine_layer.config(),
+                    team_member,
+                ));
+                MineLayerStatus::Dropped
+            };
+            mine_layer.set_status(status);
+        }
+
+        for (entity, site_entity, config, team_member) in dropped {
+            use crate::util::cgmath::prelude::*;
+            let site = super::components::pose::world_pose(world, site_entity);
+            let placement = site.transform()
+                * cgmath::Matrix4::from_translation(cgmath::vec3(MINE_PLACEMENT_OFFSET, 0.0, 0.0));
+            let position = placement.to_translation();
+            let source = world.component::<UnitMember>(entity).map(|m| m.unit());
+            spawn_mine(
+                world,
+                MineSpawnConfig {
+                    x: position.x,
+                    y: position.y,
+                    config,
+                    team_member,
+                    source,
+                    time: current,
+                },
+            );
+        }
+    }
+}
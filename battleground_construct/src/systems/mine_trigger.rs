@@ -0,0 +1,100 @@
+use super::components::group::Group;
+use super::components::health::Health;
+use super::components::impact::Impact;
+use super::components::mine::Mine;
+use super::components::pose::{world_pose, Pose};
+use super::components::team_member::TeamMember;
+use super::components::unit::Unit;
+use super::components::unit_source::UnitSource;
+use super::Clock;
+use crate::util::cgmath::prelude::*;
+use engine::prelude::*;
+
+/// Detonates armed mines when a unit of another team is within their trigger radius, the mine is
+/// turned into an impact such that the splash damage is processed like that of a projectile.
+pub struct MineTrigger {}
+impl System for MineTrigger {
+    fn update(&mut self, world: &mut World) {
+        let current = {
+            let (_entity, clock) = world
+                .component_iter::<Clock>()
+                .next()
+                .expect("Should have one clock");
+            clock.elapsed_as_f32()
+        };
+
+        let mines = world
+            .component_iter::<Mine>()
+            .filter(|(_, mine)| mine.is_armed(current))
+            .map(|(entity, mine)| (entity, *mine))
+            .collect::<Vec<_>>();
+        if mines.is_empty() {
+            return;
+        }
+
+        // The unit entity doesn't have a pose, use the first member of the group that does.
+        let mut units = vec![];
+        for (entity, _unit) in world.component_iter::<Unit>() {
+            let alive = world
+                .component::<Health>(entity)
+                .map(|h| !h.is_destroyed())
+                .unwrap_or(false);
+            if !alive {
+                continue;
+            }
+            let group = world.component::<Group>(entity);
+            let posed = group.and_then(|g| {
+                g.entities()
+                    .iter()
+                    .find(|e| world.component::<Pose>(**e).is_some())
+                    .copied()
+            });
+            if let Some(posed) = posed {
+                let team = world.component::<TeamMember>(entity).map(|t| t.team());
+                units.push((world_pose(world, posed).to_translation(), team));
+            }
+        }
+
+        for (mine_entity, mine) in mines {
+            let mine_pose = world_pose(world, mine_entity);
+            let position = mine_pose.to_translation();
+            let triggered = units.iter().any(|(unit_position, team)| {
+                mine.is_triggered_by(*team)
+                    && position.distance2(*unit_position)
+                        <= mine.trigger_radius() * mine.trigger_radius()
+            });
+            if !triggered {
+                continue;
+            }
+            detonate(world, mine_entity);
+            let source = world
+                .component::<UnitSource>(mine_entity)
+                .map(|s| s.source());
+            world.add_component(
+                mine_entity,
+                Impact::new(None, *mine_pose.transform(), source),
+            );
+        }
+    }
+}
+
+fn detonate(world: &mut World, mine_entity: EntityId) {
+    let radius = world
+        .component::<crate::components::damage_splash::DamageSplash>(mine_entity)
+        .map(|s| s.radius())
+        .unwrap_or(1.0);
+    let explosion_entity = world.add_entity();
+    let effect_id = crate::components::id_generator::generate_id(world);
+    let mine_pose = world_pose(world, mine_entity);
+    world.add_component(
+        explosion_entity,
+        crate::display::particle_emitter::ParticleEmitter::explosion(effect_id, radius),
+    );
+    world.add_component(explosion_entity, mine_pose);
+    world.add_component(
+        explosion_entity,
+        crate::components::expiry::Expiry::lifetime(10.0),
+    );
+    world.remove_component::<Mine>(mine_entity);
+    world.remove_component::<crate::display::mine::Mine>(mine_entity);
+}
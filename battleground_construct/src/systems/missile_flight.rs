@@ -0,0 +1,42 @@
+use super::components::missile::Missile;
+use super::components::pose::Pose;
+use super::components::velocity::Velocity;
+use super::Clock;
+use crate::util::cgmath::prelude::*;
+use cgmath::InnerSpace;
+use engine::prelude::*;
+
+/// Steers the guided missiles and aligns their pose with the direction of flight, needs to run
+/// before the velocities are integrated.
+pub struct MissileFlight {}
+impl System for MissileFlight {
+    fn update(&mut self, world: &mut World) {
+        let dt = {
+            let (_entity, clock) = world
+                .component_iter::<Clock>()
+                .next()
+                .expect("Should have one clock");
+            clock.step_as_f32()
+        };
+
+        for entity in world.component_entities::<Missile>() {
+            let speed = {
+                let mut missile = world.component_mut::<Missile>(entity).unwrap();
+                let mut pose = if let Some(pose) = world.component_mut::<Pose>(entity) {
+                    pose
+                } else {
+                    continue;
+                };
+                missile.update(pose.to_translation(), dt);
+                *pose = Pose::from_mat4(missile.pose(pose.to_translation()));
+                missile.velocity().magnitude()
+            };
+
+            // The pose is aligned with the velocity, so the missile moves along its local x axis.
+            world.add_component(
+                entity,
+                Velocity::from_linear(cgmath::Vector3::new(speed, 0.0, 0.0)),
+            );
+        }
+    }
+}
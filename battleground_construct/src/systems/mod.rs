@@ -2,6 +2,8 @@ pub mod acceleration_velocity;
 pub mod cannon_trigger;
 pub mod capture;
 pub mod clock;
+pub mod collision;
+pub mod construction;
 pub mod deploy;
 pub mod destroy;
 pub mod display_capture_flag;
@@ -16,11 +18,17 @@ pub mod health_check;
 pub mod health_tank_body;
 pub mod kinematics_differential_drive;
 pub mod kinematics_tricycle;
+pub mod match_logic_capture_the_flag;
 pub mod match_logic_domination;
+pub mod match_logic_escort;
 pub mod match_logic_finished;
 pub mod match_logic_king_of_the_hill;
+pub mod match_logic_scoreboard;
 pub mod match_logic_team_deathmatch;
 pub mod match_logic_time_limit;
+pub mod mine_layer;
+pub mod mine_trigger;
+pub mod missile_flight;
 pub mod playback;
 pub mod playback_finished;
 pub mod playback_units;
@@ -30,10 +38,16 @@ pub mod projectile_hit;
 pub mod radar_scan;
 pub mod radio_transmission;
 pub mod record;
+pub mod repair;
+pub mod respawn;
+pub mod resupply;
 pub mod revolute_pose;
 pub mod revolute_update;
 pub mod revolute_velocity;
+pub mod sensor_update;
+pub mod team_blackboard_update;
 pub mod team_color_body;
+pub mod terrain_follow;
 pub mod timed_function;
 pub mod unit_control;
 pub mod unit_controller_error_check;
@@ -69,6 +69,68 @@ impl System for PlaybackUnits {
             }
         }
 
+        for entity in world.component_entities::<units::scout::UnitScout>() {
+            let needs_spawn = world
+                .component::<components::recording::PlaybackUnitCreatedMarker>(entity)
+                .is_none();
+            let is_destroyed = world
+                .component::<components::recording::PlaybackUnitDestroyedMarker>(entity)
+                .is_some();
+            let health_present = world
+                .component::<components::health::Health>(entity)
+                .is_some();
+
+            if needs_spawn && health_present {
+                let scout_unit = *world.component::<units::scout::UnitScout>(entity).unwrap();
+                scout::add_scout_passive(world, &scout_unit);
+                world.add_component(
+                    scout_unit.unit_entity(),
+                    components::recording::PlaybackUnitCreatedMarker,
+                );
+            }
+            if !health_present && !is_destroyed {
+                let scout_unit = *world.component::<units::scout::UnitScout>(entity).unwrap();
+                world.remove_entities(&scout_unit.children());
+                world.add_component(
+                    scout_unit.unit_entity(),
+                    components::recording::PlaybackUnitDestroyedMarker,
+                );
+            }
+        }
+
+        for entity in world.component_entities::<units::missile_launcher::UnitMissileLauncher>() {
+            let needs_spawn = world
+                .component::<components::recording::PlaybackUnitCreatedMarker>(entity)
+                .is_none();
+            let is_destroyed = world
+                .component::<components::recording::PlaybackUnitDestroyedMarker>(entity)
+                .is_some();
+            let health_present = world
+                .component::<components::health::Health>(entity)
+                .is_some();
+
+            if needs_spawn && health_present {
+                let launcher_unit = *world
+                    .component::<units::missile_launcher::UnitMissileLauncher>(entity)
+                    .unwrap();
+                missile_launcher::add_missile_launcher_passive(world, &launcher_unit);
+                world.add_component(
+                    launcher_unit.unit_entity(),
+                    components::recording::PlaybackUnitCreatedMarker,
+                );
+            }
+            if !health_present && !is_destroyed {
+                let launcher_unit = *world
+                    .component::<units::missile_launcher::UnitMissileLauncher>(entity)
+                    .unwrap();
+                world.remove_entities(&launcher_unit.children());
+                world.add_component(
+                    launcher_unit.unit_entity(),
+                    components::recording::PlaybackUnitDestroyedMarker,
+                );
+            }
+        }
+
         for entity in world.component_entities::<units::capturable_flag::UnitCapturableFlag>() {
             let needs_spawn = world
                 .component::<components::recording::PlaybackUnitCreatedMarker>(entity)
@@ -82,5 +144,90 @@ impl System for PlaybackUnits {
                 world.add_component(entity, components::recording::PlaybackUnitCreatedMarker);
             }
         }
+
+        for entity in world.component_entities::<units::obstacle::UnitObstacle>() {
+            let needs_spawn = world
+                .component::<components::recording::PlaybackUnitCreatedMarker>(entity)
+                .is_none();
+
+            if needs_spawn {
+                let unit = *world
+                    .component::<units::obstacle::UnitObstacle>(entity)
+                    .unwrap();
+                units::obstacle::add_obstacle_passives(world, &unit);
+                world.add_component(entity, components::recording::PlaybackUnitCreatedMarker);
+            }
+        }
+
+        for entity in world.component_entities::<units::resupply_zone::UnitResupplyZone>() {
+            let needs_spawn = world
+                .component::<components::recording::PlaybackUnitCreatedMarker>(entity)
+                .is_none();
+
+            if needs_spawn {
+                let unit = *world
+                    .component::<units::resupply_zone::UnitResupplyZone>(entity)
+                    .unwrap();
+                units::resupply_zone::add_resupply_zone_passives(world, &unit);
+                world.add_component(entity, components::recording::PlaybackUnitCreatedMarker);
+            }
+        }
+
+        for entity in world.component_entities::<units::structure::UnitStructure>() {
+            let needs_spawn = world
+                .component::<components::recording::PlaybackUnitCreatedMarker>(entity)
+                .is_none();
+
+            if needs_spawn {
+                let unit = *world
+                    .component::<units::structure::UnitStructure>(entity)
+                    .unwrap();
+                units::structure::add_structure_passives(world, &unit);
+                world.add_component(entity, components::recording::PlaybackUnitCreatedMarker);
+            }
+        }
+
+        for entity in world.component_entities::<units::team_flag::UnitTeamFlag>() {
+            let needs_spawn = world
+                .component::<components::recording::PlaybackUnitCreatedMarker>(entity)
+                .is_none();
+
+            if needs_spawn {
+                let unit = *world
+                    .component::<units::team_flag::UnitTeamFlag>(entity)
+                    .unwrap();
+                units::team_flag::add_team_flag_passives(world, &unit);
+                world.add_component(entity, components::recording::PlaybackUnitCreatedMarker);
+            }
+        }
+
+        for entity in world.component_entities::<units::payload::UnitPayload>() {
+            let needs_spawn = world
+                .component::<components::recording::PlaybackUnitCreatedMarker>(entity)
+                .is_none();
+
+            if needs_spawn {
+                let unit = *world
+                    .component::<units::payload::UnitPayload>(entity)
+                    .unwrap();
+                units::payload::add_payload_passives(world, &unit);
+                world.add_component(entity, components::recording::PlaybackUnitCreatedMarker);
+            }
+        }
+
+        for entity in world.component_entities::<units::terrain::UnitTerrain>() {
+            let needs_spawn = world
+                .component::<components::recording::PlaybackUnitCreatedMarker>(entity)
+                .is_none();
+
+            if needs_spawn {
+                let unit = world
+                    .component::<units::terrain::UnitTerrain>(entity)
+                    .unwrap()
+                    .clone();
+                units::terrain::add_terrain_passives(world, &unit);
+                world.add_component(entity, components::recording::PlaybackUnitCreatedMarker);
+            }
+        }
     }
 }
@@ -1,7 +1,14 @@
 use super::components;
+use super::components::cannon::Cannon;
+use super::components::differential_drive_base::DifferentialDriveBase;
 use super::components::group::Group;
+use super::components::gun_battery::GunBattery;
 use super::components::health::Health;
 use super::components::hit_by::HitBy;
+use super::components::hit_zone::{HitZone, Subsystem};
+use super::components::radar::Radar;
+use super::components::subsystem_health::SubsystemHealth;
+use super::components::tricycle_base::TricycleBase;
 use engine::prelude::*;
 
 // Consumes HitBy components and handles logic of hitting a tank.
@@ -22,7 +29,7 @@ impl System for ProcessHitBy {
         }
 
         // Pop all HitBy objects from their components.
-        let hit_by = world.remove_components::<HitBy>(&hits);
+        let mut hit_by = world.remove_components::<HitBy>(&hits);
 
         // Ensure the roots have a HitByHistory
         for (_hit_entity, root_entity) in hit_entity_and_root.iter() {
@@ -35,10 +42,36 @@ impl System for ProcessHitBy {
         }
 
         // Next, we can process the HitBy
-        for (ids, hit_by) in hit_entity_and_root.iter().zip(hit_by.iter()) {
-            // let hit_entity = ids.0;
+        let mut degraded = vec![];
+        for (ids, hit_by) in hit_entity_and_root.iter().zip(hit_by.iter_mut()) {
+            let hit_entity = ids.0;
             let root_entity = ids.1;
-            let hit_by = hit_by.as_ref().expect("all hits should have hitby now.");
+            let hit_by = hit_by.as_mut().expect("all hits should have hitby now.");
+
+            // Apply the armor of the hit zone, this modifies the damage of the hits themselves such
+            // that the history reflects the damage that was actually dealt.
+            if let Some(zone) = world.component::<HitZone>(hit_entity).map(|z| *z) {
+                use crate::util::cgmath::prelude::*;
+                let world_to_local = components::pose::world_pose(world, hit_entity)
+                    .transform()
+                    .to_inv_h();
+                hit_by.scale_damage(|impact| {
+                    zone.multiplier((world_to_local * impact.position()).to_translation())
+                });
+
+                if let Some(subsystem) = zone.subsystem {
+                    if world.component::<SubsystemHealth>(root_entity).is_none() {
+                        world.add_component(root_entity, SubsystemHealth::new());
+                    }
+                    let mut subsystem_health = world
+                        .component_mut::<SubsystemHealth>(root_entity)
+                        .expect("added above");
+                    for (damage, _impact) in hit_by.hits() {
+                        subsystem_health.subtract(subsystem, damage);
+                    }
+                    degraded.push(root_entity);
+                }
+            }
 
             // Modify the health.
             if let Some(ref mut health) = world.component_mut::<Health>(root_entity) {
@@ -54,5 +87,44 @@ impl System for ProcessHitBy {
                 .expect("added above")
                 .add_hits(hit_by);
         }
+
+        for root_entity in degraded {
+            apply_subsystem_degradation(world, root_entity);
+        }
+    }
+}
+
+/// Propagate the subsystem health of a unit to the components in its group.
+fn apply_subsystem_degradation(world: &mut World, root_entity: EntityId) {
+    let subsystem_health = if let Some(v) = world.component::<SubsystemHealth>(root_entity) {
+        *v
+    } else {
+        return;
+    };
+    let members = if let Some(group) = world.component::<Group>(root_entity) {
+        group.entities().to_vec()
+    } else {
+        vec![root_entity]
+    };
+
+    let tracks = subsystem_health.capability(Subsystem::Tracks);
+    let radar = subsystem_health.capability(Subsystem::Radar);
+    let weapon = subsystem_health.capability(Subsystem::Weapon);
+    for entity in members {
+        if let Some(mut base) = world.component_mut::<DifferentialDriveBase>(entity) {
+            base.set_velocity_scale(tracks);
+        }
+        if let Some(mut base) = world.component_mut::<TricycleBase>(entity) {
+            base.set_velocity_scale(tracks);
+        }
+        if let Some(mut radar_component) = world.component_mut::<Radar>(entity) {
+            radar_component.set_range_scale(radar);
+        }
+        if let Some(mut cannon) = world.component_mut::<Cannon>(entity) {
+            cannon.set_reload_scale(1.0 / weapon);
+        }
+        if let Some(mut gun_battery) = world.component_mut::<GunBattery>(entity) {
+            gun_battery.set_reload_scale(1.0 / weapon);
+        }
     }
 }
@@ -7,6 +7,7 @@ use super::components::impact::Impact;
 use super::components::point_projectile::PointProjectile;
 use super::components::pose::world_pose;
 use super::components::pose::Pose;
+use super::components::terrain::Terrain;
 use crate::components::acceleration::Acceleration;
 use crate::components::unit::UnitId;
 use crate::components::unit_source::UnitSource;
@@ -21,6 +22,8 @@ impl System for ProjectileHit {
         struct HitState {
             projectile: EntityId,
             impact: Impact,
+            /// How far the projectile is past the surface, zero for objects it is inside of.
+            depth: f32,
         }
 
         // This fails if at any point someone applies a HitSphere to a PointProjectile.
@@ -47,9 +50,13 @@ impl System for ProjectileHit {
             })
             .collect::<Vec<(EntityId, Option<UnitId>, Pose)>>();
 
+        let terrain = world.component_iter::<Terrain>().next();
+
         {
-            // Get all the hit planes
-            let hit_sphere_with_pose = {
+            // Get all the hit planes, the terrain replaces the floor plane if there is one.
+            let hit_sphere_with_pose = if terrain.is_some() {
+                vec![]
+            } else {
                 let hitplanes = world.component_iter::<HitPlane>();
                 hitplanes
                     .map(|(entity, sphere)| {
@@ -65,7 +72,8 @@ impl System for ProjectileHit {
                     //            hitbox_pose is world -> hitbox.
                     let point_in_hitplane_frame =
                         hitplane_pose.transform().to_inv_h() * projectile_pose.transform();
-                    let inside = hitplane.above(point_in_hitplane_frame.to_translation());
+                    let local = point_in_hitplane_frame.to_translation();
+                    let inside = hitplane.above(local);
                     if inside {
                         let v = HitState {
                             projectile: *projectile_entity,
@@ -74,6 +82,7 @@ impl System for ProjectileHit {
                                 *projectile_pose.transform(),
                                 *source_id,
                             ),
+                            depth: -cgmath::dot(hitplane.direction, local),
                         };
                         projectile_hits.push(v);
                         break; // projectile can only hit one thing.
@@ -81,6 +90,25 @@ impl System for ProjectileHit {
                 }
             }
 
+            // Check the terrain, if there is any.
+            if let Some((terrain_entity, terrain)) = terrain.as_ref() {
+                let heightmap = terrain.heightmap();
+                for (projectile_entity, source_id, projectile_pose) in projectile_poses.iter() {
+                    let position = projectile_pose.to_translation();
+                    if heightmap.is_below(position) {
+                        projectile_hits.push(HitState {
+                            projectile: *projectile_entity,
+                            impact: Impact::new(
+                                Some(*terrain_entity),
+                                *projectile_pose.transform(),
+                                *source_id,
+                            ),
+                            depth: heightmap.height_at(position.x, position.y) - position.z,
+                        });
+                    }
+                }
+            }
+
             // Get all the hitspheres
             let hit_sphere_with_pose = {
                 let hitspheres = world.component_iter::<HitSphere>();
@@ -107,6 +135,7 @@ impl System for ProjectileHit {
                                 *projectile_pose.transform(),
                                 *source_id,
                             ),
+                            depth: 0.0,
                         };
                         projectile_hits.push(v);
                         break; // projectile can only hit one thing.
@@ -143,6 +172,7 @@ impl System for ProjectileHit {
                                 *projectile_pose.transform(),
                                 *source_id,
                             ),
+                            depth: 0.0,
                         };
                         projectile_hits.push(v);
                         break; // projectile can only hit one thing.
@@ -175,6 +205,7 @@ impl System for ProjectileHit {
                                 *projectile_pose.transform(),
                                 *source_id,
                             ),
+                            depth: 0.0,
                         };
                         projectile_hits.push(v);
                         break; // projectile can only hit one thing.
@@ -183,7 +214,24 @@ impl System for ProjectileHit {
             }
         }
 
+        drop(terrain);
+
+        // A projectile can only hit one thing, keep the impact nearest to where it came from.
+        let mut nearest_hits: Vec<HitState> = vec![];
         for v in projectile_hits {
+            if let Some(existing) = nearest_hits
+                .iter_mut()
+                .find(|h| h.projectile == v.projectile)
+            {
+                if v.depth < existing.depth {
+                    *existing = v;
+                }
+            } else {
+                nearest_hits.push(v);
+            }
+        }
+
+        for v in nearest_hits {
             // Run the hit effect before modifying any projectile components.
             let hit_effect = world
                 .component::<HitEffect>(v.projectile)
@@ -203,3 +251,43 @@ impl System for ProjectileHit {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::heightmap::Heightmap;
+
+    #[test]
+    fn test_projectile_hits_terrain_once() {
+        let mut world = World::new();
+        let floor = world.add_entity();
+        world.add_component(floor, Pose::new());
+        world.add_component(floor, HitPlane::new());
+        // Terrain that is below the floor plane everywhere.
+        let heightmap =
+            Heightmap::from_rows(0.0, 0.0, 1.0, &[vec![-2.0, -2.0], vec![-2.0, -2.0]]).unwrap();
+        let terrain = world.add_entity();
+        world.add_component(terrain, Terrain::new(heightmap));
+
+        let hits = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = hits.clone();
+        let effect: crate::components::hit_effect::HitEffectFn =
+            std::rc::Rc::new(move |_: &mut World, _, _: &Impact| counter.set(counter.get() + 1));
+        let projectile = world.add_entity();
+        world.add_component(projectile, PointProjectile::new());
+        world.add_component(projectile, HitEffect::new(effect));
+
+        // Below the floor plane, but above the terrain, it is still flying.
+        world.add_component(projectile, Pose::from_xyz(0.0, 0.0, -1.0));
+        ProjectileHit {}.update(&mut world);
+        assert_eq!(hits.get(), 0);
+        assert!(world.component::<Impact>(projectile).is_none());
+
+        // Into the terrain, a single impact.
+        world.add_component(projectile, Pose::from_xyz(0.0, 0.0, -3.0));
+        ProjectileHit {}.update(&mut world);
+        assert_eq!(hits.get(), 1);
+        let impact = world.component::<Impact>(projectile).unwrap();
+        assert_eq!(impact.impact_on(), Some(terrain));
+    }
+}
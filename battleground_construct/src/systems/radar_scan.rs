@@ -1,31 +1,142 @@
 use super::components::group::Group;
+use super::components::hit_box::HitBox;
+use super::components::hit_collection::HitCollection;
 use super::components::pose::world_pose;
-use super::components::radar::Radar;
+use super::components::radar::{Radar, RadarDetections};
 use super::components::radar_reflector::RadarReflector;
-use crate::display::primitives::Mat4;
+use super::components::random::Random;
+use super::components::team::TeamId;
+use super::components::team_member::TeamMember;
+use super::components::terrain::Terrain;
+use crate::display::primitives::{Mat4, Vec3};
+use crate::util::box_collision::AxisAlignedBox;
+use crate::util::cgmath::prelude::*;
+use crate::util::heightmap::Heightmap;
 use engine::prelude::*;
 
+/// A single box that blocks line of sight, with its world pose already inverted.
+struct Occluder {
+    entity: EntityId,
+    world_to_box: Mat4,
+    aab: AxisAlignedBox<f32>,
+}
+
+/// All geometry that can block the line of sight between a radar and a reflector.
+struct LineOfSight {
+    occluders: Vec<Occluder>,
+    heightmap: Option<Heightmap>,
+}
+
+impl LineOfSight {
+    fn from_world(world: &World) -> Self {
+        let mut occluders = vec![];
+        let mut add_box = |entity: EntityId, transform: Mat4, hitbox: &HitBox| {
+            occluders.push(Occluder {
+                entity,
+                world_to_box: transform.to_inv_h(),
+                aab: AxisAlignedBox::new(hitbox.length(), hitbox.width(), hitbox.height()),
+            });
+        };
+
+        for (entity, hitbox) in world.component_iter::<HitBox>() {
+            add_box(entity, *world_pose(world, entity).transform(), &hitbox);
+        }
+        for (entity, collection) in world.component_iter::<HitCollection>() {
+            let pose = world_pose(world, entity);
+            for (hitbox_transform, hitbox) in collection.hit_boxes() {
+                add_box(entity, pose.transform() * hitbox_transform, hitbox);
+            }
+        }
+
+        let heightmap = world
+            .component_iter::<Terrain>()
+            .next()
+            .map(|(_, t)| t.heightmap().clone());
+
+        LineOfSight {
+            occluders,
+            heightmap,
+        }
+    }
+
+    /// Cast a ray from p0 to p1, returns true if anything blocks it. Entities for which `ignore`
+    /// returns true are not considered.
+    fn is_occluded<F: Fn(EntityId) -> bool>(&self, p0: Vec3, p1: Vec3, ignore: F) -> bool {
+        let to_h = |p: Vec3| p.extend(1.0);
+        self.occluders.iter().any(|occluder| {
+            !ignore(occluder.entity) && {
+                let p0_local = (occluder.world_to_box * to_h(p0)).truncate();
+                let p1_local = (occluder.world_to_box * to_h(p1)).truncate();
+                occluder.aab.is_intersecting(p0_local, p1_local)
+            }
+        }) || self
+            .heightmap
+            .as_ref()
+            .is_some_and(|h| h.is_intersecting(p0, p1))
+    }
+}
+
 pub struct RadarScan {}
 impl System for RadarScan {
     fn update(&mut self, world: &mut World) {
-        let mut reflectors: Vec<(Mat4, f32, Group)> = vec![];
+        // Reflectors with a limited range for other teams also carry their team.
+        type EnemyRange = Option<(f32, Option<TeamId>)>;
+        let mut reflectors: Vec<(Mat4, f32, Group, EntityId, EnemyRange)> = vec![];
         for (entity, reflector) in world.component_iter::<RadarReflector>() {
             let pose = world_pose(world, entity);
+            let team = world.component::<TeamMember>(entity).map(|t| t.team());
             reflectors.push((
                 *pose.transform(),
                 reflector.reflectivity(),
                 world.component::<Group>(entity).unwrap().clone(),
+                entity,
+                reflector.enemy_range().map(|r| (r, team)),
             ));
         }
 
+        let line_of_sight = LineOfSight::from_world(world);
+        let mut random = world
+            .component_iter_mut::<Random>()
+            .next()
+            .expect("should have a random generator, are default components added?")
+            .1;
+
+        let mut detections = vec![];
         for (entity, mut radar) in world.component_iter_mut::<Radar>() {
             let radar_pose = world_pose(world, entity);
+            let radar_position = radar_pose.to_translation();
+            let radar_group = world.component::<Group>(entity);
+            let radar_team = world.component::<TeamMember>(entity).map(|t| t.team());
+            let own_entity = |e: EntityId| {
+                e == entity
+                    || radar_group
+                        .as_ref()
+                        .is_some_and(|g| g.entities().contains(&e))
+            };
             let reflectors = reflectors
                 .iter()
                 .filter(|v| !v.2.entities().contains(&entity))
-                .map(|v| (v.0, v.1))
+                .filter(|v| match v.4 {
+                    Some((range, team)) if team.is_none() || team != radar_team => {
+                        radar_position.distance2(v.0.to_translation()) <= range * range
+                    }
+                    _ => true,
+                })
+                .filter(|v| {
+                    // The reflector sits inside its own unit, so that can't occlude it.
+                    !line_of_sight.is_occluded(radar_position, v.0.to_translation(), |e| {
+                        own_entity(e) || v.2.entities().contains(&e)
+                    })
+                })
+                .map(|v| (v.0, v.1, Some(v.3)))
                 .collect::<Vec<_>>();
-            radar.update_reflections(&radar_pose, &reflectors);
+            radar.update_reflections_from(&radar_pose, &reflectors, &mut random);
+            detections.push((entity, RadarDetections::from_radar(&radar)));
+        }
+        drop(random);
+
+        for (entity, detection) in detections {
+            world.add_component(entity, detection);
         }
     }
 }
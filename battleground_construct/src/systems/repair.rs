@@ -0,0 +1,106 @@
+use super::components::group::Group;
+use super::components::health::Health;
+use super::components::pose::{world_pose, Pose};
+use super::components::repair::{Repair, RepairHistory};
+use super::components::team_member::TeamMember;
+use super::components::unit::Unit;
+use super::Clock;
+use crate::util::cgmath::prelude::*;
+use engine::prelude::*;
+
+pub struct RepairSystem {}
+impl System for RepairSystem {
+    fn update(&mut self, world: &mut World) {
+        let current = {
+            let (_entity, clock) = world
+                .component_iter::<Clock>()
+                .next()
+                .expect("Should have one clock");
+            clock.elapsed_as_f32()
+        };
+
+        // Collect the repairs that restore health this cycle.
+        let mut repairs = vec![];
+        for (entity, mut repair) in world.component_iter_mut::<Repair>() {
+            if repair.is_active() && repair.is_due(current) {
+                let position = world_pose(world, entity).to_translation();
+                repairs.push((position, *repair));
+            }
+        }
+        if repairs.is_empty() {
+            return;
+        }
+
+        // The unit entity doesn't have a pose, use the first member of the group that does.
+        let mut units = vec![];
+        for (entity, unit) in world.component_iter::<Unit>() {
+            let group = world.component::<Group>(entity);
+            let posed = group.and_then(|g| {
+                g.entities()
+                    .iter()
+                    .find(|e| world.component::<Pose>(**e).is_some())
+                    .copied()
+            });
+            if let Some(posed) = posed {
+                let team = world.component::<TeamMember>(entity).map(|t| t.team());
+                units.push((entity, unit.id(), posed, team));
+            }
+        }
+
+        let mut repaired = vec![];
+        for (entity, unit_id, posed, team) in units {
+            let position = world_pose(world, posed).to_translation();
+            for (repair_position, repair) in repairs.iter() {
+                let in_range =
+                    position.distance2(*repair_position) <= repair.radius() * repair.radius();
+                let same_team = repair.team().map(|t| Some(t) == team).unwrap_or(true);
+                // A constructor can't repair itself.
+                let is_self = repair.source() == Some(unit_id);
+                if !in_range || !same_team || is_self {
+                    continue;
+                }
+                let restored = if let Some(mut health) = world.component_mut::<Health>(entity) {
+                    health.add(repair.amount())
+                } else {
+                    0.0
+                };
+                if restored <= 0.0 {
+                    continue;
+                }
+                if world.component::<RepairHistory>(entity).is_none() {
+                    world.add_component(entity, RepairHistory::new());
+                }
+                world
+                    .component_mut::<RepairHistory>(entity)
+                    .expect("added above")
+                    .add_repair(restored, repair.source(), current);
+                repaired.push(posed);
+            }
+        }
+
+        for posed in repaired {
+            repair_effect(world, posed);
+        }
+    }
+}
+
+/// Spray of green particles rising from the repaired unit.
+fn repair_effect(world: &mut World, entity: EntityId) {
+    let emitter_entity = world.add_entity();
+    let effect_id = super::components::id_generator::generate_id(world);
+    world.add_component(
+        emitter_entity,
+        crate::display::particle_emitter::ParticleEmitter::bullet_impact(
+            effect_id,
+            0.03,
+            crate::display::Color::GREEN,
+            cgmath::vec3(0.0, 0.0, 10.0),
+        ),
+    );
+    let pose = world_pose(world, entity);
+    world.add_component(emitter_entity, pose);
+    world.add_component(
+        emitter_entity,
+        super::components::expiry::Expiry::lifetime(3.0),
+    );
+}
@@ -0,0 +1,88 @@
+use super::components::health::Health;
+use super::components::random::Random;
+use super::components::respawn::{add_respawn, Reinforcement, Respawn, RespawnConfig};
+use super::Clock;
+use crate::config::setup::scenario_config;
+use engine::prelude::*;
+
+/// Respawns destroyed units once their delay has passed, in the respawn zone of their team.
+pub struct RespawnSystem {}
+impl System for RespawnSystem {
+    fn update(&mut self, world: &mut World) {
+        let config = if let Some(config) = scenario_config::<RespawnConfig>(world) {
+            config
+        } else {
+            return;
+        };
+        let current = world
+            .component_iter::<Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .elapsed_as_f32();
+
+        // Units that lost their health are destroyed, collect the ones that are due.
+        let mut due = vec![];
+        for (entity, mut respawn) in world.component_iter_mut::<Respawn>() {
+            if world.component::<Health>(entity).is_none() && respawn.is_due(current, config.delay)
+            {
+                due.push(entity);
+            }
+        }
+
+        for entity in due {
+            let respawn = *world
+                .remove_component::<Respawn>(entity)
+                .expect("just iterated over it");
+            let spawner = respawn.spawner();
+            let spawned = if let Some(zone) = config.zone(spawner.team()) {
+                let (x, y) = {
+                    let (_, mut random) = world
+                        .component_iter_mut::<Random>()
+                        .next()
+                        .expect("Should have one random");
+                    // Square root to distribute the positions uniformly over the area.
+                    let r = zone.radius * random.uniform().sqrt();
+                    let angle = random.uniform_range(0.0, std::f32::consts::TAU);
+                    (zone.x + r * angle.cos(), zone.y + r * angle.sin())
+                };
+                spawner.spawn_at(world, x, y, zone.yaw)
+            } else {
+                spawner.spawn(world)
+            };
+            // The same spawner created this unit during setup, so spawning it again succeeds.
+            let unit_entity = spawned.expect("respawning a unit that spawned before");
+            if let Some(next) = respawn.next() {
+                world.add_component(unit_entity, next);
+            }
+        }
+    }
+}
+
+/// Spawns the reinforcement waves when they are due.
+pub struct ReinforcementSystem {}
+impl System for ReinforcementSystem {
+    fn update(&mut self, world: &mut World) {
+        let current = world
+            .component_iter::<Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .elapsed_as_f32();
+
+        let mut spawners = vec![];
+        for (_entity, mut reinforcement) in world.component_iter_mut::<Reinforcement>() {
+            if reinforcement.is_due(current) {
+                spawners.extend(reinforcement.spawners().iter().cloned());
+            }
+        }
+
+        for spawner in spawners {
+            // Setup already created the controllers of the waves, so this succeeds.
+            let unit_entity = spawner
+                .spawn(world)
+                .expect("reinforcements are validated during setup");
+            add_respawn(world, unit_entity, spawner);
+        }
+    }
+}
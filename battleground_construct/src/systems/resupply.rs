@@ -0,0 +1,48 @@
+use super::components::ammunition::Ammunition;
+use super::components::pose::world_pose;
+use super::components::resupply::Resupply;
+use super::components::team_member::TeamMember;
+use super::Clock;
+use crate::util::cgmath::prelude::*;
+use engine::prelude::*;
+
+pub struct ResupplySystem {}
+impl System for ResupplySystem {
+    fn update(&mut self, world: &mut World) {
+        let current = {
+            let (_entity, clock) = world
+                .component_iter::<Clock>()
+                .next()
+                .expect("Should have one clock");
+            clock.elapsed_as_f32()
+        };
+
+        // Collect the resupplies that hand out a round this cycle.
+        let mut resupplies = vec![];
+        for (entity, mut resupply) in world.component_iter_mut::<Resupply>() {
+            if resupply.is_active() && resupply.is_due(current) {
+                let position = world_pose(world, entity).to_translation();
+                resupplies.push((position, resupply.radius(), resupply.team()));
+            }
+        }
+        if resupplies.is_empty() {
+            return;
+        }
+
+        for (entity, mut ammunition) in world.component_iter_mut::<Ammunition>() {
+            if ammunition.is_full() {
+                continue;
+            }
+            let position = world_pose(world, entity).to_translation();
+            let team = world.component::<TeamMember>(entity).map(|t| t.team());
+            let rounds = resupplies
+                .iter()
+                .filter(|(resupply_position, radius, resupply_team)| {
+                    position.distance2(*resupply_position) <= radius * radius
+                        && resupply_team.map(|t| Some(t) == team).unwrap_or(true)
+                })
+                .count();
+            ammunition.resupply(rounds as u32);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use super::components::clock::Clock;
+use super::components::gps::{Gps, GpsConfig};
+use super::components::imu::Imu;
+use super::components::pose::world_pose;
+use super::components::random::Random;
+use crate::config::setup::scenario_config;
+use engine::prelude::*;
+
+/// Update the gps readings and imu rates from the current poses.
+pub struct SensorUpdate {}
+impl System for SensorUpdate {
+    fn update(&mut self, world: &mut World) {
+        let (time, dt) = {
+            let (_entity, clock) = world
+                .component_iter::<Clock>()
+                .next()
+                .expect("Should have one clock");
+            (clock.elapsed_as_f32(), clock.step_as_f32())
+        };
+
+        let config = scenario_config::<GpsConfig>(world).unwrap_or_default();
+        let mut random = world
+            .component_iter_mut::<Random>()
+            .next()
+            .expect("should have a random generator, are default components added?")
+            .1;
+        for (entity, mut gps) in world.component_iter_mut::<Gps>() {
+            let pose = world_pose(world, entity);
+            gps.update(&config, time, pose.transform(), &mut random);
+        }
+
+        for (entity, mut imu) in world.component_iter_mut::<Imu>() {
+            let pose = world_pose(world, entity);
+            imu.update(pose.transform(), dt);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::components::team_blackboard::TeamBlackboard;
+use engine::prelude::*;
+
+/// Applies the writes the unit controllers made to the team blackboards.
+pub struct TeamBlackboardUpdate {}
+impl System for TeamBlackboardUpdate {
+    fn update(&mut self, world: &mut World) {
+        for (_entity, mut blackboard) in world.component_iter_mut::<TeamBlackboard>() {
+            blackboard.apply_writes();
+        }
+    }
+}
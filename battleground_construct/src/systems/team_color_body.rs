@@ -1,6 +1,7 @@
 use super::components;
 use crate::display::artillery_body::ArtilleryBody;
 use crate::display::flag::Flag;
+use crate::display::obstacle::Obstacle;
 use crate::display::tank_body::TankBody;
 use crate::display::wheeled_body::WheeledBody;
 use engine::prelude::*;
@@ -37,6 +38,15 @@ impl System for TeamColorBody {
                     }
                 }
 
+                if world
+                    .component::<crate::units::structure::UnitStructure>(entity)
+                    .is_some()
+                {
+                    if let Some(mut obstacle) = world.component_mut::<Obstacle>(entity) {
+                        obstacle.set_color(*team.color());
+                    }
+                }
+
                 if let Some(base) =
                     world.component::<crate::units::base_tricycle::BaseTricycle>(entity)
                 {
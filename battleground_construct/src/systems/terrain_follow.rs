@@ -0,0 +1,75 @@
+use super::components::pose::Pose;
+use super::components::terrain::{Terrain, TerrainFollower};
+use crate::display::primitives::{Mat4, Vec3};
+use engine::prelude::*;
+
+/// Distance over which the surface normal is determined, roughly half a vehicle's length.
+const NORMAL_DISTANCE: f32 = 0.5;
+
+/// Places terrain followers on the terrain surface, their heading is retained while their roll and
+/// pitch follow the surface.
+pub struct TerrainFollow {}
+impl System for TerrainFollow {
+    fn update(&mut self, world: &mut World) {
+        use cgmath::InnerSpace;
+        let (_entity, terrain) = if let Some(v) = world.component_iter::<Terrain>().next() {
+            v
+        } else {
+            return;
+        };
+        let heightmap = terrain.heightmap();
+
+        for entity in world.component_entities::<TerrainFollower>() {
+            if let Some(mut pose) = world.component_mut::<Pose>(entity) {
+                let x = pose.x();
+                let y = pose.y();
+                let heading = pose.transform().x.truncate();
+                let yaw = heading.y.atan2(heading.x);
+                let normal = heightmap.normal_at(x, y, NORMAL_DISTANCE);
+                // Tilt the heading vertically into the surface, such that the yaw is unchanged.
+                let (dx, dy) = (yaw.cos(), yaw.sin());
+                let dz = -(normal.x * dx + normal.y * dy) / normal.z;
+                let x_axis = Vec3::new(dx, dy, dz).normalize();
+                let y_axis = normal.cross(x_axis);
+                *pose.transform_mut() = Mat4::from_cols(
+                    x_axis.extend(0.0),
+                    y_axis.extend(0.0),
+                    normal.extend(0.0),
+                    Vec3::new(x, y, heightmap.height_at(x, y)).extend(1.0),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::heightmap::Heightmap;
+
+    #[test]
+    fn test_terrain_follow() {
+        use crate::util::cgmath::ToRollPitchYaw;
+        let mut world = World::new();
+        // Slope going up along x, one meter per meter.
+        let heightmap =
+            Heightmap::from_rows(0.0, 0.0, 1.0, &[vec![-1.0, 0.0, 1.0], vec![-1.0, 0.0, 1.0]])
+                .unwrap();
+        let terrain = world.add_entity();
+        world.add_component(terrain, Terrain::new(heightmap));
+        let follower = world.add_entity();
+        world.add_component(follower, Pose::from_se2(0.5, 0.0, 0.3));
+        world.add_component(follower, TerrainFollower::new());
+
+        TerrainFollow {}.update(&mut world);
+
+        let pose = world.component::<Pose>(follower).unwrap();
+        assert!((pose.z() - 0.5).abs() < 1e-5);
+        // Heading is retained.
+        let heading = pose.transform().x;
+        assert!((heading.y.atan2(heading.x) - 0.3).abs() < 1e-5);
+        let rpy = pose.to_rpy();
+        // Nose pointing up the slope, the pitch is negative.
+        assert!(rpy.y < -0.5);
+    }
+}
@@ -42,6 +42,7 @@ impl System for UnitControl {
         for (entity, mut controller) in world.component_iter_mut::<UnitController>() {
             if let Some(interface) = interface_map.get_mut(&entity) {
                 let control = controller.vehicle_control();
+                interface.get_mut().trace_begin_update(time);
                 match control.update(&mut *interface.get_mut()) {
                     Ok(_) => {}
                     Err(v) => {
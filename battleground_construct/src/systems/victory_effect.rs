@@ -35,10 +35,11 @@ impl System for VictoryEffect {
         }
 
         // We got here, we ought to spawn victory effects!
-        if report.winner.is_none() {
+        let winner_team_id = if let Some(winner) = report.winner() {
+            winner
+        } else {
             return;
-        }
-        let winner_team_id = report.winner.unwrap();
+        };
         let team_entity = components::team::get_team_entity(world, winner_team_id);
         if team_entity.is_none() {
             return;
@@ -71,6 +72,20 @@ impl System for VictoryEffect {
                                     .expect("unit should exist")
                                     .turret_entity
                             }
+                            UnitType::MissileLauncher => {
+                                world
+                                    .component::<units::missile_launcher::UnitMissileLauncher>(
+                                        entity,
+                                    )
+                                    .expect("unit should exist")
+                                    .turret_entity
+                            }
+                            UnitType::Scout => {
+                                world
+                                    .component::<units::scout::UnitScout>(entity)
+                                    .expect("unit should exist")
+                                    .radar_entity
+                            }
                             UnitType::Tank => {
                                 world
                                     .component::<units::tank::UnitTank>(entity)
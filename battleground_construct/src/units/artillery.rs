@@ -175,6 +175,7 @@ pub fn spawn_artillery(world: &mut World, config: ArtillerySpawnConfig) -> Entit
     world.add_component(unit_entity, unit_artillery);
 
     add_artillery_passive(world, &unit_artillery);
+    add_artillery_hit_zones(world, &unit_artillery);
 
     // -----   Base
 
@@ -197,6 +198,15 @@ pub fn spawn_artillery(world: &mut World, config: ArtillerySpawnConfig) -> Entit
         diff_drive_config,
         MODULE_ARTILLERY_DIFF_DRIVE,
     );
+    super::common::add_common_collider(
+        world,
+        &register_interface,
+        base_entity,
+        components::collider::Collider::from_hit_box(
+            Mat4::from_translation(Vec3::new(0.0, 0.0, ARTILLERY_DIM_FLOOR_TO_BODY_Z)),
+            components::hit_box::HitBox::new(2.5, ARTILLERY_TRACK_WIDTH + 0.2, 0.5),
+        ),
+    );
 
     // world.add_component(base_entity, display::artillery_tracks::ArtilleryTracks::new());
 
@@ -275,6 +285,7 @@ pub fn spawn_artillery(world: &mut World, config: ArtillerySpawnConfig) -> Entit
         muzzle_entity,
         components::gun_battery::GunBattery::new(artillery_battery_config()),
     );
+    super::common::add_ammunition(world, muzzle_entity, |c| c.artillery);
     register_interface.get_mut().add_module(
         "gun_battery",
         MODULE_ARTILLERY_GUN_BATTERY,
@@ -327,6 +338,7 @@ pub fn spawn_artillery(world: &mut World, config: ArtillerySpawnConfig) -> Entit
         // detection_angle_yaw: 45.0f32.to_radians(),
         // detection_angle_pitch: 180f32.to_radians(),
         signal_strength: 1.0,
+        ..Default::default()
     };
     super::common::add_radar(
         world,
@@ -409,10 +421,11 @@ pub fn add_artillery_passive(world: &mut World, unit: &UnitArtillery) {
     world.add_component(unit.rear_track_entity, hit_collection);
 
     // -----   Turret
-    world.add_component(
-        unit.turret_entity,
-        display::artillery_turret::ArtilleryTurret::new(),
-    );
+    let artillery_turret = display::artillery_turret::ArtilleryTurret::new();
+    let hit_collection =
+        components::hit_collection::HitCollection::from_hit_boxes(&artillery_turret.hit_boxes());
+    world.add_component(unit.turret_entity, artillery_turret);
+    world.add_component(unit.turret_entity, hit_collection);
 
     // -----   Barrel
     let artillery_barrel = display::artillery_barrel::ArtilleryBarrel::new();
@@ -452,6 +465,25 @@ pub fn add_artillery_passive(world: &mut World, unit: &UnitArtillery) {
     world.add_component(unit.health_bar_entity, Parent::new(unit.base_entity));
 }
 
+fn add_artillery_hit_zones(world: &mut World, unit: &UnitArtillery) {
+    use components::hit_zone::{HitZone, Subsystem};
+    world.add_component(unit.body_entity, super::common::body_hit_zone());
+    world.add_component(
+        unit.turret_entity,
+        HitZone::uniform(1.0).with_subsystem(Subsystem::Radar),
+    );
+    world.add_component(
+        unit.barrel_entity,
+        HitZone::uniform(0.8).with_subsystem(Subsystem::Weapon),
+    );
+    for track_entity in [unit.front_track_entity, unit.rear_track_entity] {
+        world.add_component(
+            track_entity,
+            HitZone::uniform(0.5).with_subsystem(Subsystem::Tracks),
+        );
+    }
+}
+
 pub fn artillery_battery_config() -> components::gun_battery::GunBatteryConfig {
     let mut poses = vec![];
 
@@ -569,6 +601,9 @@ pub fn artillery_fire_function(world: &mut World, gun_battery_entity: EntityId,
         crate::components::expiry::Expiry::lifetime(15.0),
     );
     world.add_component(emitter_entity, muzzle_pose_raw);
+    if let Some(unit_member) = unit_id {
+        world.add_component(emitter_entity, UnitSource::new(unit_member));
+    }
 }
 
 fn artillery_hit_effect(
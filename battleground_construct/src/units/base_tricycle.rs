@@ -1,14 +1,14 @@
 use super::{Unit, UnitId};
 use crate::components;
 use crate::display;
-use crate::display::primitives::Vec3;
+use crate::display::primitives::{Mat4, Vec3};
 use battleground_unit_control::units::UnitType;
 use components::parent::Parent;
 use components::pose::{Pose, PreTransform};
 use engine::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use battleground_unit_control::units::constructor::*;
+use battleground_unit_control::units::base_tricycle::*;
 
 // Display submodule doesn't use these.
 const BASE_TRICYCLE_RADAR_REFLECTIVITY: f32 = 0.5;
@@ -48,6 +48,16 @@ pub struct BaseTricycleSpawnConfig {
     pub controller: Box<dyn battleground_unit_control::UnitControl>,
     pub team_member: Option<components::team_member::TeamMember>,
     pub radio_config: Option<super::common::RadioConfig>,
+    pub tricycle_config: components::tricycle_base::TricycleConfig,
+}
+
+/// The drive limits of the base tricycle, units may change these for faster or slower variants.
+pub fn base_tricycle_config() -> components::tricycle_base::TricycleConfig {
+    components::tricycle_base::TricycleConfig {
+        wheel_base: BASE_TRICYCLE_WHEEL_BASE,
+        wheel_velocity_bounds: (-1.0, 1.0),
+        wheel_acceleration_bounds: Some((-0.5, 0.5)),
+    }
 }
 
 impl Unit for BaseTricycle {
@@ -160,24 +170,38 @@ pub fn spawn_base_tricycle(
     world.add_component(unit_entity, base_tricycle);
 
     add_base_tricycle_passive(world, &base_tricycle);
+    add_base_tricycle_hit_zones(world, &base_tricycle);
 
     // -----   Base
     let body = display::wheeled_body::WheeledBody::new();
 
     world.add_component(base_entity, Pose::from_se2(config.x, config.y, config.yaw));
-    let tricycle_config = components::tricycle_base::TricycleConfig {
-        wheel_base: BASE_TRICYCLE_WHEEL_BASE,
-        wheel_velocity_bounds: (-1.0, 1.0),
-        wheel_acceleration_bounds: Some((-0.5, 0.5)),
-    };
     super::common::add_common_tricycle(
         world,
         &register_interface,
         base_entity,
         front_left_steer_entity,
-        tricycle_config,
+        config.tricycle_config,
         MODULE_BASE_TRICYCLE_DRIVE,
     );
+    let body_hitbox = body.hitbox();
+    super::common::add_common_collider(
+        world,
+        &register_interface,
+        base_entity,
+        components::collider::Collider::from_hit_box(
+            Mat4::from_translation(Vec3::new(
+                body.center_offset(),
+                0.0,
+                BASE_TRICYCLE_DIM_FLOOR_TO_BODY_Z,
+            )),
+            components::hit_box::HitBox::new(
+                body_hitbox.length(),
+                body.track_width() + BASE_TRICYCLE_WHEEL_WIDTH,
+                0.5,
+            ),
+        ),
+    );
     world.add_component(
         base_entity,
         components::tricycle_front_wheels::TricycleFrontWheels::new(&[
@@ -320,6 +344,22 @@ pub fn spawn_base_tricycle(
     base_tricycle
 }
 
+fn add_base_tricycle_hit_zones(world: &mut World, unit: &BaseTricycle) {
+    use components::hit_zone::{HitZone, Subsystem};
+    world.add_component(unit.center_entity, super::common::body_hit_zone());
+    for wheel_entity in [
+        unit.rear_left_wheel_entity,
+        unit.rear_right_wheel_entity,
+        unit.front_left_wheel_entity,
+        unit.front_right_wheel_entity,
+    ] {
+        world.add_component(
+            wheel_entity,
+            HitZone::uniform(0.5).with_subsystem(Subsystem::Tracks),
+        );
+    }
+}
+
 pub fn add_base_tricycle_passive(world: &mut World, unit: &BaseTricycle) {
     // -----   Body
     let body = display::wheeled_body::WheeledBody::new();
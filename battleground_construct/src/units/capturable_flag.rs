@@ -1,5 +1,6 @@
 use crate::components;
 use crate::display;
+use crate::display::primitives::{Mat4, Vec3};
 use components::pose::Pose;
 use engine::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -61,6 +62,15 @@ pub fn spawn_capturable_flag(world: &mut World, config: CapturableFlagConfig) ->
     );
     world.add_component(capturable_entity, capturable);
 
+    // The pole itself blocks units.
+    world.add_component(
+        capturable_entity,
+        components::collider::Collider::from_hit_box(
+            Mat4::from_translation(Vec3::new(0.0, 0.0, 1.0)),
+            components::hit_box::HitBox::new(0.2, 0.2, 2.0),
+        ),
+    );
+
     capturable_entity
 }
 
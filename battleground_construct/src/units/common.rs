@@ -1,4 +1,5 @@
 use crate::components;
+use crate::config::setup::scenario_config;
 use battleground_unit_control::units::common;
 use battleground_unit_control::units::UnitType;
 use components::unit::UnitId;
@@ -29,6 +30,11 @@ pub fn add_common_global(register_interface: &RegisterInterfaceContainer) {
     );
 }
 
+/// Armor of the main body of a unit; the front is thickest, the rear is weakest.
+pub fn body_hit_zone() -> components::hit_zone::HitZone {
+    components::hit_zone::HitZone::directional(0.7, 1.0, 1.5)
+}
+
 pub fn add_common_unit(
     world: &mut World,
     register_interface: &RegisterInterfaceContainer,
@@ -37,6 +43,10 @@ pub fn add_common_unit(
 ) -> UnitId {
     // -----   Unit
     world.add_component(unit_entity, components::health::Health::new());
+    world.add_component(
+        unit_entity,
+        components::subsystem_health::SubsystemHealth::new(),
+    );
     world.add_component(unit_entity, components::eternal::Eternal::new());
     register_interface.get_mut().add_module(
         "team",
@@ -55,6 +65,14 @@ pub fn add_common_unit(
         components::unit::UnitModuleComponent::new(unit_entity),
     );
 
+    if scenario_config::<components::team_blackboard::TeamBlackboardConfig>(world).is_some() {
+        register_interface.get_mut().add_module(
+            "team_blackboard",
+            common::MODULE_TEAM_BLACKBOARD,
+            components::team_blackboard::TeamBlackboardModule::new(unit_entity),
+        );
+    }
+
     unit_id
 }
 
@@ -70,11 +88,18 @@ pub fn add_common_diff_drive(
         base_entity,
         components::differential_drive_base::DifferentialDriveBase::from_config(config),
     );
+    world.add_component(base_entity, components::terrain::TerrainFollower::new());
     register_interface.get_mut().add_module(
         "diff_drive",
         module_id,
         components::differential_drive_base::DifferentialDriveBaseModule::new(base_entity),
     );
+    world.add_component(base_entity, components::odometry::Odometry::new());
+    register_interface.get_mut().add_module(
+        "odometry",
+        common::MODULE_ODOMETRY,
+        components::odometry::OdometryModule::new(base_entity),
+    );
 }
 
 pub fn add_common_tricycle(
@@ -90,11 +115,50 @@ pub fn add_common_tricycle(
         base_entity,
         components::tricycle_base::TricycleBase::new(config, steer_entity),
     );
+    world.add_component(base_entity, components::terrain::TerrainFollower::new());
     register_interface.get_mut().add_module(
         "tricycle",
         module_id,
         components::tricycle_base::TricycleBaseModule::new(base_entity),
     );
+    world.add_component(base_entity, components::odometry::Odometry::new());
+    register_interface.get_mut().add_module(
+        "odometry",
+        common::MODULE_ODOMETRY,
+        components::odometry::OdometryModule::new(base_entity),
+    );
+}
+
+/// Add a collider to the base entity, with a bumper module to report contacts.
+pub fn add_common_collider(
+    world: &mut World,
+    register_interface: &RegisterInterfaceContainer,
+    base_entity: EntityId,
+    collider: components::collider::Collider,
+) {
+    world.add_component(base_entity, collider);
+    world.add_component(base_entity, components::bumper::Bumper::new());
+    register_interface.get_mut().add_module(
+        "bumper",
+        common::MODULE_BUMPER,
+        components::bumper::BumperModule::new(base_entity),
+    );
+}
+
+/// Add ammunition to a weapon if the scenario limits it for this unit type.
+pub fn add_ammunition<F: Fn(&components::ammunition::AmmunitionConfig) -> Option<u32>>(
+    world: &mut World,
+    weapon_entity: EntityId,
+    capacity: F,
+) {
+    let capacity = scenario_config::<components::ammunition::AmmunitionConfig>(world)
+        .and_then(|config| capacity(&config));
+    if let Some(capacity) = capacity {
+        world.add_component(
+            weapon_entity,
+            components::ammunition::Ammunition::new(capacity),
+        );
+    }
 }
 
 pub fn add_common_body(
@@ -120,6 +184,14 @@ pub fn add_common_body(
         common::MODULE_GPS,
         components::gps::GpsModule::new(body_entity),
     );
+    world.add_component(body_entity, components::gps::Gps::new());
+
+    register_interface.get_mut().add_module(
+        "imu",
+        common::MODULE_IMU,
+        components::imu::ImuModule::new(body_entity),
+    );
+    world.add_component(body_entity, components::imu::Imu::new());
 }
 
 pub fn add_radio_receiver_transmitter(
@@ -198,6 +270,11 @@ pub fn add_radar(
     module_id: u32,
     radar_config: components::radar::RadarConfig,
 ) {
+    // The scenario may specify a sensor model for all radars.
+    let mut radar_config = radar_config;
+    if let Some(noise) = scenario_config::<components::radar::RadarNoise>(world) {
+        radar_config.noise = noise;
+    }
     register_interface.get_mut().add_module(
         name,
         module_id,
@@ -339,7 +416,7 @@ pub fn add_common_deploy(
 ) {
     world.add_component(base_entity, components::deploy::Deploy::new(config));
     register_interface.get_mut().add_module(
-        "deply",
+        "deploy",
         common::MODULE_DEPLOY,
         components::deploy::DeployModule::new(base_entity),
     );
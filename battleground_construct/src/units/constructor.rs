@@ -1,7 +1,10 @@
-use super::base_tricycle::{spawn_base_tricycle, BaseTricycle, BaseTricycleSpawnConfig};
+use super::base_tricycle::{
+    base_tricycle_config, spawn_base_tricycle, BaseTricycle, BaseTricycleSpawnConfig,
+};
 use super::common::{add_component_box, ComponentBox, ComponentBoxSpawnConfig};
 use super::{Unit, UnitId};
 use crate::components;
+use crate::config::setup::scenario_config;
 // use crate::display;
 use crate::display::primitives::Vec3;
 // use components::group::Group;
@@ -10,7 +13,12 @@ use components::pose::PreTransform;
 use engine::prelude::*;
 use serde::{Deserialize, Serialize};
 
-// use battleground_unit_control::units::constructor::*;
+use battleground_unit_control::units::constructor::*;
+
+/// Radius around the constructor in which allies are resupplied.
+const CONSTRUCTOR_RESUPPLY_RADIUS: f32 = 4.0;
+/// Duration between rounds handed out by the constructor.
+const CONSTRUCTOR_RESUPPLY_INTERVAL: f32 = 2.0;
 
 pub struct ConstructorSpawnConfig {
     pub x: f32,
@@ -68,6 +76,7 @@ pub fn spawn_constructor(world: &mut World, config: ConstructorSpawnConfig) -> E
         controller: config.controller,
         team_member: config.team_member,
         radio_config: config.radio_config,
+        tricycle_config: base_tricycle_config(),
     };
 
     // Payload size is 1.5 long, 1 wide.
@@ -124,6 +133,59 @@ pub fn spawn_constructor(world: &mut World, config: ConstructorSpawnConfig) -> E
         .unwrap()
         .set_desired_state(components::deploy::DeployState::Deployed);
 
+    // Resupplies the ammunition of nearby allies while deployed.
+    world.add_component(
+        base.base_entity,
+        components::resupply::Resupply::new(
+            CONSTRUCTOR_RESUPPLY_RADIUS,
+            CONSTRUCTOR_RESUPPLY_INTERVAL,
+            config.team_member.map(|t| t.team()),
+        ),
+    );
+
+    // Repairs nearby allies while deployed.
+    let repair_config =
+        scenario_config::<components::repair::RepairConfig>(world).unwrap_or_default();
+    let mut repair = components::repair::Repair::new(
+        repair_config.constructor_radius,
+        repair_config.constructor_rate,
+        repair_config.interval,
+        config.team_member.map(|t| t.team()),
+    )
+    .with_source(Some(base.unit_id));
+    repair.set_active(false);
+    world.add_component(base.base_entity, repair);
+
+    // Builds structures while deployed.
+    let costs =
+        scenario_config::<components::construction::ConstructionConfig>(world).unwrap_or_default();
+    world.add_component(
+        base.control_entity,
+        components::construction::Construction::new(costs, base.base_entity),
+    );
+    register_interface.get_mut().add_module(
+        "constructor",
+        MODULE_CONSTRUCTOR_BUILD,
+        components::construction::ConstructionModule::new(base.control_entity),
+    );
+    register_interface.get_mut().add_module(
+        "tower_radar",
+        MODULE_CONSTRUCTOR_TOWER_RADAR,
+        components::construction::TowerRadarModule::new(base.control_entity),
+    );
+
+    // Drops mines while deployed.
+    let mine_config = scenario_config::<components::mine::MineConfig>(world).unwrap_or_default();
+    world.add_component(
+        base.control_entity,
+        components::mine::MineLayer::new(mine_config, base.base_entity),
+    );
+    register_interface.get_mut().add_module(
+        "mine_layer",
+        MODULE_CONSTRUCTOR_MINE_LAYER,
+        components::mine::MineLayerModule::new(base.control_entity),
+    );
+
     super::common::add_group_team_unit(world, &unit_constructor, config.team_member);
     world.add_component(unit_constructor.unit_entity(), unit_constructor);
 
@@ -198,4 +260,15 @@ pub fn deploy_function(world: &mut World, control_entity: EntityId) {
     let mut deploy = deploy.unwrap();
 
     deploy.set_state(current_state);
+
+    if let Some(mut resupply) =
+        world.component_mut::<components::resupply::Resupply>(constructor.base.base_entity)
+    {
+        resupply.set_active(current_state == components::deploy::DeployState::Deployed);
+    }
+    if let Some(mut repair) =
+        world.component_mut::<components::repair::Repair>(constructor.base.base_entity)
+    {
+        repair.set_active(current_state == components::deploy::DeployState::Deployed);
+    }
 }
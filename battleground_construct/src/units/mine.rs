@@ -0,0 +1,59 @@
+use crate::components;
+use crate::display;
+use components::pose::Pose;
+use engine::prelude::*;
+
+const MINE_RADAR_REFLECTIVITY: f32 = 0.1;
+
+pub struct MineSpawnConfig {
+    pub x: f32,
+    pub y: f32,
+    pub config: components::mine::MineConfig,
+    pub team_member: Option<components::team_member::TeamMember>,
+    pub source: Option<components::unit::UnitId>,
+    /// Time at which the mine was dropped, it arms after the arm time of the config.
+    pub time: f32,
+}
+
+/// Spawn a mine on the terrain, returning the mine entity.
+pub fn spawn_mine(world: &mut World, config: MineSpawnConfig) -> EntityId {
+    let mine_entity = world.add_entity();
+    world.add_component(mine_entity, Pose::from_se2(config.x, config.y, 0.0));
+    super::terrain::place_on_terrain(world, mine_entity);
+
+    world.add_component(
+        mine_entity,
+        components::mine::Mine::new(
+            config.team_member.map(|t| t.team()),
+            config.config.trigger_radius,
+            config.time + config.config.arm_time,
+        ),
+    );
+    world.add_component(
+        mine_entity,
+        components::damage_splash::DamageSplash::new(
+            config.config.damage,
+            config.config.splash_radius,
+        ),
+    );
+    if let Some(source) = config.source {
+        world.add_component(
+            mine_entity,
+            components::unit_source::UnitSource::new(source),
+        );
+    }
+    if let Some(team_member) = config.team_member {
+        world.add_component(mine_entity, team_member);
+    }
+
+    // Other teams only see the mine on their radar when they are close.
+    world.add_component(
+        mine_entity,
+        components::radar_reflector::RadarReflector::new(MINE_RADAR_REFLECTIVITY)
+            .with_enemy_range(config.config.detection_range),
+    );
+    world.add_component(mine_entity, components::group::Group::from(&[mine_entity]));
+
+    world.add_component(mine_entity, display::mine::Mine::new());
+    mine_entity
+}
@@ -0,0 +1,325 @@
+use super::base_tricycle::{
+    base_tricycle_config, spawn_base_tricycle, BaseTricycle, BaseTricycleSpawnConfig,
+};
+use super::{Unit, UnitId};
+use crate::components;
+use crate::display;
+use crate::display::primitives::Vec3;
+use crate::util::cgmath::prelude::*;
+use components::parent::Parent;
+use components::pose::{Pose, PreTransform};
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use battleground_unit_control::units::missile_launcher::*;
+
+const MISSILE_LAUNCHER_RELOAD_TIME: f32 = 5.0;
+const MISSILE_LAUNCHER_DATALINK_SLOTS: usize = 2;
+const MISSILE_LAUNCHER_RAIL_LENGTH: f32 = 0.9;
+const MISSILE_SPEED: f32 = 8.0;
+const MISSILE_TURN_RATE: f32 = 1.5;
+const MISSILE_FUEL: f32 = 6.0;
+const MISSILE_SPLASH_DAMAGE: f32 = 0.35;
+const MISSILE_SPLASH_RADIUS: f32 = 2.5;
+
+pub struct MissileLauncherSpawnConfig {
+    pub x: f32,
+    pub y: f32,
+    pub yaw: f32,
+    pub controller: Box<dyn battleground_unit_control::UnitControl>,
+    pub team_member: Option<components::team_member::TeamMember>,
+    pub radio_config: Option<super::common::RadioConfig>,
+}
+
+impl Default for MissileLauncherSpawnConfig {
+    fn default() -> Self {
+        MissileLauncherSpawnConfig {
+            x: 0.0,
+            y: 0.0,
+            yaw: 0.0,
+            controller: Box::new(unit_control_builtin::idle::Idle {}),
+            team_member: None,
+            radio_config: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct UnitMissileLauncher {
+    pub base: BaseTricycle,
+    pub turret_entity: EntityId,
+    pub rail_entity: EntityId,
+    pub release_entity: EntityId,
+}
+impl Component for UnitMissileLauncher {}
+
+impl Unit for UnitMissileLauncher {
+    fn children(&self) -> Vec<EntityId> {
+        let mut r = self.base.children();
+        r.push(self.turret_entity);
+        r.push(self.rail_entity);
+        r.push(self.release_entity);
+        r
+    }
+    fn unit_entity(&self) -> EntityId {
+        self.base.unit_entity()
+    }
+    fn unit_id(&self) -> UnitId {
+        self.base.unit_id()
+    }
+}
+
+/// Spawn a missile launcher, returning the unit entity.
+pub fn spawn_missile_launcher(world: &mut World, config: MissileLauncherSpawnConfig) -> EntityId {
+    let base_config = BaseTricycleSpawnConfig {
+        x: config.x,
+        y: config.y,
+        yaw: config.yaw,
+        controller: config.controller,
+        team_member: config.team_member,
+        radio_config: config.radio_config,
+        tricycle_config: base_tricycle_config(),
+    };
+
+    let base = spawn_base_tricycle(
+        world,
+        base_config,
+        battleground_unit_control::units::UnitType::MissileLauncher,
+    );
+
+    let turret_entity = world.add_entity();
+    let rail_entity = world.add_entity();
+    let release_entity = world.add_entity();
+
+    let unit_missile_launcher = UnitMissileLauncher {
+        base,
+        turret_entity,
+        rail_entity,
+        release_entity,
+    };
+
+    add_missile_launcher_passive(world, &unit_missile_launcher);
+    add_missile_launcher_hit_zones(world, &unit_missile_launcher);
+
+    let register_interface = super::common::get_register_interface(world, base.control_entity);
+
+    // -----   Turret
+    world.add_component(turret_entity, Parent::new(base.payload_entity));
+    world.add_component(
+        turret_entity,
+        PreTransform::from_translation(Vec3::new(
+            0.0,
+            0.0,
+            MISSILE_LAUNCHER_DIM_PAYLOAD_TO_LAUNCHER_Z,
+        )),
+    );
+    let revolute_config = components::revolute::RevoluteConfig {
+        axis: Vec3::new(0.0, 0.0, 1.0),
+        velocity_bounds: (-1.0, 1.0),
+        acceleration_bounds: Some((-2.0, 2.0)),
+        ..Default::default()
+    };
+    super::common::add_revolute(
+        world,
+        &register_interface,
+        turret_entity,
+        "launcher",
+        MODULE_MISSILE_LAUNCHER_REVOLUTE_YAW,
+        revolute_config,
+    );
+
+    // -----   Rail
+    world.add_component(rail_entity, Parent::new(turret_entity));
+    world.add_component(
+        rail_entity,
+        PreTransform::from_translation(Vec3::new(
+            0.0,
+            0.0,
+            MISSILE_LAUNCHER_DIM_LAUNCHER_TO_RAIL_Z,
+        ))
+        .rotated_angle_y(cgmath::Rad(-MISSILE_LAUNCHER_PARAM_LAUNCH_PITCH)),
+    );
+
+    // -----   Release
+    world.add_component(release_entity, Parent::new(rail_entity));
+    world.add_component(
+        release_entity,
+        PreTransform::from_translation(Vec3::new(MISSILE_LAUNCHER_DIM_RAIL_TO_RELEASE_X, 0.0, 0.0)),
+    );
+    let cannon_config = components::cannon::CannonConfig {
+        reload_time: MISSILE_LAUNCHER_RELOAD_TIME,
+        fire_effect: std::rc::Rc::new(missile_fire_function),
+    };
+    world.add_component(
+        release_entity,
+        components::cannon::Cannon::new(cannon_config),
+    );
+    super::common::add_ammunition(world, release_entity, |c| c.missile_launcher);
+    register_interface.get_mut().add_module(
+        "cannon",
+        MODULE_MISSILE_LAUNCHER_CANNON,
+        components::cannon::CannonModule::new(release_entity),
+    );
+
+    world.add_component(
+        release_entity,
+        components::missile::MissileLauncher::new(
+            missile_config(),
+            MISSILE_LAUNCHER_DATALINK_SLOTS,
+        ),
+    );
+    register_interface.get_mut().add_module(
+        "datalink",
+        MODULE_MISSILE_LAUNCHER_DATALINK,
+        components::missile::MissileDatalinkModule::new(release_entity),
+    );
+
+    super::common::add_group_team_unit(world, &unit_missile_launcher, config.team_member);
+    world.add_component(unit_missile_launcher.unit_entity(), unit_missile_launcher);
+
+    base.unit_entity
+}
+
+pub fn add_missile_launcher_passive(world: &mut World, unit: &UnitMissileLauncher) {
+    super::base_tricycle::add_base_tricycle_passive(world, &unit.base);
+
+    // -----   Turret
+    let post = display::scout_mast::ScoutMast::new(MISSILE_LAUNCHER_DIM_LAUNCHER_TO_RAIL_Z);
+    let hit_collection =
+        components::hit_collection::HitCollection::from_hit_boxes(&post.hit_boxes());
+    world.add_component(unit.turret_entity, post);
+    world.add_component(unit.turret_entity, hit_collection);
+
+    // -----   Rail
+    let rail = display::missile_rail::MissileRail::new(MISSILE_LAUNCHER_RAIL_LENGTH);
+    let hit_collection =
+        components::hit_collection::HitCollection::from_hit_boxes(&rail.hit_boxes());
+    world.add_component(unit.rail_entity, rail);
+    world.add_component(unit.rail_entity, hit_collection);
+}
+
+fn add_missile_launcher_hit_zones(world: &mut World, unit: &UnitMissileLauncher) {
+    use components::hit_zone::{HitZone, Subsystem};
+    world.add_component(unit.base.center_entity, super::common::body_hit_zone());
+    for entity in [unit.turret_entity, unit.rail_entity] {
+        world.add_component(
+            entity,
+            HitZone::uniform(0.8).with_subsystem(Subsystem::Weapon),
+        );
+    }
+}
+
+pub fn missile_config() -> components::missile::MissileConfig {
+    components::missile::MissileConfig {
+        speed: MISSILE_SPEED,
+        turn_rate: MISSILE_TURN_RATE,
+        fuel: MISSILE_FUEL,
+    }
+}
+
+pub fn missile_fire_function(world: &mut World, release_entity: EntityId) {
+    use crate::components::missile::{Missile, MissileLauncher};
+    use crate::components::point_projectile::PointProjectile;
+    use crate::components::unit_source::UnitSource;
+    use crate::components::velocity::Velocity;
+
+    let config = if let Some(mut launcher) = world.component_mut::<MissileLauncher>(release_entity)
+    {
+        launcher.unlink(|e| world.component::<Missile>(e).is_some());
+        *launcher.config()
+    } else {
+        return;
+    };
+
+    let release_pose = components::pose::world_pose(world, release_entity);
+    let direction = (release_pose.transform() * cgmath::Vector4::<f32>::unit_x()).truncate();
+    let missile = Missile::new(config, direction);
+
+    let projectile_entity = world.add_entity();
+    world.add_component::<PointProjectile>(projectile_entity, PointProjectile::new());
+    let unit_id = world
+        .component::<components::unit_member::UnitMember>(release_entity)
+        .map(|v| v.unit());
+    if let Some(unit_member) = unit_id {
+        world.add_component(projectile_entity, UnitSource::new(unit_member));
+    }
+    world.add_component(
+        projectile_entity,
+        Pose::from_mat4(missile.pose(release_pose.to_translation())),
+    );
+    // The missile flight system updates this velocity from the missile on every step.
+    world.add_component(
+        projectile_entity,
+        Velocity::from_linear(Vec3::new(config.speed, 0.0, 0.0)),
+    );
+    world.add_component(projectile_entity, missile);
+    world.add_component(projectile_entity, display::missile::Missile::new());
+
+    world.add_component(
+        projectile_entity,
+        components::damage_splash::DamageSplash::new(MISSILE_SPLASH_DAMAGE, MISSILE_SPLASH_RADIUS),
+    );
+    world.add_component(
+        projectile_entity,
+        components::hit_effect::HitEffect::new(std::rc::Rc::new(missile_hit_effect)),
+    );
+
+    let effect_id = components::id_generator::generate_id(world);
+    world.add_component(
+        projectile_entity,
+        crate::display::particle_emitter::ParticleEmitter::bullet_trail(
+            effect_id,
+            0.08,
+            crate::display::Color::rgb(160, 160, 160),
+        ),
+    );
+
+    let time = world
+        .component_iter::<components::clock::Clock>()
+        .next()
+        .map(|(_, clock)| clock.elapsed_as_f32())
+        .unwrap_or(0.0);
+    if let Some(mut launcher) = world.component_mut::<MissileLauncher>(release_entity) {
+        launcher.link(projectile_entity, time);
+    }
+}
+
+fn missile_hit_effect(
+    world: &mut World,
+    projectile: EntityId,
+    _impact: &components::impact::Impact,
+) {
+    let projectile_destructor = world.add_entity();
+    let effect_id = components::id_generator::generate_id(world);
+    let world_pose = crate::components::pose::world_pose(world, projectile);
+    world.add_component(
+        projectile_destructor,
+        crate::display::particle_emitter::ParticleEmitter::explosion(
+            effect_id,
+            MISSILE_SPLASH_RADIUS,
+        ),
+    );
+    world.add_component(projectile_destructor, world_pose);
+    world.add_component(
+        projectile_destructor,
+        crate::components::expiry::Expiry::lifetime(10.0),
+    );
+    // The missile is no longer in flight, this also drops it from the datalink.
+    world.remove_component::<components::missile::Missile>(projectile);
+    world.remove_component::<display::missile::Missile>(projectile);
+
+    // Copy the trail to a new entity.
+    let emitter_entity = world.add_entity();
+    let emitter =
+        world.remove_component::<crate::display::particle_emitter::ParticleEmitter>(projectile);
+    // Disable the particle emitter.
+    if let Some(mut emitter) = emitter {
+        emitter.emitting = false;
+        world.add_component_boxed(emitter_entity, emitter);
+    }
+
+    world.add_component(
+        emitter_entity,
+        crate::components::expiry::Expiry::lifetime(5.0),
+    );
+}
@@ -4,7 +4,16 @@ pub mod base_tricycle;
 pub mod capturable_flag;
 pub mod common;
 pub mod constructor;
+pub mod mine;
+pub mod missile_launcher;
+pub mod obstacle;
+pub mod payload;
+pub mod resupply_zone;
+pub mod scout;
+pub mod structure;
 pub mod tank;
+pub mod team_flag;
+pub mod terrain;
 
 pub use crate::components::unit::UnitId;
 
@@ -0,0 +1,148 @@
+use crate::components;
+use crate::display;
+use components::hit_box::HitBox;
+use components::pose::Pose;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Shape of a static obstacle, the origin is at the center of its footprint on the floor.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum ObstacleShape {
+    /// Box with dimensions in x, y and z.
+    Box {
+        length: f32,
+        width: f32,
+        height: f32,
+    },
+    /// Upright cylinder.
+    Cylinder { radius: f32, height: f32 },
+}
+
+impl ObstacleShape {
+    pub fn height(&self) -> f32 {
+        match *self {
+            ObstacleShape::Box { height, .. } => height,
+            ObstacleShape::Cylinder { height, .. } => height,
+        }
+    }
+
+    /// Radius of the circle around the center that contains the footprint.
+    pub fn footprint_radius(&self) -> f32 {
+        match *self {
+            ObstacleShape::Box { length, width, .. } => (length / 2.0).hypot(width / 2.0),
+            ObstacleShape::Cylinder { radius, .. } => radius,
+        }
+    }
+
+    /// The display for this shape.
+    pub fn display(&self) -> display::obstacle::Obstacle {
+        match *self {
+            ObstacleShape::Box {
+                length,
+                width,
+                height,
+            } => display::obstacle::Obstacle::cuboid(length, width, height),
+            ObstacleShape::Cylinder { radius, height } => {
+                display::obstacle::Obstacle::cylinder(radius, height)
+            }
+        }
+    }
+
+    /// The hit collection for this shape, cylinders are approximated by boxes.
+    pub fn hit_collection(&self) -> components::hit_collection::HitCollection {
+        use crate::display::primitives::{Mat4, Vec3};
+        let center = Mat4::from_translation(Vec3::new(0.0, 0.0, self.height() / 2.0));
+        match *self {
+            ObstacleShape::Box {
+                length,
+                width,
+                height,
+            } => components::hit_collection::HitCollection::from_hit_boxes(&[(
+                center,
+                HitBox::new(length, width, height),
+            )]),
+            ObstacleShape::Cylinder { radius, height } => {
+                // Slice the cylinder into slabs along x, each spanning the chord at its center.
+                const SLABS: usize = 8;
+                let slab_length = radius * 2.0 / SLABS as f32;
+                let boxes = (0..SLABS)
+                    .map(|i| {
+                        let x = -radius + slab_length * (i as f32 + 0.5);
+                        let chord = 2.0 * (radius * radius - x * x).sqrt();
+                        (
+                            center * Mat4::from_translation(Vec3::new(x, 0.0, 0.0)),
+                            HitBox::new(slab_length, chord, height),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                components::hit_collection::HitCollection::from_hit_boxes(&boxes)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct ObstacleConfig {
+    pub x: f32,
+    pub y: f32,
+    pub yaw: f32,
+    pub shape: ObstacleShape,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct UnitObstacle {
+    pub obstacle_entity: EntityId,
+    pub shape: ObstacleShape,
+}
+impl Component for UnitObstacle {}
+
+pub fn spawn_obstacle(world: &mut World, config: ObstacleConfig) -> EntityId {
+    let obstacle_entity = world.add_entity();
+
+    world.add_component(
+        obstacle_entity,
+        Pose::from_se2(config.x, config.y, config.yaw),
+    );
+    let hit_collection = config.shape.hit_collection();
+    world.add_component(
+        obstacle_entity,
+        components::collider::Collider::from_hit_collection(&hit_collection),
+    );
+    world.add_component(obstacle_entity, hit_collection);
+
+    let unit_obstacle = UnitObstacle {
+        obstacle_entity,
+        shape: config.shape,
+    };
+    add_obstacle_passives(world, &unit_obstacle);
+    world.add_component(obstacle_entity, unit_obstacle);
+
+    obstacle_entity
+}
+
+pub fn add_obstacle_passives(world: &mut World, obstacle: &UnitObstacle) {
+    world.add_component(obstacle.obstacle_entity, obstacle.shape.display());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::display::primitives::{Mat4, Vec3};
+    use cgmath::SquareMatrix;
+
+    #[test]
+    fn test_cylinder_hit_collection() {
+        let shape = ObstacleShape::Cylinder {
+            radius: 1.0,
+            height: 2.0,
+        };
+        let collection = shape.hit_collection();
+        let origin = Mat4::identity();
+        assert!(collection.is_inside(origin, Vec3::new(0.0, 0.0, 1.0)));
+        assert!(collection.is_inside(origin, Vec3::new(0.7, 0.7, 1.9)));
+        assert!(!collection.is_inside(origin, Vec3::new(0.8, 0.8, 1.0)));
+        assert!(!collection.is_inside(origin, Vec3::new(0.0, 0.0, 2.1)));
+        assert!(!collection.is_inside(origin, Vec3::new(0.0, 0.0, -0.1)));
+    }
+}
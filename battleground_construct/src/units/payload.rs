@@ -0,0 +1,50 @@
+use super::obstacle::ObstacleShape;
+use crate::components;
+use crate::display;
+use components::pose::Pose;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const PAYLOAD_SHAPE: ObstacleShape = ObstacleShape::Box {
+    length: 1.6,
+    width: 1.0,
+    height: 0.8,
+};
+
+#[derive(Clone, Debug)]
+pub struct PayloadConfig {
+    /// Waypoints the payload follows, it starts at the first one.
+    pub path: Vec<(f32, f32)>,
+    pub speed: f32,
+    pub radius: f32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct UnitPayload {
+    pub payload_entity: EntityId,
+    pub radius: f32,
+}
+impl Component for UnitPayload {}
+
+pub fn spawn_payload(world: &mut World, config: PayloadConfig) -> EntityId {
+    let payload_entity = world.add_entity();
+    let payload = components::payload::Payload::new(&config.path, config.speed, config.radius);
+    let (x, y, yaw) = payload.pose();
+    world.add_component(payload_entity, Pose::from_se2(x, y, yaw));
+    world.add_component(payload_entity, payload);
+
+    let unit_payload = UnitPayload {
+        payload_entity,
+        radius: config.radius,
+    };
+    add_payload_passives(world, &unit_payload);
+    world.add_component(payload_entity, unit_payload);
+    payload_entity
+}
+
+pub fn add_payload_passives(world: &mut World, payload: &UnitPayload) {
+    world.add_component(payload.payload_entity, PAYLOAD_SHAPE.display());
+    let mut display = display::display_control_point::DisplayControlPoint::new();
+    display.set_radius(payload.radius);
+    world.add_component(payload.payload_entity, display);
+}
@@ -0,0 +1,47 @@
+use crate::components;
+use crate::display;
+use components::pose::Pose;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug)]
+pub struct ResupplyZoneConfig {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+    /// Duration between rounds handed out to each weapon in the zone.
+    pub interval: f32,
+    /// Only resupply weapons of this team, all teams if None.
+    pub team: Option<components::team::TeamId>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct UnitResupplyZone {
+    pub zone_entity: EntityId,
+    pub radius: f32,
+}
+impl Component for UnitResupplyZone {}
+
+pub fn spawn_resupply_zone(world: &mut World, config: ResupplyZoneConfig) -> EntityId {
+    let zone_entity = world.add_entity();
+    world.add_component(zone_entity, Pose::from_se2(config.x, config.y, 0.0));
+    world.add_component(
+        zone_entity,
+        components::resupply::Resupply::new(config.radius, config.interval, config.team),
+    );
+
+    let unit_zone = UnitResupplyZone {
+        zone_entity,
+        radius: config.radius,
+    };
+    add_resupply_zone_passives(world, &unit_zone);
+    world.add_component(zone_entity, unit_zone);
+    zone_entity
+}
+
+pub fn add_resupply_zone_passives(world: &mut World, zone: &UnitResupplyZone) {
+    let mut display = display::display_control_point::DisplayControlPoint::new();
+    display.set_radius(zone.radius);
+    display.set_color(display::Color::rgb(255, 200, 0));
+    world.add_component(zone.zone_entity, display);
+}
@@ -0,0 +1,184 @@
+use super::base_tricycle::{
+    base_tricycle_config, spawn_base_tricycle, BaseTricycle, BaseTricycleSpawnConfig,
+};
+use super::{Unit, UnitId};
+use crate::components;
+use crate::display;
+use crate::display::primitives::Vec3;
+use components::parent::Parent;
+use components::pose::PreTransform;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use battleground_unit_control::units::scout::*;
+
+const SCOUT_RADAR_RANGE: f32 = 60.0;
+const SCOUT_RADAR_DETECTION_ANGLE_YAW: f32 = 40.0;
+const SCOUT_WHEEL_VELOCITY_MAX: f32 = 2.0;
+const SCOUT_WHEEL_ACCELERATION_MAX: f32 = 1.0;
+// Lightly armored, the body takes more damage than that of the other units.
+const SCOUT_BODY_DAMAGE_MULTIPLIER: f32 = 1.5;
+
+pub struct ScoutSpawnConfig {
+    pub x: f32,
+    pub y: f32,
+    pub yaw: f32,
+    pub controller: Box<dyn battleground_unit_control::UnitControl>,
+    pub team_member: Option<components::team_member::TeamMember>,
+    pub radio_config: Option<super::common::RadioConfig>,
+}
+
+impl Default for ScoutSpawnConfig {
+    fn default() -> Self {
+        ScoutSpawnConfig {
+            x: 0.0,
+            y: 0.0,
+            yaw: 0.0,
+            controller: Box::new(unit_control_builtin::idle::Idle {}),
+            team_member: None,
+            radio_config: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct UnitScout {
+    pub base: BaseTricycle,
+    pub mast_entity: EntityId,
+    pub radar_entity: EntityId,
+}
+impl Component for UnitScout {}
+
+impl Unit for UnitScout {
+    fn children(&self) -> Vec<EntityId> {
+        let mut r = self.base.children();
+        r.push(self.mast_entity);
+        r.push(self.radar_entity);
+        r
+    }
+    fn unit_entity(&self) -> EntityId {
+        self.base.unit_entity()
+    }
+    fn unit_id(&self) -> UnitId {
+        self.base.unit_id()
+    }
+}
+
+/// Spawn a scout, returning the unit entity.
+pub fn spawn_scout(world: &mut World, config: ScoutSpawnConfig) -> EntityId {
+    let base_config = BaseTricycleSpawnConfig {
+        x: config.x,
+        y: config.y,
+        yaw: config.yaw,
+        controller: config.controller,
+        team_member: config.team_member,
+        radio_config: config.radio_config,
+        tricycle_config: components::tricycle_base::TricycleConfig {
+            wheel_velocity_bounds: (-SCOUT_WHEEL_VELOCITY_MAX, SCOUT_WHEEL_VELOCITY_MAX),
+            wheel_acceleration_bounds: Some((
+                -SCOUT_WHEEL_ACCELERATION_MAX,
+                SCOUT_WHEEL_ACCELERATION_MAX,
+            )),
+            ..base_tricycle_config()
+        },
+    };
+
+    let base = spawn_base_tricycle(
+        world,
+        base_config,
+        battleground_unit_control::units::UnitType::Scout,
+    );
+
+    let mast_entity = world.add_entity();
+    let radar_entity = world.add_entity();
+
+    let unit_scout = UnitScout {
+        base,
+        mast_entity,
+        radar_entity,
+    };
+
+    add_scout_passive(world, &unit_scout);
+    add_scout_hit_zones(world, &unit_scout);
+
+    let register_interface = super::common::get_register_interface(world, base.control_entity);
+
+    // -----   Mast
+    world.add_component(mast_entity, Parent::new(base.payload_entity));
+    world.add_component(
+        mast_entity,
+        PreTransform::from_translation(Vec3::new(0.0, 0.0, 0.0)),
+    );
+
+    // -----   Radar
+    world.add_component(radar_entity, Parent::new(mast_entity));
+    world.add_component(
+        radar_entity,
+        PreTransform::from_translation(Vec3::new(0.0, 0.0, SCOUT_DIM_PAYLOAD_TO_RADAR_Z)),
+    );
+
+    let revolute_config = components::revolute::RevoluteConfig {
+        axis: Vec3::new(0.0, 0.0, 1.0),
+        velocity_bounds: (-std::f32::consts::PI * 2.0, std::f32::consts::PI * 2.0),
+        velocity_cmd: -std::f32::consts::PI,
+        acceleration_bounds: Some((-std::f32::consts::PI, std::f32::consts::PI)),
+        ..Default::default()
+    };
+    super::common::add_revolute(
+        world,
+        &register_interface,
+        radar_entity,
+        "radar_rotation",
+        MODULE_SCOUT_REVOLUTE_RADAR,
+        revolute_config,
+    );
+    let radar_config = components::radar::RadarConfig {
+        range_max: SCOUT_RADAR_RANGE,
+        detection_angle_yaw: SCOUT_RADAR_DETECTION_ANGLE_YAW.to_radians(),
+        detection_angle_pitch: 180f32.to_radians(),
+        signal_strength: 1.0,
+        ..Default::default()
+    };
+    super::common::add_radar(
+        world,
+        &register_interface,
+        radar_entity,
+        "radar",
+        MODULE_SCOUT_RADAR,
+        radar_config,
+    );
+
+    super::common::add_group_team_unit(world, &unit_scout, config.team_member);
+    world.add_component(unit_scout.unit_entity(), unit_scout);
+
+    base.unit_entity
+}
+
+pub fn add_scout_passive(world: &mut World, unit: &UnitScout) {
+    super::base_tricycle::add_base_tricycle_passive(world, &unit.base);
+
+    // -----   Mast
+    let mast = display::scout_mast::ScoutMast::new(SCOUT_DIM_PAYLOAD_TO_RADAR_Z);
+    let hit_collection =
+        components::hit_collection::HitCollection::from_hit_boxes(&mast.hit_boxes());
+    world.add_component(unit.mast_entity, mast);
+    world.add_component(unit.mast_entity, hit_collection);
+
+    // -----   Radar
+    let mut radar_model = display::radar_model::RadarModel::new();
+    radar_model.length *= 2.0;
+    radar_model.height *= 1.5;
+    world.add_component(unit.radar_entity, radar_model);
+}
+
+fn add_scout_hit_zones(world: &mut World, unit: &UnitScout) {
+    use components::hit_zone::{HitZone, Subsystem};
+    world.add_component(
+        unit.base.center_entity,
+        HitZone::uniform(SCOUT_BODY_DAMAGE_MULTIPLIER),
+    );
+    world.add_component(
+        unit.mast_entity,
+        HitZone::uniform(1.0).with_subsystem(Subsystem::Radar),
+    );
+}
@@ -0,0 +1,133 @@
+use super::obstacle::ObstacleShape;
+use crate::components;
+use crate::config::setup::scenario_config;
+use crate::display;
+use crate::display::primitives::Vec3;
+use components::construction::StructureType;
+use components::parent::Parent;
+use components::pose::{Pose, PreTransform};
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const RADAR_TOWER_HEIGHT: f32 = 3.0;
+const RADAR_TOWER_RANGE: f32 = 60.0;
+
+#[derive(Copy, Clone, Debug)]
+pub struct StructureConfig {
+    pub x: f32,
+    pub y: f32,
+    pub yaw: f32,
+    pub structure: StructureType,
+    pub team_member: Option<components::team_member::TeamMember>,
+}
+
+/// A static structure built by a constructor.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct UnitStructure {
+    pub structure_entity: EntityId,
+    /// The entity holding the radar for the radar tower, the structure entity otherwise.
+    pub radar_entity: EntityId,
+    pub structure: StructureType,
+}
+impl Component for UnitStructure {}
+
+/// The shape of the structure, the origin is at the center of its footprint on the floor.
+pub fn structure_shape(structure: StructureType) -> ObstacleShape {
+    match structure {
+        StructureType::Wall => ObstacleShape::Box {
+            length: 0.4,
+            width: 3.0,
+            height: 1.2,
+        },
+        StructureType::RadarTower => ObstacleShape::Cylinder {
+            radius: 0.3,
+            height: RADAR_TOWER_HEIGHT,
+        },
+        StructureType::RepairStation => ObstacleShape::Box {
+            length: 1.5,
+            width: 1.5,
+            height: 0.8,
+        },
+    }
+}
+
+pub fn spawn_structure(world: &mut World, config: StructureConfig) -> EntityId {
+    let structure_entity = world.add_entity();
+    world.add_component(
+        structure_entity,
+        Pose::from_se2(config.x, config.y, config.yaw),
+    );
+    let hit_collection = structure_shape(config.structure).hit_collection();
+    world.add_component(
+        structure_entity,
+        components::collider::Collider::from_hit_collection(&hit_collection),
+    );
+    world.add_component(structure_entity, hit_collection);
+    if let Some(team_member) = config.team_member {
+        world.add_component(structure_entity, team_member);
+    }
+
+    if config.structure == StructureType::RepairStation {
+        let repair_config =
+            scenario_config::<components::repair::RepairConfig>(world).unwrap_or_default();
+        world.add_component(
+            structure_entity,
+            components::repair::Repair::new(
+                repair_config.station_radius,
+                repair_config.station_rate,
+                repair_config.interval,
+                config.team_member.map(|t| t.team()),
+            ),
+        );
+    }
+
+    let radar_entity = if config.structure == StructureType::RadarTower {
+        let radar_entity = world.add_entity();
+        world.add_component(radar_entity, Parent::new(structure_entity));
+        world.add_component(
+            radar_entity,
+            PreTransform::from_translation(Vec3::new(0.0, 0.0, RADAR_TOWER_HEIGHT)),
+        );
+        let radar_config = components::radar::RadarConfig {
+            range_max: RADAR_TOWER_RANGE,
+            detection_angle_yaw: std::f32::consts::PI * 2.0,
+            detection_angle_pitch: std::f32::consts::PI,
+            signal_strength: 1.0,
+            ..Default::default()
+        };
+        let mut radar = components::radar::Radar::new_with_config(radar_config);
+        if let Some(noise) = scenario_config::<components::radar::RadarNoise>(world) {
+            radar.set_noise(noise);
+        }
+        world.add_component(radar_entity, radar);
+        if let Some(team_member) = config.team_member {
+            world.add_component(radar_entity, team_member);
+        }
+        radar_entity
+    } else {
+        structure_entity
+    };
+
+    let unit_structure = UnitStructure {
+        structure_entity,
+        radar_entity,
+        structure: config.structure,
+    };
+    add_structure_passives(world, &unit_structure);
+    world.add_component(structure_entity, unit_structure);
+
+    structure_entity
+}
+
+pub fn add_structure_passives(world: &mut World, structure: &UnitStructure) {
+    world.add_component(
+        structure.structure_entity,
+        structure_shape(structure.structure).display(),
+    );
+    if structure.radar_entity != structure.structure_entity {
+        world.add_component(
+            structure.radar_entity,
+            display::radar_model::RadarModel::new(),
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use super::{Unit, UnitId};
 use crate::components;
 use crate::display;
-use crate::display::primitives::Vec3;
+use crate::display::primitives::{Mat4, Vec3};
 use components::parent::Parent;
 use components::pose::{Pose, PreTransform};
 use engine::prelude::*;
@@ -147,6 +147,7 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
     world.add_component(unit_entity, unit_tank);
 
     add_tank_passive(world, &unit_tank);
+    add_tank_hit_zones(world, &unit_tank);
 
     // -----   Base
     world.add_component(base_entity, Pose::from_se2(config.x, config.y, config.yaw));
@@ -162,6 +163,15 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
         diff_drive_config,
         MODULE_TANK_DIFF_DRIVE,
     );
+    super::common::add_common_collider(
+        world,
+        &register_interface,
+        base_entity,
+        components::collider::Collider::from_hit_box(
+            Mat4::from_translation(Vec3::new(0.0, 0.0, TANK_DIM_FLOOR_TO_BODY_Z)),
+            components::hit_box::HitBox::new(1.4, 1.4, 0.5),
+        ),
+    );
 
     // -----   Body
     world.add_component(body_entity, Parent::new(base_entity));
@@ -236,6 +246,7 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
         muzzle_entity,
         components::cannon::Cannon::new(cannon_config),
     );
+    super::common::add_ammunition(world, muzzle_entity, |c| c.tank);
     world.add_component(
         muzzle_entity,
         PreTransform::from_translation(Vec3::new(TANK_DIM_BARREL_TO_MUZZLE_X, 0.0, 0.0)),
@@ -279,6 +290,7 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
         // detection_angle_yaw: 45.0f32.to_radians(),
         // detection_angle_pitch: 180f32.to_radians(),
         signal_strength: 1.0,
+        ..Default::default()
     };
     super::common::add_radar(
         world,
@@ -382,6 +394,23 @@ pub fn add_tank_passive(world: &mut World, unit: &UnitTank) {
     world.add_component(unit.health_bar_entity, Parent::new(unit.base_entity));
 }
 
+fn add_tank_hit_zones(world: &mut World, unit: &UnitTank) {
+    use components::hit_zone::{HitZone, Subsystem};
+    world.add_component(unit.body_entity, super::common::body_hit_zone());
+    world.add_component(
+        unit.turret_entity,
+        HitZone::uniform(1.0).with_subsystem(Subsystem::Radar),
+    );
+    world.add_component(
+        unit.barrel_entity,
+        HitZone::uniform(0.8).with_subsystem(Subsystem::Weapon),
+    );
+    world.add_component(
+        unit.base_entity,
+        HitZone::uniform(0.5).with_subsystem(Subsystem::Tracks),
+    );
+}
+
 pub fn cannon_function(world: &mut World, cannon_entity: EntityId) {
     use crate::components::point_projectile::PointProjectile;
     use crate::components::unit_source::UnitSource;
@@ -472,6 +501,9 @@ pub fn cannon_function(world: &mut World, cannon_entity: EntityId) {
         crate::components::expiry::Expiry::lifetime(15.0),
     );
     world.add_component(emitter_entity, muzzle_pose_raw);
+    if let Some(unit_member) = unit_id {
+        world.add_component(emitter_entity, UnitSource::new(unit_member));
+    }
 }
 
 fn cannon_hit_effect(
@@ -0,0 +1,57 @@
+use crate::components;
+use crate::display;
+use components::pose::Pose;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const FLAG_POLE_HEIGHT: f32 = 1.5;
+
+#[derive(Copy, Clone, Debug)]
+pub struct TeamFlagConfig {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+    pub team: components::team::TeamId,
+}
+
+/// The flag of a team for capture the flag, the flag entity moves with its carrier while the base
+/// entity stays put.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct UnitTeamFlag {
+    pub flag_entity: EntityId,
+    pub base_entity: EntityId,
+    pub radius: f32,
+}
+impl Component for UnitTeamFlag {}
+
+pub fn spawn_team_flag(world: &mut World, config: TeamFlagConfig) -> EntityId {
+    let base_entity = world.add_entity();
+    world.add_component(base_entity, Pose::from_se2(config.x, config.y, 0.0));
+
+    let flag_entity = world.add_entity();
+    world.add_component(flag_entity, Pose::from_se2(config.x, config.y, 0.0));
+    world.add_component(
+        flag_entity,
+        components::team_flag::TeamFlag::new(config.team, config.x, config.y, config.radius),
+    );
+
+    let unit_flag = UnitTeamFlag {
+        flag_entity,
+        base_entity,
+        radius: config.radius,
+    };
+    add_team_flag_passives(world, &unit_flag);
+    world.add_component(flag_entity, unit_flag);
+    flag_entity
+}
+
+pub fn add_team_flag_passives(world: &mut World, unit_flag: &UnitTeamFlag) {
+    let mut flag = display::flag::Flag::new();
+    flag.set_pole_height(FLAG_POLE_HEIGHT);
+    flag.set_flag_position(1.0);
+    world.add_component(unit_flag.flag_entity, flag);
+
+    let mut display = display::display_control_point::DisplayControlPoint::new();
+    display.set_radius(unit_flag.radius);
+    world.add_component(unit_flag.base_entity, display);
+}
@@ -0,0 +1,59 @@
+use crate::components;
+use crate::display;
+use crate::util::heightmap::Heightmap;
+use components::pose::Pose;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UnitTerrain {
+    pub terrain_entity: EntityId,
+    pub heightmap: Heightmap,
+}
+impl Component for UnitTerrain {}
+
+pub fn spawn_terrain(world: &mut World, heightmap: Heightmap) -> EntityId {
+    let terrain_entity = world.add_entity();
+    world.add_component(terrain_entity, Pose::new());
+    world.add_component(
+        terrain_entity,
+        components::terrain::Terrain::new(heightmap.clone()),
+    );
+
+    let unit_terrain = UnitTerrain {
+        terrain_entity,
+        heightmap,
+    };
+    add_terrain_passives(world, &unit_terrain);
+    world.add_component(terrain_entity, unit_terrain);
+
+    terrain_entity
+}
+
+pub fn add_terrain_passives(world: &mut World, terrain: &UnitTerrain) {
+    world.add_component(
+        terrain.terrain_entity,
+        display::terrain::TerrainMesh::new(terrain.heightmap.clone()),
+    );
+}
+
+/// Height of the terrain at the provided position, zero if there is no terrain.
+pub fn terrain_height(world: &World, x: f32, y: f32) -> f32 {
+    world
+        .component_iter::<components::terrain::Terrain>()
+        .next()
+        .map(|(_, t)| t.heightmap().height_at(x, y))
+        .unwrap_or(0.0)
+}
+
+/// Move the entity vertically such that it sits on the terrain.
+pub fn place_on_terrain(world: &mut World, entity: EntityId) {
+    let height = if let Some(pose) = world.component::<Pose>(entity) {
+        terrain_height(world, pose.x(), pose.y())
+    } else {
+        return;
+    };
+    if let Some(mut pose) = world.component_mut::<Pose>(entity) {
+        pose.transform_mut().w.z = height;
+    }
+}
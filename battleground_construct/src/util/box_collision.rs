@@ -1,4 +1,4 @@
-use cgmath::{BaseNum, Vector3};
+use cgmath::{BaseFloat, BaseNum, Matrix4, Vector3};
 use serde::{Deserialize, Serialize};
 
 /// Generic AxisAlignedBox of given dimensions. AxisAlignedBox is centered around the origin.
@@ -201,6 +201,132 @@ impl<S: BaseNum + std::fmt::Display> AxisAlignedBox<S> {
     }
 }
 
+impl<S: BaseFloat + std::fmt::Display> AxisAlignedBox<S> {
+    /// Check if this box, placed at `transform`, overlaps with the other box placed at
+    /// `other_transform`. Both transforms may hold arbitrary rotations.
+    pub fn is_intersecting_box(
+        &self,
+        transform: Matrix4<S>,
+        other: &AxisAlignedBox<S>,
+        other_transform: Matrix4<S>,
+    ) -> bool {
+        self.penetration(transform, other, other_transform)
+            .is_some()
+    }
+
+    ///
+    /// Determine the penetration between this box at `transform` and the other box at
+    /// `other_transform`, returns None if they don't overlap. Otherwise this returns the smallest
+    /// translation that moves this box out of the other box.
+    ///
+    pub fn penetration(
+        &self,
+        transform: Matrix4<S>,
+        other: &AxisAlignedBox<S>,
+        other_transform: Matrix4<S>,
+    ) -> Option<Vector3<S>> {
+        self.penetration_worker(transform, other, other_transform, |axis, overlap| {
+            Some(axis * overlap)
+        })
+    }
+
+    ///
+    /// Same as [`Self::penetration`], but the returned translation is perpendicular to `normal`,
+    /// for objects that can only move in a plane. Separating axes that are not in the plane are
+    /// projected onto it, which requires a longer translation to resolve the same overlap.
+    ///
+    pub fn penetration_planar(
+        &self,
+        transform: Matrix4<S>,
+        other: &AxisAlignedBox<S>,
+        other_transform: Matrix4<S>,
+        normal: Vector3<S>,
+    ) -> Option<Vector3<S>> {
+        use cgmath::InnerSpace;
+        let normal = normal.normalize();
+        let epsilon: S = cgmath::num_traits::cast(1e-4).unwrap();
+        self.penetration_worker(transform, other, other_transform, |axis, overlap| {
+            let in_plane = axis - normal * axis.dot(normal);
+            let length2 = in_plane.magnitude2();
+            if length2 < epsilon {
+                // Perpendicular to the plane, can't be resolved by moving in the plane.
+                return None;
+            }
+            Some(in_plane * (overlap / length2))
+        })
+    }
+
+    // Separating axis theorem; the boxes overlap if their projections overlap on all of the face
+    // normals of both boxes and the cross products between their edges. The resolve function
+    // turns an axis pointing away from the other box and the overlap into a translation.
+    fn penetration_worker<F: Fn(Vector3<S>, S) -> Option<Vector3<S>>>(
+        &self,
+        transform: Matrix4<S>,
+        other: &AxisAlignedBox<S>,
+        other_transform: Matrix4<S>,
+        resolve: F,
+    ) -> Option<Vector3<S>> {
+        use cgmath::InnerSpace;
+        let zero = S::zero();
+        let two = S::one() + S::one();
+        let epsilon: S = cgmath::num_traits::cast(1e-6).unwrap();
+
+        let axes = |m: &Matrix4<S>| {
+            [
+                m.x.truncate().normalize(),
+                m.y.truncate().normalize(),
+                m.z.truncate().normalize(),
+            ]
+        };
+        let a_axes = axes(&transform);
+        let b_axes = axes(&other_transform);
+        let a_half = [self.x / two, self.y / two, self.z / two];
+        let b_half = [other.x / two, other.y / two, other.z / two];
+        let radius = |box_axes: &[Vector3<S>; 3], half: &[S; 3], axis: Vector3<S>| {
+            box_axes
+                .iter()
+                .zip(half.iter())
+                .fold(zero, |r, (a, h)| r + a.dot(axis).abs() * *h)
+        };
+
+        let mut candidates: Vec<Vector3<S>> = vec![];
+        candidates.extend_from_slice(&a_axes);
+        candidates.extend_from_slice(&b_axes);
+        for a in a_axes.iter() {
+            for b in b_axes.iter() {
+                let c = a.cross(*b);
+                // Parallel edges don't provide a new axis.
+                if c.magnitude2() > epsilon {
+                    candidates.push(c.normalize());
+                }
+            }
+        }
+
+        let offset = other_transform.w.truncate() - transform.w.truncate();
+        let mut best: Option<Vector3<S>> = None;
+        for axis in candidates {
+            let distance = offset.dot(axis);
+            let overlap =
+                radius(&a_axes, &a_half, axis) + radius(&b_axes, &b_half, axis) - distance.abs();
+            if overlap <= zero {
+                // Found a separating axis, no intersection.
+                return None;
+            }
+            // Push this box away from the other box.
+            let direction = if distance > zero { -axis } else { axis };
+            if let Some(translation) = resolve(direction, overlap) {
+                if best
+                    .map(|b| translation.magnitude2() < b.magnitude2())
+                    .unwrap_or(true)
+                {
+                    best = Some(translation);
+                }
+            }
+        }
+        best
+    }
+}
+
 #[cfg(test)]
 mod test {
     fn verify_points<S: BaseNum + std::fmt::Display>(
@@ -329,4 +455,54 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_penetration() {
+        use cgmath::vec3;
+        use cgmath::InnerSpace;
+        use cgmath::SquareMatrix;
+        let b = AxisAlignedBox::new(1.0f32, 1.0, 1.0);
+        let origin = Matrix4::<f32>::identity();
+
+        // Overlapping by 0.2 along x, should push back along -x.
+        let other = Matrix4::from_translation(vec3(0.8, 0.0, 0.0));
+        assert!(b.is_intersecting_box(origin, &b, other));
+        let p = b.penetration(origin, &b, other).unwrap();
+        assert!((p - vec3(-0.2, 0.0, 0.0)).magnitude() < 1e-5);
+
+        // Rotated by 45 degrees, the corner reaches to 0.5 + sqrt(0.5).
+        let rotated = |x: f32| {
+            Matrix4::from_translation(vec3(x, 0.0, 0.0))
+                * Matrix4::from_angle_z(cgmath::Deg(45.0f32))
+        };
+        assert!(b.is_intersecting_box(origin, &b, rotated(1.2)));
+        assert!(!b.is_intersecting_box(origin, &b, rotated(1.25)));
+        // Corner to corner, the cross of the z axes is no axis.
+        let diagonal = Matrix4::from_translation(vec3(0.9, 0.9, 0.0));
+        assert!(b.is_intersecting_box(origin, &b, diagonal));
+        assert!(!b.is_intersecting_box(
+            origin,
+            &b,
+            diagonal * Matrix4::from_angle_z(cgmath::Deg(45.0f32))
+        ));
+
+        // Barely overlapping in z, the planar penetration must be horizontal.
+        let stacked = Matrix4::from_translation(vec3(0.5, 0.0, 0.9));
+        let p = b.penetration(origin, &b, stacked).unwrap();
+        assert!((p - vec3(0.0, 0.0, -0.1)).magnitude() < 1e-5);
+        let p = b
+            .penetration_planar(origin, &b, stacked, vec3(0.0, 0.0, 1.0))
+            .unwrap();
+        assert!((p - vec3(-0.5, 0.0, 0.0)).magnitude() < 1e-5);
+
+        // Tilted boxes have no axes in the plane, but can still be resolved.
+        let tilted = Matrix4::from_translation(vec3(0.9, 0.0, 0.0))
+            * Matrix4::from_angle_y(cgmath::Deg(20.0f32));
+        let p = b
+            .penetration_planar(origin, &b, tilted, vec3(0.0, 0.0, 1.0))
+            .unwrap();
+        assert!(p.z.abs() < 1e-5 && p.x < 0.0);
+        let moved = Matrix4::from_translation(p * 1.001);
+        assert!(!b.is_intersecting_box(moved, &b, tilted));
+    }
 }
@@ -0,0 +1,208 @@
+use cgmath::Vector3;
+use serde::{Deserialize, Serialize};
+
+/// A regular grid of height samples, heights between the samples are interpolated bilinearly.
+/// Outside of the grid the height of the nearest edge is used.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Heightmap {
+    /// Position of the first sample.
+    x_min: f32,
+    y_min: f32,
+    /// Distance between two samples.
+    cell_size: f32,
+    /// Number of samples in x and y.
+    size_x: usize,
+    size_y: usize,
+    /// Heights, x changes fastest.
+    heights: Vec<f32>,
+}
+
+impl Heightmap {
+    pub fn new(
+        x_min: f32,
+        y_min: f32,
+        cell_size: f32,
+        size_x: usize,
+        size_y: usize,
+        heights: Vec<f32>,
+    ) -> Self {
+        assert_eq!(heights.len(), size_x * size_y);
+        assert!(size_x >= 2 && size_y >= 2);
+        Heightmap {
+            x_min,
+            y_min,
+            cell_size,
+            size_x,
+            size_y,
+            heights,
+        }
+    }
+
+    /// Create a heightmap centered at x, y from rows as seen from above; the first row is at the
+    /// highest y coordinate, each row goes along positive x.
+    pub fn from_rows(
+        x: f32,
+        y: f32,
+        cell_size: f32,
+        rows: &[Vec<f32>],
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let size_y = rows.len();
+        let size_x = rows.first().map(|r| r.len()).unwrap_or(0);
+        if size_x < 2 || size_y < 2 {
+            return Err("heightmap needs at least two rows and two columns".into());
+        }
+        if rows.iter().any(|r| r.len() != size_x) {
+            return Err("heightmap rows must all have the same length".into());
+        }
+        if cell_size <= 0.0 {
+            return Err("heightmap cell size must be positive".into());
+        }
+        let heights = rows.iter().rev().flatten().copied().collect::<Vec<f32>>();
+        let x_min = x - (size_x - 1) as f32 * cell_size / 2.0;
+        let y_min = y - (size_y - 1) as f32 * cell_size / 2.0;
+        Ok(Heightmap::new(
+            x_min, y_min, cell_size, size_x, size_y, heights,
+        ))
+    }
+
+    /// Create a heightmap centered at x, y from a png image as seen from above. Black is at zero
+    /// height, white at `height_scale`.
+    pub fn from_png(
+        x: f32,
+        y: f32,
+        cell_size: f32,
+        height_scale: f32,
+        data: &[u8],
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let (width, height, values) = super::png::decode_grayscale(data)?;
+        let rows = values
+            .chunks(width)
+            .take(height)
+            .map(|r| r.iter().map(|v| v * height_scale).collect::<Vec<f32>>())
+            .collect::<Vec<_>>();
+        Self::from_rows(x, y, cell_size, &rows)
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Number of samples in x and y.
+    pub fn size(&self) -> (usize, usize) {
+        (self.size_x, self.size_y)
+    }
+
+    /// Position of the sample at the provided indices.
+    pub fn sample_position(&self, ix: usize, iy: usize) -> Vector3<f32> {
+        Vector3::new(
+            self.x_min + ix as f32 * self.cell_size,
+            self.y_min + iy as f32 * self.cell_size,
+            self.sample(ix, iy),
+        )
+    }
+
+    fn sample(&self, ix: usize, iy: usize) -> f32 {
+        let ix = ix.min(self.size_x - 1);
+        let iy = iy.min(self.size_y - 1);
+        self.heights[iy * self.size_x + ix]
+    }
+
+    /// Height of the surface at the provided position.
+    pub fn height_at(&self, x: f32, y: f32) -> f32 {
+        let fx = ((x - self.x_min) / self.cell_size).clamp(0.0, (self.size_x - 1) as f32);
+        let fy = ((y - self.y_min) / self.cell_size).clamp(0.0, (self.size_y - 1) as f32);
+        let ix = (fx.floor() as usize).min(self.size_x - 2);
+        let iy = (fy.floor() as usize).min(self.size_y - 2);
+        let tx = fx - ix as f32;
+        let ty = fy - iy as f32;
+        let h00 = self.sample(ix, iy);
+        let h10 = self.sample(ix + 1, iy);
+        let h01 = self.sample(ix, iy + 1);
+        let h11 = self.sample(ix + 1, iy + 1);
+        let h0 = h00 + (h10 - h00) * tx;
+        let h1 = h01 + (h11 - h01) * tx;
+        h0 + (h1 - h0) * ty
+    }
+
+    /// Normal of the surface at the provided position, determined over the provided distance.
+    pub fn normal_at(&self, x: f32, y: f32, distance: f32) -> Vector3<f32> {
+        use cgmath::InnerSpace;
+        let dx = self.height_at(x + distance, y) - self.height_at(x - distance, y);
+        let dy = self.height_at(x, y + distance) - self.height_at(x, y - distance);
+        Vector3::new(-dx, -dy, 2.0 * distance).normalize()
+    }
+
+    /// Whether a point is below the surface.
+    pub fn is_below(&self, point: Vector3<f32>) -> bool {
+        point.z < self.height_at(point.x, point.y)
+    }
+
+    /// Check if the line segment between two points goes through the terrain, the endpoints
+    /// themselves are not considered.
+    pub fn is_intersecting(&self, p0: Vector3<f32>, p1: Vector3<f32>) -> bool {
+        use cgmath::InnerSpace;
+        let direction = p1 - p0;
+        // Sample at a quarter of the cell size, sufficient since the surface is bilinear.
+        let steps = ((direction.magnitude() / (self.cell_size / 4.0)).ceil() as usize).max(1);
+        (1..steps).any(|i| self.is_below(p0 + direction * (i as f32 / steps as f32)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hill() -> Heightmap {
+        Heightmap::from_rows(
+            0.0,
+            0.0,
+            1.0,
+            &[
+                vec![0.0, 0.0, 0.0],
+                vec![0.0, 2.0, 0.0],
+                vec![0.0, 0.0, 1.0],
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_height_at() {
+        let h = hill();
+        assert_eq!(h.size(), (3, 3));
+        assert_eq!(h.height_at(0.0, 0.0), 2.0);
+        assert_eq!(h.height_at(0.5, 0.0), 1.0);
+        // Last row is at the lowest y.
+        assert_eq!(h.height_at(1.0, -1.0), 1.0);
+        assert_eq!(h.height_at(1.0, 1.0), 0.0);
+        // Outside uses the edge.
+        assert_eq!(h.height_at(5.0, -5.0), 1.0);
+
+        let n = h.normal_at(-0.5, 0.0, 0.1);
+        assert!(n.x < 0.0 && n.z > 0.0 && n.y.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_is_intersecting() {
+        let h = hill();
+        use cgmath::vec3;
+        assert!(h.is_below(vec3(0.0, 0.0, 1.9)));
+        assert!(!h.is_below(vec3(0.0, 0.0, 2.1)));
+        // Through the hill.
+        assert!(h.is_intersecting(vec3(-1.0, 0.0, 0.5), vec3(1.0, 0.0, 0.5)));
+        // Over the hill.
+        assert!(!h.is_intersecting(vec3(-1.0, 0.0, 2.5), vec3(1.0, 0.0, 2.5)));
+        // Next to the hill.
+        assert!(!h.is_intersecting(vec3(-1.0, 1.0, 0.5), vec3(1.0, 1.0, 0.5)));
+    }
+
+    #[test]
+    fn test_from_png() {
+        let data = crate::util::png::test::encode_grayscale(2, 2, &[0, 255, 255, 0], 1);
+        let h = Heightmap::from_png(0.0, 0.0, 2.0, 3.0, &data).unwrap();
+        assert_eq!(h.height_at(-1.0, 1.0), 0.0);
+        assert_eq!(h.height_at(1.0, 1.0), 3.0);
+        assert_eq!(h.height_at(-1.0, -1.0), 3.0);
+        assert_eq!(h.height_at(0.0, 0.0), 1.5);
+    }
+}
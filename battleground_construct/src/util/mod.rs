@@ -1,4 +1,6 @@
 pub mod box_collision;
+pub mod heightmap;
+pub mod png;
 pub use cgmath_util as cgmath;
 
 #[cfg(test)]
@@ -0,0 +1,193 @@
+//! Minimal png decoder, just enough to read heightmaps.
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let pa = (p - a as i16).abs();
+    let pb = (p - b as i16).abs();
+    let pc = (p - c as i16).abs();
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// Decode a png into a single channel, values are normalized to [0, 1]. For images with multiple
+/// channels the first channel is used. Only non-interlaced images with a bit depth of 8 or 16 are
+/// supported, palette images are not.
+///
+/// Returns the width, height and the values, row by row starting with the top row.
+pub fn decode_grayscale(
+    data: &[u8],
+) -> Result<(usize, usize, Vec<f32>), Box<dyn std::error::Error>> {
+    if data.get(0..8) != Some(&PNG_SIGNATURE[..]) {
+        return Err("not a png file".into());
+    }
+
+    let mut width = 0usize;
+    let mut height = 0usize;
+    let mut bit_depth = 0u8;
+    let mut channels = 0usize;
+    let mut compressed: Vec<u8> = vec![];
+
+    // Iterate over the chunks; length, type, data, crc.
+    let mut offset = 8;
+    while offset < data.len() {
+        let length = read_u32(data, offset).ok_or("truncated chunk")? as usize;
+        let chunk_type = data.get(offset + 4..offset + 8).ok_or("truncated chunk")?;
+        let chunk = data
+            .get(offset + 8..offset + 8 + length)
+            .ok_or("truncated chunk")?;
+        match chunk_type {
+            b"IHDR" => {
+                width = read_u32(chunk, 0).ok_or("truncated header")? as usize;
+                height = read_u32(chunk, 4).ok_or("truncated header")? as usize;
+                bit_depth = *chunk.get(8).ok_or("truncated header")?;
+                let color_type = *chunk.get(9).ok_or("truncated header")?;
+                let interlace = *chunk.get(12).ok_or("truncated header")?;
+                channels = match color_type {
+                    0 => 1,
+                    2 => 3,
+                    4 => 2,
+                    6 => 4,
+                    _ => return Err(format!("unsupported color type {color_type}").into()),
+                };
+                if bit_depth != 8 && bit_depth != 16 {
+                    return Err(format!("unsupported bit depth {bit_depth}").into());
+                }
+                if interlace != 0 {
+                    return Err("interlaced images are not supported".into());
+                }
+            }
+            b"IDAT" => compressed.extend_from_slice(chunk),
+            b"IEND" => break,
+            _ => {}
+        }
+        offset += 12 + length;
+    }
+    if width == 0 || height == 0 {
+        return Err("png has no pixels".into());
+    }
+
+    let raw = miniz_oxide::inflate::decompress_to_vec_zlib(&compressed)
+        .map_err(|e| format!("failed to decompress png: {e:?}"))?;
+
+    // Undo the filtering, each row is prefixed with the filter type.
+    let bytes_per_sample = bit_depth as usize / 8;
+    let bpp = channels * bytes_per_sample;
+    // Dimensions come from the file, so guard against overflow on malformed or huge images.
+    let too_large = || "png dimensions are too large";
+    let stride = width.checked_mul(bpp).ok_or_else(too_large)?;
+    let required = (stride + 1).checked_mul(height).ok_or_else(too_large)?;
+    if raw.len() < required {
+        return Err("png data is truncated".into());
+    }
+    let mut previous = vec![0u8; stride];
+    let mut current = vec![0u8; stride];
+    let mut values = Vec::with_capacity(width * height);
+    for row in 0..height {
+        let start = row * (stride + 1);
+        let filter = raw[start];
+        let line = &raw[start + 1..start + 1 + stride];
+        for i in 0..stride {
+            let a = if i >= bpp { current[i - bpp] } else { 0 };
+            let b = previous[i];
+            let c = if i >= bpp { previous[i - bpp] } else { 0 };
+            let predictor = match filter {
+                0 => 0,
+                1 => a,
+                2 => b,
+                3 => ((a as u16 + b as u16) / 2) as u8,
+                4 => paeth(a, b, c),
+                _ => return Err(format!("unsupported filter type {filter}").into()),
+            };
+            current[i] = line[i].wrapping_add(predictor);
+        }
+        for x in 0..width {
+            let sample = &current[x * bpp..x * bpp + bytes_per_sample];
+            let value = if bytes_per_sample == 1 {
+                sample[0] as f32 / u8::MAX as f32
+            } else {
+                u16::from_be_bytes([sample[0], sample[1]]) as f32 / u16::MAX as f32
+            };
+            values.push(value);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    Ok((width, height, values))
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    /// Encode a grayscale 8 bit png, crc's are not checked by the decoder so left at zero.
+    pub fn encode_grayscale(width: usize, height: usize, values: &[u8], filter: u8) -> Vec<u8> {
+        let mut res = PNG_SIGNATURE.to_vec();
+        let mut chunk = |chunk_type: &[u8], payload: &[u8]| {
+            res.extend((payload.len() as u32).to_be_bytes());
+            res.extend(chunk_type);
+            res.extend(payload);
+            res.extend([0u8; 4]);
+        };
+        let mut header = vec![];
+        header.extend((width as u32).to_be_bytes());
+        header.extend((height as u32).to_be_bytes());
+        header.extend([8, 0, 0, 0, 0]);
+        chunk(b"IHDR", &header);
+
+        let mut raw = vec![];
+        for row in 0..height {
+            raw.push(filter);
+            for x in 0..width {
+                let v = values[row * width + x];
+                // Only the sub filter is supported by this encoder.
+                let predictor = if filter == 1 && x > 0 {
+                    values[row * width + x - 1]
+                } else {
+                    0
+                };
+                raw.push(v.wrapping_sub(predictor));
+            }
+        }
+        chunk(
+            b"IDAT",
+            &miniz_oxide::deflate::compress_to_vec_zlib(&raw, 6),
+        );
+        chunk(b"IEND", &[]);
+        res
+    }
+
+    #[test]
+    fn test_decode_grayscale() {
+        let values = [0u8, 51, 102, 153, 204, 255];
+        for filter in [0, 1] {
+            let data = encode_grayscale(3, 2, &values, filter);
+            let (width, height, decoded) = decode_grayscale(&data).unwrap();
+            assert_eq!(width, 3);
+            assert_eq!(height, 2);
+            for (v, d) in values.iter().zip(decoded.iter()) {
+                assert!((*v as f32 / 255.0 - d).abs() < 1e-6);
+            }
+        }
+        assert!(decode_grayscale(&[1, 2, 3]).is_err());
+
+        // Dimensions for which the size of the data overflows are rejected.
+        let mut data = encode_grayscale(1, 1, &[0], 0);
+        let header = PNG_SIGNATURE.len() + 8;
+        data[header..header + 8].copy_from_slice(&[0xff; 8]);
+        data[header + 8] = 16;
+        data[header + 9] = 6;
+        assert!(decode_grayscale(&data).is_err());
+    }
+}
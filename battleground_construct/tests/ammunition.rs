@@ -0,0 +1,58 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{
+    ControllerType, ResupplyZone, ScenarioConfig, Spawn, SpawnConfig,
+};
+use battleground_construct::Construct;
+
+mod common;
+use common::{run, setup_scenario, tanks};
+
+fn shooting_tank(resupply_zones: Vec<ResupplyZone>) -> Construct {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            spawns: vec![Spawn {
+                controller: ControllerType::SwivelShoot,
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+        ammunition: Some(components::ammunition::AmmunitionConfig {
+            tank: Some(2),
+            ..Default::default()
+        }),
+        resupply_zones,
+        ..Default::default()
+    };
+    setup_scenario(&config)
+}
+
+fn ammunition(construct: &Construct) -> u32 {
+    let tank = tanks(construct)[0];
+    construct
+        .world()
+        .component::<components::ammunition::Ammunition>(tank.muzzle_entity)
+        .unwrap()
+        .count()
+}
+
+#[test]
+fn test_ammunition_runs_out() {
+    let mut construct = shooting_tank(vec![]);
+    assert_eq!(ammunition(&construct), 2);
+    run(&mut construct, 10.0);
+    assert_eq!(ammunition(&construct), 0);
+}
+
+#[test]
+fn test_resupply_zone() {
+    let mut construct = shooting_tank(vec![ResupplyZone {
+        x: 0.0,
+        y: 0.0,
+        radius: 3.0,
+        interval: 0.5,
+        team: None,
+    }]);
+    // Resupplied faster than the cannon reloads, so the magazine fills back up.
+    run(&mut construct, 10.0);
+    assert!(ammunition(&construct) > 0);
+}
@@ -0,0 +1,137 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{
+    FlagBase, MatchConfig, MatchType, ScenarioConfig, Spawn, SpawnConfig,
+};
+use battleground_construct::Construct;
+use components::team::TeamId;
+use components::team_flag::{FlagState, TeamFlag};
+use components::unit_interface::{RegisterMap, UnitModule};
+
+use battleground_unit_control::modules::objectives::*;
+
+mod common;
+use common::{setup_scenario, step, tanks, team, team_of, teleport};
+
+fn flag_of(construct: &Construct, team: TeamId) -> TeamFlag {
+    *construct
+        .world()
+        .component_iter::<TeamFlag>()
+        .find(|(_, f)| f.team() == team)
+        .unwrap()
+        .1
+}
+
+#[test]
+fn test_capture_the_flag() {
+    let config = ScenarioConfig {
+        match_config: MatchConfig {
+            mode: MatchType::CaptureTheFlag {
+                flags: vec![
+                    FlagBase {
+                        team: 0,
+                        x: 10.0,
+                        y: 0.0,
+                        radius: 1.5,
+                    },
+                    FlagBase {
+                        team: 1,
+                        x: -10.0,
+                        y: 0.0,
+                        radius: 1.5,
+                    },
+                ],
+                point_limit: Some(2),
+            },
+            ..Default::default()
+        },
+        spawn_config: SpawnConfig {
+            teams: vec![team("red"), team("blue")],
+            spawns: vec![
+                Spawn {
+                    team: Some(0),
+                    x: -10.0,
+                    ..Default::default()
+                },
+                Spawn {
+                    team: Some(1),
+                    x: 20.0,
+                    y: 20.0,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    let tanks = tanks(&construct);
+    let (red_tank, blue_tank) = (tanks[0], tanks[1]);
+    let (red, blue) = (
+        team_of(&construct, red_tank.unit_entity),
+        team_of(&construct, blue_tank.unit_entity),
+    );
+
+    // Red starts on the blue flag and picks it up.
+    step(&mut construct, 0.5);
+    let blue_flag = flag_of(&construct, blue);
+    assert_eq!(blue_flag.state(), FlagState::Carried);
+    assert_eq!(blue_flag.carrier(), Some(red_tank.unit_id));
+    assert_eq!(flag_of(&construct, red).state(), FlagState::AtBase);
+
+    // The objectives module exposes the flags.
+    let mut registers = RegisterMap::new();
+    components::objectives_module::ObjectivesModule::new()
+        .get_registers(construct.world(), &mut registers);
+    assert_eq!(
+        registers[&REG_OBJECTIVES_FLAG_COUNT].value_i32().unwrap(),
+        2
+    );
+    let carried = (0..2)
+        .map(|i| REG_OBJECTIVES_FLAG_START + i * REG_OBJECTIVES_FLAG_STRIDE)
+        .find(|start| {
+            registers[&(start + REG_OBJECTIVES_FLAG_OFFSET_STATE)]
+                .value_i32()
+                .unwrap()
+                == OBJECTIVES_FLAG_STATE_CARRIED
+        })
+        .unwrap();
+    assert_eq!(
+        registers[&(carried + REG_OBJECTIVES_FLAG_OFFSET_CARRIER)]
+            .value_i32()
+            .unwrap(),
+        red_tank.unit_id.as_u64() as i32
+    );
+
+    // Bringing it to the red base scores, the flag goes back to its base.
+    teleport(&mut construct, &red_tank, 10.0, 0.0);
+    step(&mut construct, 0.5);
+    assert_eq!(flag_of(&construct, blue).state(), FlagState::AtBase);
+    let (_, capture_the_flag) = construct
+        .world()
+        .component_iter::<components::match_capture_the_flag::MatchCaptureTheFlag>()
+        .next()
+        .unwrap();
+    assert_eq!(capture_the_flag.points(), vec![(red, 1)]);
+    drop(capture_the_flag);
+
+    // Pick it up again, the flag drops when the carrier is destroyed.
+    teleport(&mut construct, &red_tank, -10.0, 0.0);
+    step(&mut construct, 0.5);
+    assert_eq!(flag_of(&construct, blue).state(), FlagState::Carried);
+    teleport(&mut construct, &red_tank, 0.0, 0.0);
+    step(&mut construct, 0.5);
+    construct
+        .world
+        .component_mut::<components::health::Health>(red_tank.unit_entity)
+        .unwrap()
+        .subtract(1.0);
+    step(&mut construct, 0.5);
+    let blue_flag = flag_of(&construct, blue);
+    assert_eq!(blue_flag.state(), FlagState::Dropped);
+    assert_eq!(blue_flag.carrier(), None);
+
+    // A blue unit touching the dropped flag returns it.
+    teleport(&mut construct, &blue_tank, 0.0, 0.0);
+    step(&mut construct, 0.5);
+    assert_eq!(flag_of(&construct, blue).state(), FlagState::AtBase);
+}
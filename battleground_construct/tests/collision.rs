@@ -0,0 +1,88 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{
+    ControllerType, Obstacle, ScenarioConfig, Spawn, SpawnConfig,
+};
+use battleground_construct::units::obstacle::ObstacleShape;
+
+mod common;
+use common::{run, setup_scenario, tanks};
+
+fn driving_tank(x: f32, yaw: f32) -> Spawn {
+    Spawn {
+        x,
+        yaw,
+        controller: ControllerType::DiffDriveForwardsBackwards {
+            velocities: (1.0, 1.0),
+            duration: 1000.0,
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_tanks_dont_pass_through_each_other() {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            spawns: vec![
+                driving_tank(0.0, 0.0),
+                driving_tank(4.0, std::f32::consts::PI),
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    run(&mut construct, 10.0);
+
+    let tanks = tanks(&construct);
+    let a = construct.entity_pose(tanks[0].base_entity);
+    let b = construct.entity_pose(tanks[1].base_entity);
+    // Both tanks pushed equally hard, so they meet in the middle, a collider length apart.
+    assert!((b.x() - a.x()) > 1.35, "a: {}, b: {}", a.x(), b.x());
+    assert!((a.x() + b.x() - 4.0).abs() < 0.1);
+
+    let bumper = construct
+        .world()
+        .component::<components::bumper::Bumper>(tanks[0].base_entity)
+        .unwrap()
+        .clone();
+    assert!(bumper.in_contact());
+    assert!(bumper.direction().x > 0.99);
+}
+
+#[test]
+fn test_tank_stopped_by_obstacle() {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            spawns: vec![driving_tank(0.0, 0.0)],
+            ..Default::default()
+        },
+        obstacles: vec![Obstacle {
+            x: 3.0,
+            y: 0.0,
+            yaw: 0.0,
+            shape: ObstacleShape::Box {
+                length: 1.0,
+                width: 4.0,
+                height: 1.0,
+            },
+        }],
+        collision_damage: Some(components::collision_damage::CollisionDamage {
+            damage_per_speed: 0.1,
+            minimum_speed: 0.1,
+        }),
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    run(&mut construct, 10.0);
+
+    let tank = tanks(&construct)[0];
+    let pose = construct.entity_pose(tank.base_entity);
+    // Obstacle face is at 2.5, the tank's collider extends 0.7 in front of it.
+    assert!(pose.x() < 1.81, "x: {}", pose.x());
+    assert!(pose.x() > 1.7, "x: {}", pose.x());
+
+    // The first impact caused damage, pushing against it afterwards doesn't.
+    let health = common::health(&construct, tank.unit_entity);
+    assert!(health < 1.0 && health > 0.85, "health: {health}");
+}
@@ -0,0 +1,80 @@
+//! Helpers shared by the integration tests.
+// Each test binary includes this module and only uses some of the helpers.
+#![allow(dead_code)]
+
+use battleground_construct::components;
+use battleground_construct::config::specification::{ScenarioConfig, Team};
+use battleground_construct::units::tank::UnitTank;
+use battleground_construct::Construct;
+use components::match_finished::{MatchFinished, MatchReport};
+use components::team::TeamId;
+use engine::EntityId;
+
+/// Create the construct for the scenario.
+pub fn setup_scenario(config: &ScenarioConfig) -> Construct {
+    battleground_construct::config::setup::setup_scenario(config).unwrap()
+}
+
+/// Update the construct until its elapsed time reaches `time`.
+pub fn run(construct: &mut Construct, time: f32) {
+    while construct.elapsed_as_f32() < time {
+        construct.update();
+    }
+}
+
+/// Update the construct for `duration` seconds.
+pub fn step(construct: &mut Construct, duration: f32) {
+    let end = construct.elapsed_as_f32() + duration;
+    run(construct, end);
+}
+
+pub fn team(name: &str) -> Team {
+    Team {
+        name: name.to_owned(),
+        ..Default::default()
+    }
+}
+
+/// All tanks, in spawn order.
+pub fn tanks(construct: &Construct) -> Vec<UnitTank> {
+    let mut tanks = construct
+        .world()
+        .component_iter::<UnitTank>()
+        .map(|(_, t)| *t)
+        .collect::<Vec<_>>();
+    tanks.sort_by_key(|t| t.unit_id);
+    tanks
+}
+
+pub fn team_of(construct: &Construct, unit_entity: EntityId) -> TeamId {
+    construct
+        .world()
+        .component::<components::team_member::TeamMember>(unit_entity)
+        .unwrap()
+        .team()
+}
+
+pub fn health(construct: &Construct, unit_entity: EntityId) -> f32 {
+    construct
+        .world()
+        .component::<components::health::Health>(unit_entity)
+        .unwrap()
+        .health()
+}
+
+/// Move the tank to the position, facing positive x.
+pub fn teleport(construct: &mut Construct, tank: &UnitTank, x: f32, y: f32) {
+    *construct
+        .world
+        .component_mut::<components::pose::Pose>(tank.base_entity)
+        .unwrap() = components::pose::Pose::from_se2(x, y, 0.0);
+}
+
+/// The report of the match, None while the match is still running.
+pub fn match_report(construct: &Construct) -> Option<MatchReport> {
+    construct
+        .world()
+        .component_iter::<MatchFinished>()
+        .next()
+        .map(|(_, f)| f.report().expect("finished match has a report").clone())
+}
@@ -0,0 +1,145 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{
+    ControllerType, ScenarioConfig, Spawn, SpawnConfig, Team, Unit,
+};
+use battleground_construct::units::structure::UnitStructure;
+use battleground_construct::Construct;
+use battleground_unit_control::modules::constructor::*;
+use battleground_unit_control::modules::radar::REG_RADAR_RANGE_MAX;
+use battleground_unit_control::modules::tower_radar::*;
+use battleground_unit_control::units::constructor::{
+    MODULE_CONSTRUCTOR_BUILD, MODULE_CONSTRUCTOR_TOWER_RADAR,
+};
+use battleground_unit_control::{Interface, UnitControl};
+use std::sync::Mutex;
+
+mod common;
+use common::{run, setup_scenario};
+
+/// Keeps requesting walls.
+struct BuildWalls;
+impl UnitControl for BuildWalls {
+    fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+        interface.set_i32(
+            MODULE_CONSTRUCTOR_BUILD,
+            REG_CONSTRUCTOR_BUILD,
+            STRUCTURE_WALL,
+        )?;
+        Ok(())
+    }
+}
+
+fn build_walls() -> Box<dyn UnitControl> {
+    Box::new(BuildWalls)
+}
+
+/// The range of the tower radar as last read by [`BuildRadarTower`], if a tower was present.
+static TOWER_RADAR_RANGE: Mutex<Option<f32>> = Mutex::new(None);
+
+/// Requests a radar tower and reads its radar once it stands.
+struct BuildRadarTower;
+impl UnitControl for BuildRadarTower {
+    fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+        interface.set_i32(
+            MODULE_CONSTRUCTOR_BUILD,
+            REG_CONSTRUCTOR_BUILD,
+            STRUCTURE_RADAR_TOWER,
+        )?;
+        if interface.get_i32(MODULE_CONSTRUCTOR_TOWER_RADAR, REG_TOWER_RADAR_PRESENT)? != 0 {
+            let range = interface.get_f32(MODULE_CONSTRUCTOR_TOWER_RADAR, REG_RADAR_RANGE_MAX)?;
+            *TOWER_RADAR_RANGE.lock().unwrap() = Some(range);
+        }
+        Ok(())
+    }
+}
+
+fn build_radar_tower() -> Box<dyn UnitControl> {
+    Box::new(BuildRadarTower)
+}
+
+fn setup(budget: f32, controller: fn() -> Box<dyn UnitControl>) -> Construct {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            teams: vec![Team {
+                name: "red".to_owned(),
+                color: (255, 0, 0),
+                budget: Some(budget),
+                ..Default::default()
+            }],
+            spawns: vec![Spawn {
+                team: Some(0),
+                unit: Unit::Constructor,
+                controller: ControllerType::Function(controller),
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    setup_scenario(&config)
+}
+
+fn structures(construct: &Construct) -> Vec<UnitStructure> {
+    construct
+        .world()
+        .component_iter::<UnitStructure>()
+        .map(|(_, s)| *s)
+        .collect()
+}
+
+fn construction(construct: &Construct) -> components::construction::Construction {
+    *construct
+        .world()
+        .component_iter::<components::construction::Construction>()
+        .next()
+        .unwrap()
+        .1
+}
+
+#[test]
+fn test_constructor_builds_within_budget() {
+    // Walls cost 10, so none fit in the budget.
+    let mut construct = setup(5.0, build_walls);
+    run(&mut construct, 10.0);
+    assert!(structures(&construct).is_empty());
+    assert_eq!(construction(&construct).budget(), Some(5.0));
+    assert_eq!(
+        construction(&construct).status(),
+        components::construction::BuildStatus::InsufficientBudget
+    );
+}
+
+#[test]
+fn test_constructor_refuses_occupied_spot() {
+    let mut construct = setup(25.0, build_walls);
+    run(&mut construct, 30.0);
+
+    // The constructor didn't move, so only the first wall fits behind it and nothing else is spent.
+    let structures = structures(&construct);
+    assert_eq!(structures.len(), 1);
+    assert_eq!(
+        structures[0].structure,
+        components::construction::StructureType::Wall
+    );
+    assert_eq!(construction(&construct).budget(), Some(15.0));
+    assert_eq!(
+        construction(&construct).status(),
+        components::construction::BuildStatus::Occupied
+    );
+}
+
+#[test]
+fn test_constructor_reads_tower_radar() {
+    let mut construct = setup(30.0, build_radar_tower);
+    run(&mut construct, 5.0);
+    assert!(TOWER_RADAR_RANGE.lock().unwrap().is_none());
+    run(&mut construct, 20.0);
+
+    let structures = structures(&construct);
+    assert_eq!(structures.len(), 1);
+    assert_eq!(
+        construction(&construct).tower_radar(),
+        Some(structures[0].radar_entity)
+    );
+    assert_eq!(*TOWER_RADAR_RANGE.lock().unwrap(), Some(60.0));
+}
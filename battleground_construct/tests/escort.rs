@@ -0,0 +1,128 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{
+    MatchConfig, MatchType, ScenarioConfig, Spawn, SpawnConfig, Waypoint,
+};
+use battleground_construct::Construct;
+use components::match_escort::MatchEscort;
+use components::match_finished::ObjectiveReport;
+
+mod common;
+use common::{match_report, setup_scenario, step, tanks, team, team_of, teleport};
+
+fn progress(construct: &Construct) -> f32 {
+    construct
+        .world()
+        .component_iter::<MatchEscort>()
+        .next()
+        .unwrap()
+        .1
+        .progress()
+}
+
+#[test]
+fn test_escort_payload() {
+    let config = ScenarioConfig {
+        match_config: MatchConfig {
+            mode: MatchType::Escort {
+                attackers: 0,
+                path: vec![Waypoint { x: 0.0, y: 0.0 }, Waypoint { x: 4.0, y: 0.0 }],
+                speed: 1.0,
+                radius: 3.0,
+            },
+            time_limit: Some(100.0),
+            ..Default::default()
+        },
+        spawn_config: SpawnConfig {
+            teams: vec![team("attack"), team("defend")],
+            spawns: vec![
+                Spawn {
+                    team: Some(0),
+                    x: 30.0,
+                    ..Default::default()
+                },
+                Spawn {
+                    team: Some(1),
+                    x: -30.0,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    let tanks = tanks(&construct);
+    let (attacker, defender) = (tanks[0], tanks[1]);
+
+    // Nobody near, the payload stays put.
+    step(&mut construct, 1.0);
+    assert_eq!(progress(&construct), 0.0);
+
+    // The attacker pushes it forward.
+    teleport(&mut construct, &attacker, 0.0, 1.0);
+    step(&mut construct, 2.0);
+    let pushed = progress(&construct);
+    assert!(pushed > 0.3);
+
+    // Contested, it stays where it is.
+    teleport(&mut construct, &defender, 0.0, -1.0);
+    step(&mut construct, 1.0);
+    assert_eq!(progress(&construct), pushed);
+
+    // Only the defender near, it rolls back but the furthest progress is kept.
+    teleport(&mut construct, &attacker, 30.0, 0.0);
+    step(&mut construct, 1.0);
+    let (_, escort) = construct
+        .world()
+        .component_iter::<MatchEscort>()
+        .next()
+        .unwrap();
+    assert!(escort.progress() < pushed);
+    assert_eq!(escort.furthest_progress(), pushed);
+    drop(escort);
+
+    // The attacker escorts it to the end and wins.
+    teleport(&mut construct, &defender, -30.0, 0.0);
+    teleport(&mut construct, &attacker, 2.0, 1.0);
+    step(&mut construct, 5.0);
+    let report = match_report(&construct).expect("match should be finished");
+    assert_eq!(
+        report.winner(),
+        Some(team_of(&construct, attacker.unit_entity))
+    );
+    assert!(report.reports.iter().any(|r| matches!(
+        r,
+        ObjectiveReport::MatchEscort(e) if e.furthest_progress() == 1.0
+    )));
+}
+
+#[test]
+fn test_escort_invalid_setup() {
+    let escort = |path: Vec<Waypoint>, time_limit| ScenarioConfig {
+        match_config: MatchConfig {
+            mode: MatchType::Escort {
+                attackers: 0,
+                path,
+                speed: 1.0,
+                radius: 3.0,
+            },
+            time_limit,
+            ..Default::default()
+        },
+        spawn_config: SpawnConfig {
+            teams: vec![team("attack"), team("defend")],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = battleground_construct::config::setup::setup_scenario;
+    let waypoint = |x| Waypoint { x, y: 0.0 };
+
+    // A path without length would be completed immediately.
+    assert!(setup(&escort(vec![waypoint(1.0)], Some(100.0))).is_err());
+    assert!(setup(&escort(vec![waypoint(1.0), waypoint(1.0)], Some(100.0))).is_err());
+
+    // Without a time limit the defenders can never win.
+    assert!(setup(&escort(vec![waypoint(0.0), waypoint(4.0)], None)).is_err());
+    assert!(setup(&escort(vec![waypoint(0.0), waypoint(4.0)], Some(100.0))).is_ok());
+}
@@ -0,0 +1,94 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{ControllerType, ScenarioConfig};
+use battleground_construct::units::tank::UnitTank;
+use battleground_construct::Construct;
+use components::match_finished::{MatchConclusion, MatchReport};
+use components::team::TeamId;
+
+mod common;
+use common::{match_report, run, setup_scenario, tanks, team_of};
+
+/// The three team scenario, with idle units and a short time limit.
+fn free_for_all() -> ScenarioConfig {
+    let mut config =
+        battleground_construct::config::reader::get_builtin_scenario("test_free_for_all").unwrap();
+    config.match_config.time_limit = Some(1.0);
+    for team in config.spawn_config.teams.iter_mut() {
+        team.controller = None;
+    }
+    for spawn in config.spawn_config.spawns.iter_mut() {
+        spawn.controller = ControllerType::Idle;
+    }
+    config
+}
+
+fn run_to_finish(construct: &mut Construct) -> MatchReport {
+    run(construct, 2.0);
+    match_report(construct).expect("match should be finished")
+}
+
+/// Tanks ordered by team as in the scenario; red, green, blue.
+fn tanks_and_teams(construct: &Construct) -> Vec<(UnitTank, TeamId)> {
+    tanks(construct)
+        .into_iter()
+        .map(|t| (t, team_of(construct, t.unit_entity)))
+        .collect()
+}
+
+#[test]
+fn test_free_for_all_draw() {
+    let mut construct = setup_scenario(&free_for_all());
+    let report = run_to_finish(&mut construct);
+    assert!(matches!(report.conclusion, MatchConclusion::TimeLimit));
+
+    // Nobody scored, all three teams share first place.
+    assert_eq!(report.scores.len(), 3);
+    assert!(report.scores.iter().all(|s| s.rank == 1));
+    assert!(report.is_draw());
+    assert_eq!(report.winner(), None);
+
+    let wrap_up = battleground_construct::config::wrap_up::create_wrap_up_report(construct.world());
+    assert!(wrap_up.draw);
+    assert!(wrap_up.winning_team.is_none());
+    assert_eq!(wrap_up.ranking.len(), 3);
+}
+
+#[test]
+fn test_free_for_all_ranking() {
+    let mut construct = setup_scenario(&free_for_all());
+    let tanks = tanks_and_teams(&construct);
+    let ((red_tank, red), (green_tank, green), (_, blue)) = (tanks[0], tanks[1], tanks[2]);
+
+    // Red destroys the green tank.
+    let time = construct.elapsed_as_f32();
+    let mut hit_by = components::hit_by::HitBy::new();
+    hit_by.add_hit(
+        1.0,
+        components::impact::Impact::new(None, cgmath::Matrix4::from_scale(1.0), None),
+        Some(red_tank.unit_id),
+        time,
+    );
+    construct
+        .world
+        .add_component(green_tank.unit_entity, hit_by);
+
+    // Red leads, green and blue are tied for second place.
+    let report = run_to_finish(&mut construct);
+    assert!(!report.is_draw());
+    assert_eq!(report.winner(), Some(red));
+    assert_eq!(report.placement(red), Some(1));
+    assert_eq!(report.placement(green), Some(2));
+    assert_eq!(report.placement(blue), Some(2));
+
+    let wrap_up = battleground_construct::config::wrap_up::create_wrap_up_report(construct.world());
+    assert!(!wrap_up.draw);
+    assert_eq!(wrap_up.winning_team.unwrap().name, "Red");
+    let placements = wrap_up
+        .ranking
+        .iter()
+        .map(|p| (p.placement, p.team.name.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(placements[0], (1, "Red"));
+    assert!(placements[1..].contains(&(2, "Green")));
+    assert!(placements[1..].contains(&(2, "Blue")));
+}
@@ -0,0 +1,137 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{ScenarioConfig, Spawn, SpawnConfig, Unit};
+use battleground_construct::units::artillery::UnitArtillery;
+use battleground_construct::units::tank::UnitTank;
+use battleground_construct::Construct;
+use components::hit_zone::Subsystem;
+
+mod common;
+use common::{health, setup_scenario, tanks};
+
+fn idle_tank() -> (Construct, UnitTank) {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            spawns: vec![Spawn {
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    construct.update();
+    let tank = tanks(&construct)[0];
+    (construct, tank)
+}
+
+/// Hit the entity with an impact at the provided world position, then step the construct.
+fn hit(construct: &mut Construct, entity: engine::EntityId, damage: f32, x: f32, y: f32) {
+    let position = cgmath::Matrix4::from_translation(cgmath::vec3(x, y, 0.5));
+    let impact = components::impact::Impact::new(Some(entity), position, None);
+    let mut hit_by = components::hit_by::HitBy::new();
+    hit_by.add_hit(damage, impact, None, 0.0);
+    construct.world.add_component(entity, hit_by);
+    construct.update();
+}
+
+fn subsystem(construct: &Construct, unit_entity: engine::EntityId, subsystem: Subsystem) -> f32 {
+    construct
+        .world()
+        .component::<components::subsystem_health::SubsystemHealth>(unit_entity)
+        .unwrap()
+        .health(subsystem)
+}
+
+#[test]
+fn test_directional_armor() {
+    // Tank spawns at the origin, facing positive x.
+    let (mut construct, tank) = idle_tank();
+    hit(&mut construct, tank.body_entity, 0.1, 1.0, 0.0);
+    let front_damage = 1.0 - health(&construct, tank.unit_entity);
+    hit(&mut construct, tank.body_entity, 0.1, -1.0, 0.0);
+    let rear_damage = 1.0 - health(&construct, tank.unit_entity) - front_damage;
+    assert!((front_damage - 0.07).abs() < 1e-4);
+    assert!((rear_damage - 0.15).abs() < 1e-4);
+
+    // The history records the effective damage.
+    let history = construct
+        .world()
+        .component::<components::hit_by::HitByHistory>(tank.unit_entity)
+        .unwrap()
+        .hits()
+        .iter()
+        .map(|v| v.damage())
+        .collect::<Vec<_>>();
+    assert!((history[0] - 0.07).abs() < 1e-4);
+
+    // Body hits don't affect subsystems.
+    assert_eq!(
+        subsystem(&construct, tank.unit_entity, Subsystem::Tracks),
+        1.0
+    );
+}
+
+#[test]
+fn test_subsystem_degradation() {
+    let (mut construct, tank) = idle_tank();
+    let bounds = |construct: &Construct| {
+        construct
+            .world()
+            .component::<components::differential_drive_base::DifferentialDriveBase>(
+                tank.base_entity,
+            )
+            .unwrap()
+            .wheel_velocity_bounds()
+    };
+    assert_eq!(bounds(&construct), (-1.0, 1.0));
+
+    // Destroy the tracks, this leaves the minimum capability.
+    for _ in 0..4 {
+        hit(&mut construct, tank.base_entity, 0.4, 0.0, 1.0);
+    }
+    assert_eq!(
+        subsystem(&construct, tank.unit_entity, Subsystem::Tracks),
+        0.0
+    );
+    assert_eq!(
+        subsystem(&construct, tank.unit_entity, Subsystem::Radar),
+        1.0
+    );
+    let minimum = components::subsystem_health::SUBSYSTEM_MINIMUM_CAPABILITY;
+    assert_eq!(bounds(&construct), (-minimum, minimum));
+
+    // Hitting the turret damages the radar.
+    hit(&mut construct, tank.turret_entity, 0.1, 0.0, 1.0);
+    let radar_health = subsystem(&construct, tank.unit_entity, Subsystem::Radar);
+    assert!(radar_health < 1.0);
+}
+
+#[test]
+fn test_artillery_radar_zone() {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            spawns: vec![Spawn {
+                unit: Unit::Artillery,
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    construct.update();
+    let artillery = construct
+        .world()
+        .component_iter::<UnitArtillery>()
+        .map(|(_, a)| *a)
+        .next()
+        .unwrap();
+
+    // Hitting the turret damages the radar.
+    hit(&mut construct, artillery.turret_entity, 0.1, 0.0, 1.0);
+    assert!(subsystem(&construct, artillery.unit_entity, Subsystem::Radar) < 1.0);
+    assert_eq!(
+        subsystem(&construct, artillery.unit_entity, Subsystem::Weapon),
+        1.0
+    );
+}
@@ -0,0 +1,117 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{
+    ControllerType, ScenarioConfig, Spawn, SpawnConfig,
+};
+use battleground_construct::Construct;
+
+mod common;
+use common::{run, setup_scenario, tanks};
+
+fn tank_with_velocities(
+    velocities: (f32, f32),
+    gps: Option<components::gps::GpsConfig>,
+) -> Construct {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            spawns: vec![Spawn {
+                controller: ControllerType::DiffDriveForwardsBackwards {
+                    velocities,
+                    duration: 1000.0,
+                },
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+        gps,
+        ..Default::default()
+    };
+    setup_scenario(&config)
+}
+
+#[test]
+fn test_odometry_and_perfect_gps() {
+    let mut construct = tank_with_velocities((1.0, 1.0), None);
+    run(&mut construct, 3.0);
+    let tank = tanks(&construct)[0];
+
+    let odometry = *construct
+        .world()
+        .component::<components::odometry::Odometry>(tank.base_entity)
+        .unwrap();
+    let (left, right) = odometry.ticks();
+    let travelled = construct.entity_pose(tank.base_entity).x();
+    let ticks_per_meter = components::odometry::ODOMETRY_TICKS_PER_METER;
+    assert!(travelled > 1.0);
+    assert_eq!(left, right);
+    assert!((left as f32 / ticks_per_meter - travelled).abs() < 0.01);
+
+    // Without an error model the gps reports the truth.
+    let gps = construct
+        .world()
+        .component::<components::gps::Gps>(tank.body_entity)
+        .unwrap()
+        .clone();
+    assert!(gps.valid());
+    let body = construct.entity_pose(tank.body_entity);
+    assert!((gps.position().x - body.x()).abs() < 1e-5);
+    assert!((gps.position().y - body.y()).abs() < 1e-5);
+}
+
+#[test]
+fn test_imu_yaw_rate() {
+    let mut construct = tank_with_velocities((-0.5, 0.5), None);
+    run(&mut construct, 2.0);
+    let tank = tanks(&construct)[0];
+    let track_width = construct
+        .world()
+        .component::<components::differential_drive_base::DifferentialDriveBase>(tank.base_entity)
+        .unwrap()
+        .track_width();
+    let imu = *construct
+        .world()
+        .component::<components::imu::Imu>(tank.body_entity)
+        .unwrap();
+    let rates = imu.rates();
+    assert!((rates.z - 1.0 / track_width).abs() < 0.01, "{rates:?}");
+    assert!(rates.x.abs() < 1e-3);
+    assert!(rates.y.abs() < 1e-3);
+}
+
+#[test]
+fn test_gps_outage() {
+    let gps_config = components::gps::GpsConfig {
+        position_stddev: 0.5,
+        outages: vec![components::gps::GpsOutage {
+            x: 3.0,
+            y: 0.0,
+            radius: 1.0,
+        }],
+        ..Default::default()
+    };
+    let mut construct = tank_with_velocities((1.0, 1.0), Some(gps_config));
+    let tank = tanks(&construct)[0];
+    let gps = |construct: &Construct| {
+        construct
+            .world()
+            .component::<components::gps::Gps>(tank.body_entity)
+            .unwrap()
+            .clone()
+    };
+
+    // Drive until inside the outage zone.
+    while construct.entity_pose(tank.body_entity).x() < 3.0 {
+        construct.update();
+    }
+    let inside = gps(&construct);
+    assert!(!inside.valid());
+    assert!(inside.fix_time() < construct.elapsed_as_f32());
+
+    let leave_time = construct.elapsed_as_f32() + 2.0;
+    run(&mut construct, leave_time);
+    let outside = gps(&construct);
+    assert!(outside.valid());
+    // Noisy, but not the truth.
+    let body = construct.entity_pose(tank.body_entity);
+    assert!(outside.position().x != body.x());
+    assert!((outside.position().x - body.x()).abs() < 3.0);
+}
@@ -0,0 +1,151 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{
+    ControllerType, ScenarioConfig, Spawn, SpawnConfig, Unit,
+};
+use battleground_construct::units::tank::UnitTank;
+use battleground_construct::Construct;
+use battleground_unit_control::modules::mine_layer::*;
+use battleground_unit_control::units::constructor::MODULE_CONSTRUCTOR_MINE_LAYER;
+use battleground_unit_control::{Interface, UnitControl};
+use components::mine::{Mine, MineConfig, MineLayer, MineLayerStatus};
+
+mod common;
+use common::{health, setup_scenario, team, team_of};
+
+/// Requests a mine on every update.
+struct DropMines {}
+impl UnitControl for DropMines {
+    fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+        interface.set_i32(MODULE_CONSTRUCTOR_MINE_LAYER, REG_MINE_LAYER_DROP, 1)?;
+        Ok(())
+    }
+}
+
+fn drop_mines() -> Box<dyn UnitControl> {
+    Box::new(DropMines {})
+}
+
+fn setup(mines: MineConfig, spawns: Vec<Spawn>) -> Construct {
+    let mut all_spawns = vec![Spawn {
+        team: Some(0),
+        unit: Unit::Constructor,
+        controller: ControllerType::Function(drop_mines),
+        ..Default::default()
+    }];
+    all_spawns.extend(spawns);
+    let config = ScenarioConfig {
+        mines: Some(mines),
+        spawn_config: SpawnConfig {
+            teams: vec![team("red"), team("blue")],
+            spawns: all_spawns,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    setup_scenario(&config)
+}
+
+/// The tank that belongs to the team with this name.
+fn tank_of(construct: &Construct, name: &str) -> UnitTank {
+    let team = construct
+        .world()
+        .component_iter::<components::team::Team>()
+        .find(|(_, t)| t.name() == name)
+        .unwrap()
+        .1
+        .id();
+    construct
+        .world()
+        .component_iter::<UnitTank>()
+        .map(|(_, t)| *t)
+        .find(|t| team_of(construct, t.unit_entity) == team)
+        .unwrap()
+}
+
+#[test]
+fn test_mines_dropped_and_hidden() {
+    let mines = MineConfig {
+        capacity: 2,
+        ..Default::default()
+    };
+    let tank = |team, y| Spawn {
+        team: Some(team),
+        x: -1.0,
+        y,
+        ..Default::default()
+    };
+    let mut construct = setup(mines, vec![tank(0, 8.0), tank(1, -8.0)]);
+    let (red_tank, blue_tank) = (tank_of(&construct, "red"), tank_of(&construct, "blue"));
+
+    let mut red_seen = false;
+    let mut blue_seen = false;
+    while construct.elapsed_as_f32() < 10.0 {
+        construct.update();
+        let world = construct.world();
+        let mines = world.component_entities::<Mine>();
+        let reflected = |radar_entity| {
+            world
+                .component::<components::radar::Radar>(radar_entity)
+                .unwrap()
+                .reflections()
+                .iter()
+                .any(|r| r.reflector.map(|e| mines.contains(&e)) == Some(true))
+        };
+        red_seen |= reflected(red_tank.radar_entity);
+        blue_seen |= reflected(blue_tank.radar_entity);
+    }
+
+    // Both mines were dropped, further requests are refused.
+    let world = construct.world();
+    assert_eq!(world.component_entities::<Mine>().len(), 2);
+    let (_, mine_layer) = world.component_iter::<MineLayer>().next().unwrap();
+    assert_eq!(mine_layer.mines(), 0);
+    assert_eq!(mine_layer.status(), MineLayerStatus::Empty);
+
+    // The own team sees the mines on the radar, the other team is too far away to see them.
+    assert!(red_seen);
+    assert!(!blue_seen);
+
+    // Nothing set them off, the own team doesn't trigger them.
+    assert_eq!(health(&construct, red_tank.unit_entity), 1.0);
+}
+
+#[test]
+fn test_mine_detonates_on_enemy() {
+    let mines = MineConfig {
+        capacity: 1,
+        trigger_radius: 3.0,
+        ..Default::default()
+    };
+    let mut construct = setup(
+        mines,
+        vec![Spawn {
+            team: Some(1),
+            x: -4.0,
+            ..Default::default()
+        }],
+    );
+    let blue_tank = tank_of(&construct, "blue");
+
+    // Dropping happens once deployed, the mine only goes off once it is armed.
+    let arm_time = MineConfig::default().arm_time;
+    let mut dropped_at = None;
+    while construct.elapsed_as_f32() < 15.0 {
+        construct.update();
+        let world = construct.world();
+        let mines = world.component_entities::<Mine>().len();
+        if mines == 1 && dropped_at.is_none() {
+            dropped_at = Some(construct.elapsed_as_f32());
+        }
+        if let Some(dropped_at) = dropped_at {
+            if construct.elapsed_as_f32() < dropped_at + arm_time {
+                assert_eq!(mines, 1);
+            }
+        }
+    }
+    assert!(dropped_at.is_some());
+
+    assert!(construct.world().component_entities::<Mine>().is_empty());
+    let health = health(&construct, blue_tank.unit_entity);
+    assert!(health < 1.0, "{health}");
+}
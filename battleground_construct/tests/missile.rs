@@ -0,0 +1,104 @@
+use battleground_construct::components::missile::{Missile, MissileLauncher};
+use battleground_construct::config::specification::{
+    ControllerType, ScenarioConfig, Spawn, SpawnConfig, Unit,
+};
+use battleground_construct::units::missile_launcher::UnitMissileLauncher;
+use battleground_unit_control::modules::cannon::REG_CANNON_TRIGGER;
+use battleground_unit_control::modules::missile_datalink::*;
+use battleground_unit_control::units::missile_launcher::*;
+use battleground_unit_control::{Interface, UnitControl};
+
+mod common;
+use common::{health, setup_scenario, tanks};
+
+const TARGET_X: f32 = 20.0;
+const TARGET_Y: f32 = 6.0;
+
+/// Fires a single missile and guides it to the center of the tank's body.
+#[derive(Default)]
+struct GuideMissile {
+    fired: bool,
+}
+impl UnitControl for GuideMissile {
+    fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+        if !self.fired {
+            interface.set_i32(MODULE_MISSILE_LAUNCHER_CANNON, REG_CANNON_TRIGGER, 1)?;
+            self.fired = true;
+            return Ok(());
+        }
+        interface.set_i32(MODULE_MISSILE_LAUNCHER_CANNON, REG_CANNON_TRIGGER, 0)?;
+        let slot = REG_MISSILE_DATALINK_START;
+        if interface.get_i32(
+            MODULE_MISSILE_LAUNCHER_DATALINK,
+            slot + REG_MISSILE_DATALINK_OFFSET_ACTIVE,
+        )? == 1
+        {
+            interface.set_i32(
+                MODULE_MISSILE_LAUNCHER_DATALINK,
+                slot + REG_MISSILE_DATALINK_OFFSET_MODE,
+                MISSILE_GUIDANCE_POSITION,
+            )?;
+            for (offset, value) in [
+                (REG_MISSILE_DATALINK_OFFSET_TARGET_X, TARGET_X),
+                (REG_MISSILE_DATALINK_OFFSET_TARGET_Y, TARGET_Y),
+                (REG_MISSILE_DATALINK_OFFSET_TARGET_Z, 0.25),
+            ] {
+                interface.set_f32(MODULE_MISSILE_LAUNCHER_DATALINK, slot + offset, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn guide_missile() -> Box<dyn UnitControl> {
+    Box::new(GuideMissile::default())
+}
+
+#[test]
+fn test_missile_guided_to_target() {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            spawns: vec![
+                Spawn {
+                    unit: Unit::MissileLauncher,
+                    controller: ControllerType::Function(guide_missile),
+                    ..Default::default()
+                },
+                Spawn {
+                    x: TARGET_X,
+                    y: TARGET_Y,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    let launcher = *construct
+        .world()
+        .component_iter::<UnitMissileLauncher>()
+        .next()
+        .unwrap()
+        .1;
+    let tank = tanks(&construct)[0];
+
+    let mut linked = vec![];
+    while construct.elapsed_as_f32() < 8.0 {
+        construct.update();
+        let world = construct.world();
+        let slot = world
+            .component::<MissileLauncher>(launcher.release_entity)
+            .unwrap()
+            .slots()[0];
+        linked.push(slot.map(|e| world.component::<Missile>(e).is_some()) == Some(true));
+    }
+
+    // The missile went in flight, and was no longer in flight after it hit.
+    assert!(linked.iter().any(|v| *v));
+    assert!(!linked.last().unwrap());
+
+    // It missed the tank if it flew straight ahead, so the guidance steered it to the tank.
+    let health = health(&construct, tank.unit_entity);
+    assert!(health < 1.0, "{health}");
+}
@@ -0,0 +1,82 @@
+use battleground_construct::components::unit_interface::{
+    RegisterInterface, RegisterInterfaceContainer,
+};
+use battleground_construct::config::specification::{ScenarioConfig, Spawn, SpawnConfig, Unit};
+use battleground_unit_control::mock::MockInterface;
+use battleground_unit_control::modules::unit::REG_UNIT_UNIT_TYPE;
+use battleground_unit_control::units::UnitType;
+use battleground_unit_control::{Interface, RegisterType};
+
+mod common;
+use common::setup_scenario;
+
+/// Modules with their names and registers, registers with their names and types.
+type Layout = Vec<(u32, String, Vec<(u32, String, RegisterType)>)>;
+
+fn layout(interface: &dyn Interface) -> Layout {
+    let mut layout = vec![];
+    for module in interface.modules().unwrap() {
+        let mut registers = vec![];
+        for register in interface.registers(module).unwrap() {
+            registers.push((
+                register,
+                interface.register_name(module, register).unwrap(),
+                interface.register_type(module, register).unwrap(),
+            ));
+        }
+        layout.push((module, interface.module_name(module).unwrap(), registers));
+    }
+    layout
+}
+
+#[test]
+fn test_mock_matches_units() {
+    let units = [
+        Unit::Tank,
+        Unit::Artillery,
+        Unit::Constructor,
+        Unit::Scout,
+        Unit::MissileLauncher,
+    ];
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            spawns: units
+                .iter()
+                .enumerate()
+                .map(|(i, unit)| Spawn {
+                    unit: *unit,
+                    // Out of radar range of each other, the mock has no reflections.
+                    x: i as f32 * 100.0,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    construct.update();
+
+    let interfaces = construct
+        .world()
+        .component_iter::<RegisterInterfaceContainer>()
+        .map(|(_, c)| c.clone())
+        .collect::<Vec<_>>();
+    let mut seen = vec![];
+    for container in interfaces {
+        // Populate the registers, like the unit control system does before each update.
+        let mut interface = container.get_mut();
+        RegisterInterface::get_registers(&mut interface, &mut construct.world);
+        let unit_type = interface
+            .get_i32(
+                battleground_unit_control::units::common::MODULE_UNIT,
+                REG_UNIT_UNIT_TYPE,
+            )
+            .unwrap();
+        let unit_type: UnitType = (unit_type as u32).try_into().unwrap();
+        let mock = MockInterface::for_unit(unit_type);
+        assert_eq!(layout(&*interface), layout(&mock), "{unit_type:?}");
+        seen.push(unit_type);
+    }
+    assert_eq!(seen.len(), units.len());
+}
@@ -0,0 +1,72 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{ScenarioConfig, Spawn, SpawnConfig};
+use components::radar::RadarDetections;
+use components::recording::Recording;
+
+mod common;
+use common::{setup_scenario, team};
+
+/// Whether any radar currently detects a reflector.
+fn has_detections(construct: &battleground_construct::Construct) -> bool {
+    construct
+        .world()
+        .component_iter::<RadarDetections>()
+        .any(|(_, d)| !d.reflectors().is_empty())
+}
+
+#[test]
+fn test_radar_detections_played_back() {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            teams: vec![team("red"), team("blue")],
+            spawns: vec![
+                Spawn {
+                    team: Some(0),
+                    ..Default::default()
+                },
+                Spawn {
+                    team: Some(1),
+                    x: 5.0,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        },
+        recording: true,
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    let mut detected = false;
+    while construct.elapsed_as_f32() < 3.0 {
+        construct.update();
+        detected |= has_detections(&construct);
+    }
+    assert!(detected);
+
+    let path = std::env::temp_dir().join("battleground_test_radar_detections.bin");
+    let path = path.to_str().unwrap();
+    construct
+        .world()
+        .component_iter::<Recording>()
+        .next()
+        .unwrap()
+        .1
+        .write_file(path)
+        .unwrap();
+
+    // The detections are recorded, so they are there during playback as well.
+    let mut playback = battleground_construct::config::setup::setup_playback_path(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    let mut played_back = false;
+    while playback.can_update() {
+        playback.update();
+        played_back |= has_detections(&playback);
+    }
+    assert!(played_back);
+    // Viewers need the unit membership to tell which entities belong to hidden units.
+    assert!(playback
+        .world()
+        .component_iter::<components::unit_member::UnitMember>()
+        .next()
+        .is_some());
+}
@@ -0,0 +1,55 @@
+use battleground_construct::components::radar::Radar;
+use battleground_construct::config::specification::{ScenarioConfig, Spawn, SpawnConfig};
+use battleground_construct::Construct;
+
+mod common;
+use common::{setup_scenario, tanks};
+
+fn tank_at(x: f32, y: f32) -> Spawn {
+    Spawn {
+        x,
+        y,
+        ..Default::default()
+    }
+}
+
+/// Distances of all reflections seen by the first tank's radar.
+fn first_tank_reflections(construct: &Construct) -> Vec<f32> {
+    let radar = construct
+        .world()
+        .component::<Radar>(tanks(construct)[0].radar_entity)
+        .unwrap()
+        .clone();
+    radar.reflections().iter().map(|r| r.distance).collect()
+}
+
+fn reflections_for(spawns: Vec<Spawn>) -> Vec<f32> {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            spawns,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    construct.update();
+    construct.update();
+    first_tank_reflections(&construct)
+}
+
+#[test]
+fn test_radar_occluded_by_unit() {
+    // Only the far tank in front of the radar, it is seen.
+    let reflections = reflections_for(vec![tank_at(0.0, 0.0), tank_at(12.0, 0.0)]);
+    assert_eq!(reflections.len(), 1);
+    assert!(reflections[0] > 11.0);
+
+    // Put a tank in between, now only that one is visible.
+    let reflections = reflections_for(vec![
+        tank_at(0.0, 0.0),
+        tank_at(12.0, 0.0),
+        tank_at(6.0, 0.0),
+    ]);
+    assert_eq!(reflections.len(), 1, "{reflections:?}");
+    assert!(reflections[0] < 7.0);
+}
@@ -0,0 +1,70 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{ScenarioConfig, Spawn, SpawnConfig};
+use battleground_construct::units::structure::{spawn_structure, StructureConfig};
+use components::construction::StructureType;
+
+mod common;
+use common::{health, run, setup_scenario, tanks, team, team_of};
+
+#[test]
+fn test_repair_station_repairs_allies() {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            teams: vec![team("red"), team("blue")],
+            spawns: vec![
+                Spawn {
+                    team: Some(0),
+                    x: 2.0,
+                    ..Default::default()
+                },
+                Spawn {
+                    team: Some(1),
+                    x: -2.0,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+
+    let tanks = tanks(&construct);
+    let red_team = team_of(&construct, tanks[0].unit_entity);
+
+    for tank in tanks.iter() {
+        construct
+            .world
+            .component_mut::<components::health::Health>(tank.unit_entity)
+            .unwrap()
+            .subtract(0.5);
+    }
+
+    // Station in between both tanks, repairs only the red team.
+    spawn_structure(
+        &mut construct.world,
+        StructureConfig {
+            x: 0.0,
+            y: 0.0,
+            yaw: 0.0,
+            structure: StructureType::RepairStation,
+            team_member: Some(components::team_member::TeamMember::new(red_team)),
+        },
+    );
+    run(&mut construct, 10.0);
+
+    for tank in tanks.iter() {
+        let history = construct
+            .world()
+            .component::<components::repair::RepairHistory>(tank.unit_entity)
+            .map(|h| h.total());
+        if team_of(&construct, tank.unit_entity) == red_team {
+            assert!(health(&construct, tank.unit_entity) > 0.6);
+            let total = history.unwrap();
+            assert!((0.5 + total - health(&construct, tank.unit_entity)).abs() < 1e-4);
+        } else {
+            assert_eq!(health(&construct, tank.unit_entity), 0.5);
+            assert!(history.is_none());
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{
+    CapturePoint, ControllerType, MatchConfig, MatchType, ReinforcementWave, Respawn,
+    ScenarioConfig, Spawn, SpawnConfig, SpawnZone,
+};
+use battleground_construct::units::tank::UnitTank;
+use battleground_construct::util::cgmath::prelude::*;
+use battleground_construct::Construct;
+use components::team::TeamId;
+
+mod common;
+use common::{match_report, run, setup_scenario, team};
+
+/// The living tanks of the team, with their unit entities.
+fn alive_tanks(construct: &Construct, team: TeamId) -> Vec<UnitTank> {
+    let world = construct.world();
+    world
+        .component_iter::<UnitTank>()
+        .map(|(_, t)| *t)
+        .filter(|t| {
+            world
+                .component::<components::team_member::TeamMember>(t.unit_entity)
+                .map(|m| m.team() == team)
+                .unwrap_or(false)
+                && world
+                    .component::<components::health::Health>(t.unit_entity)
+                    .is_some()
+        })
+        .collect()
+}
+
+fn destroy(construct: &mut Construct, tank: &UnitTank) {
+    construct
+        .world
+        .component_mut::<components::health::Health>(tank.unit_entity)
+        .unwrap()
+        .subtract(1.0);
+}
+
+fn team_ids(construct: &Construct) -> Vec<TeamId> {
+    let mut teams = construct
+        .world()
+        .component_iter::<components::team::Team>()
+        .map(|(_, t)| t.id())
+        .collect::<Vec<_>>();
+    teams.sort();
+    teams
+}
+
+#[test]
+fn test_respawn_until_out_of_lives() {
+    let config = ScenarioConfig {
+        match_config: MatchConfig {
+            mode: MatchType::TeamDeathmatch { point_limit: None },
+            ..Default::default()
+        },
+        spawn_config: SpawnConfig {
+            teams: vec![team("red"), team("blue")],
+            spawns: vec![
+                Spawn {
+                    team: Some(0),
+                    x: 5.0,
+                    ..Default::default()
+                },
+                Spawn {
+                    team: Some(1),
+                    x: -5.0,
+                    ..Default::default()
+                },
+            ],
+            respawn: Some(Respawn {
+                delay: 2.0,
+                lives: Some(2),
+                zones: vec![SpawnZone {
+                    team: Some(0),
+                    x: 10.0,
+                    y: 10.0,
+                    radius: 1.0,
+                    yaw: 0.0,
+                }],
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    let teams = team_ids(&construct);
+    let (red, blue) = (teams[0], teams[1]);
+
+    let original = alive_tanks(&construct, red)[0];
+    destroy(&mut construct, &original);
+    run(&mut construct, 1.0);
+    assert!(alive_tanks(&construct, red).is_empty());
+
+    // After the delay it respawns in the zone of the red team.
+    run(&mut construct, 3.0);
+    let respawned = alive_tanks(&construct, red);
+    assert_eq!(respawned.len(), 1);
+    assert_ne!(respawned[0].unit_entity, original.unit_entity);
+    let position =
+        components::pose::world_pose(construct.world(), respawned[0].base_entity).to_translation();
+    assert!((position.x - 10.0).powi(2) + (position.y - 10.0).powi(2) <= 1.01);
+    assert!(match_report(&construct).is_none());
+
+    // That was the last life, blue is the last team standing.
+    destroy(&mut construct, &respawned[0]);
+    run(&mut construct, 10.0);
+    assert!(alive_tanks(&construct, red).is_empty());
+    assert_eq!(alive_tanks(&construct, blue).len(), 1);
+    let report = match_report(&construct).expect("match should be finished");
+    assert_eq!(report.winner(), Some(blue));
+}
+
+#[test]
+fn test_reinforcement_waves() {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            teams: vec![team("red")],
+            spawns: vec![Spawn {
+                team: Some(0),
+                ..Default::default()
+            }],
+            reinforcements: vec![ReinforcementWave {
+                time: 2.0,
+                repeat: Some(3.0),
+                waves: Some(2),
+                spawns: vec![Spawn {
+                    team: Some(0),
+                    x: 5.0,
+                    ..Default::default()
+                }],
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    let red = team_ids(&construct)[0];
+
+    run(&mut construct, 1.0);
+    assert_eq!(alive_tanks(&construct, red).len(), 1);
+    run(&mut construct, 3.0);
+    assert_eq!(alive_tanks(&construct, red).len(), 2);
+    run(&mut construct, 10.0);
+    assert_eq!(alive_tanks(&construct, red).len(), 3);
+}
+
+#[test]
+fn test_no_last_team_standing_without_respawn() {
+    let config = ScenarioConfig {
+        match_config: MatchConfig {
+            mode: MatchType::KingOfTheHill {
+                capture_points: vec![CapturePoint {
+                    x: 0.0,
+                    y: 0.0,
+                    yaw: 0.0,
+                    radius: 2.0,
+                    capture_speed: 1.0,
+                    team: None,
+                }],
+                point_limit: None,
+            },
+            ..Default::default()
+        },
+        spawn_config: SpawnConfig {
+            teams: vec![team("red"), team("blue")],
+            spawns: vec![
+                Spawn {
+                    team: Some(0),
+                    x: 10.0,
+                    ..Default::default()
+                },
+                Spawn {
+                    team: Some(1),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    let teams = team_ids(&construct);
+    let (red, blue) = (teams[0], teams[1]);
+
+    // Blue holds the capture point for a while and is then wiped out.
+    run(&mut construct, 10.0);
+    let blue_tank = alive_tanks(&construct, blue)[0];
+    destroy(&mut construct, &blue_tank);
+    run(&mut construct, 12.0);
+    assert!(alive_tanks(&construct, blue).is_empty());
+    assert_eq!(alive_tanks(&construct, red).len(), 1);
+
+    // Without respawns the match doesn't end on that, blue still leads on points.
+    assert!(match_report(&construct).is_none());
+    let (_, koth) = construct
+        .world()
+        .component_iter::<components::match_king_of_the_hill::MatchKingOfTheHill>()
+        .next()
+        .unwrap();
+    assert!(koth.last_team_standing().is_none());
+    assert_eq!(koth.get_leader(), Some(blue));
+}
+
+#[test]
+fn test_reinforcement_controller_error_at_setup() {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            reinforcements: vec![ReinforcementWave {
+                time: 2.0,
+                spawns: vec![Spawn {
+                    controller: ControllerType::FromControlConfig {
+                        name: "missing".to_owned(),
+                    },
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert!(battleground_construct::config::setup::setup_scenario(&config).is_err());
+}
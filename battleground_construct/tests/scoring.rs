@@ -0,0 +1,94 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{
+    MatchConfig, ScenarioConfig, Spawn, SpawnConfig,
+};
+use components::match_finished::MatchConclusion;
+use components::scoreboard::{ObjectiveWeight, ScoreObjective, ScoringConfig};
+
+mod common;
+use common::{match_report, setup_scenario, step, tanks, team, team_of};
+
+#[test]
+fn test_weighted_scoring() {
+    let weight = |objective, weight| ObjectiveWeight { objective, weight };
+    let config = ScenarioConfig {
+        match_config: MatchConfig {
+            time_limit: Some(10.0),
+            scoring: Some(ScoringConfig {
+                objectives: vec![
+                    weight(ScoreObjective::Kills, 10.0),
+                    weight(ScoreObjective::DamageDealt, 1.0),
+                    weight(ScoreObjective::Survival, 1.0),
+                ],
+                tie_breakers: vec![],
+                point_limit: Some(11.0),
+            }),
+            ..Default::default()
+        },
+        spawn_config: SpawnConfig {
+            teams: vec![team("red"), team("blue")],
+            spawns: vec![
+                Spawn {
+                    team: Some(0),
+                    x: 5.0,
+                    ..Default::default()
+                },
+                Spawn {
+                    team: Some(0),
+                    x: 5.0,
+                    y: 5.0,
+                    ..Default::default()
+                },
+                Spawn {
+                    team: Some(1),
+                    x: -5.0,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    let tanks = tanks(&construct);
+    let (red_tank, blue_tank) = (tanks[0], tanks[2]);
+    let (red, blue) = (
+        team_of(&construct, red_tank.unit_entity),
+        team_of(&construct, blue_tank.unit_entity),
+    );
+
+    // Red has more units alive and leads, but that is below the point limit.
+    step(&mut construct, 0.5);
+    assert!(match_report(&construct).is_none());
+
+    // Blue destroys a red tank, that puts it over the point limit. The hit is processed in the next
+    // step, so it has the time of that step.
+    let time = construct
+        .world()
+        .component_iter::<components::clock::Clock>()
+        .next()
+        .map(|(_, c)| c.elapsed_as_f32() + c.step_as_f32())
+        .unwrap();
+    let mut hit_by = components::hit_by::HitBy::new();
+    hit_by.add_hit(
+        1.0,
+        components::impact::Impact::new(None, cgmath::Matrix4::from_scale(1.0), None),
+        Some(blue_tank.unit_id),
+        time,
+    );
+    construct.world.add_component(red_tank.unit_entity, hit_by);
+    step(&mut construct, 0.5);
+
+    let report = match_report(&construct).expect("match should be finished");
+    assert!(matches!(report.conclusion, MatchConclusion::Objective));
+    assert_eq!(report.winner(), Some(blue));
+    let (first, second) = (&report.scores[0], &report.scores[1]);
+    assert_eq!((first.team, first.rank), (blue, 1));
+    assert_eq!(first.value(ScoreObjective::Kills), 1.0);
+    assert_eq!(first.value(ScoreObjective::DamageDealt), 1.0);
+    assert_eq!(first.value(ScoreObjective::Survival), 1.0);
+    assert_eq!(first.total, 12.0);
+    assert_eq!((second.team, second.rank), (red, 2));
+    assert_eq!(second.value(ScoreObjective::Survival), 1.0);
+    assert_eq!(second.total, 1.0);
+}
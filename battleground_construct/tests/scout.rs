@@ -0,0 +1,55 @@
+use battleground_construct::components::radar::Radar;
+use battleground_construct::config::specification::{ScenarioConfig, Spawn, SpawnConfig, Unit};
+use battleground_construct::units::scout::UnitScout;
+
+mod common;
+use common::{setup_scenario, tanks};
+
+#[test]
+fn test_scout_radar_outranges_tank() {
+    let config = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            spawns: vec![
+                Spawn {
+                    unit: Unit::Scout,
+                    ..Default::default()
+                },
+                Spawn {
+                    x: 45.0,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    let (_, scout) = construct
+        .world()
+        .component_iter::<UnitScout>()
+        .map(|(e, s)| (e, *s))
+        .next()
+        .unwrap();
+    let tank = tanks(&construct)[0];
+
+    // Both radars rotate, within a few seconds both have swept past the other unit.
+    let mut scout_seen = vec![];
+    let mut tank_seen = vec![];
+    while construct.elapsed_as_f32() < 3.0 {
+        construct.update();
+        let world = construct.world();
+        let distances = |entity| {
+            world
+                .component::<Radar>(entity)
+                .unwrap()
+                .reflections()
+                .iter()
+                .map(|r| r.distance)
+                .collect::<Vec<_>>()
+        };
+        scout_seen.extend(distances(scout.radar_entity));
+        tank_seen.extend(distances(tank.radar_entity));
+    }
+    assert!(scout_seen.iter().any(|d| *d > 40.0), "{scout_seen:?}");
+    assert!(tank_seen.is_empty(), "{tank_seen:?}");
+}
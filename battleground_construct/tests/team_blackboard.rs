@@ -0,0 +1,89 @@
+use battleground_construct::components;
+use battleground_construct::config::specification::{
+    ControllerType, ScenarioConfig, Spawn, SpawnConfig,
+};
+use battleground_unit_control::modules::team_blackboard::*;
+use battleground_unit_control::modules::unit::REG_UNIT_UNIT_ID;
+use battleground_unit_control::units::common::{MODULE_TEAM_BLACKBOARD, MODULE_UNIT};
+use battleground_unit_control::{Interface, UnitControl};
+use components::team_blackboard::{TeamBlackboard, TeamBlackboardConfig};
+
+mod common;
+use common::{run, setup_scenario, tanks, team, team_of};
+
+/// Claims the first byte with its unit id once and sets a marker byte for its unit id.
+#[derive(Default)]
+struct MarkBlackboard {
+    claimed: bool,
+}
+impl UnitControl for MarkBlackboard {
+    fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+        let unit_id = interface.get_i32(MODULE_UNIT, REG_UNIT_UNIT_ID)? as usize;
+        let size = interface.get_i32(MODULE_TEAM_BLACKBOARD, REG_TEAM_BLACKBOARD_SIZE)? as usize;
+        let mut data =
+            vec![0; interface.get_bytes_len(MODULE_TEAM_BLACKBOARD, REG_TEAM_BLACKBOARD_DATA)?];
+        interface.get_bytes(MODULE_TEAM_BLACKBOARD, REG_TEAM_BLACKBOARD_DATA, &mut data)?;
+        assert_eq!(data.len(), size);
+        if !self.claimed {
+            data[0] = unit_id as u8;
+            self.claimed = true;
+        }
+        data[1 + unit_id % (size - 1)] = 1;
+        interface.set_bytes(MODULE_TEAM_BLACKBOARD, REG_TEAM_BLACKBOARD_DATA, &data)?;
+        Ok(())
+    }
+}
+
+fn mark_blackboard() -> Box<dyn UnitControl> {
+    Box::new(MarkBlackboard::default())
+}
+
+#[test]
+fn test_team_blackboard() {
+    let spawn = |team, y| Spawn {
+        team: Some(team),
+        y,
+        controller: ControllerType::Function(mark_blackboard),
+        ..Default::default()
+    };
+    let config = ScenarioConfig {
+        team_blackboard: Some(TeamBlackboardConfig { size: 16 }),
+        spawn_config: SpawnConfig {
+            teams: vec![team("red"), team("blue")],
+            spawns: vec![spawn(0, 0.0), spawn(0, 5.0), spawn(1, -5.0)],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = setup_scenario(&config);
+    run(&mut construct, 1.0);
+
+    // Tanks are in spawn order, so the unit ids are sorted.
+    let tanks = tanks(&construct);
+    let team_units = |team| {
+        tanks
+            .iter()
+            .filter(|t| team_of(&construct, t.unit_entity) == team)
+            .map(|t| t.unit_id.as_u64() as usize)
+            .collect::<Vec<_>>()
+    };
+
+    let blackboards = construct
+        .world()
+        .component_iter::<TeamBlackboard>()
+        .map(|(_, b)| (b.team(), b.data().to_vec()))
+        .collect::<Vec<_>>();
+    assert_eq!(blackboards.len(), 2);
+    for (team, data) in blackboards {
+        let units = team_units(team);
+        assert_eq!(data.len(), 16);
+        // All units claimed the first byte in the same step, the highest unit id wins.
+        assert_eq!(data[0] as usize, *units.last().unwrap());
+        // Writes to different bytes are all kept, the other team's markers are not present.
+        let markers = data[1..].iter().filter(|v| **v == 1).count();
+        assert_eq!(markers, units.len());
+        for unit in units {
+            assert_eq!(data[1 + unit % 15], 1);
+        }
+    }
+}
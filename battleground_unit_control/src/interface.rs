@@ -24,7 +24,68 @@ impl TryFrom<u32> for RegisterType {
     }
 }
 
+/// Value of a register, used by the batched register access.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegisterValue {
+    I32(i32),
+    F32(f32),
+    Bytes(Vec<u8>),
+}
+
+impl RegisterValue {
+    /// The type of this register value.
+    pub fn register_type(&self) -> RegisterType {
+        match self {
+            RegisterValue::I32(_) => RegisterType::I32,
+            RegisterValue::F32(_) => RegisterType::F32,
+            RegisterValue::Bytes(_) => RegisterType::Bytes,
+        }
+    }
+
+    /// Append the value to a buffer, prefixed by the register type. Bytes are further prefixed
+    /// by their length. All integers are little endian.
+    pub fn write_le_bytes(&self, buffer: &mut Vec<u8>) {
+        buffer.extend((self.register_type() as u32).to_le_bytes());
+        match self {
+            RegisterValue::I32(v) => buffer.extend(v.to_le_bytes()),
+            RegisterValue::F32(v) => buffer.extend(v.to_le_bytes()),
+            RegisterValue::Bytes(v) => {
+                buffer.extend((v.len() as u32).to_le_bytes());
+                buffer.extend_from_slice(v);
+            }
+        }
+    }
+
+    /// Read a value written by [`RegisterValue::write_le_bytes`], returns the value and the number
+    /// of bytes consumed, None if the buffer does not hold a valid value.
+    pub fn from_le_bytes(buffer: &[u8]) -> Option<(RegisterValue, usize)> {
+        let register_type: RegisterType = read_u32(buffer, 0)?.try_into().ok()?;
+        match register_type {
+            RegisterType::I32 => Some((RegisterValue::I32(read_u32(buffer, 4)? as i32), 8)),
+            RegisterType::F32 => {
+                Some((RegisterValue::F32(f32::from_bits(read_u32(buffer, 4)?)), 8))
+            }
+            RegisterType::Bytes => {
+                let len = read_u32(buffer, 4)? as usize;
+                let end = len.checked_add(8)?;
+                let data = buffer.get(8..end)?;
+                Some((RegisterValue::Bytes(data.to_vec()), end))
+            }
+        }
+    }
+}
+
+/// Read a little endian u32 at the provided offset.
+pub(crate) fn read_u32(buffer: &[u8], offset: usize) -> Option<u32> {
+    let b = buffer.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes(b.try_into().ok()?))
+}
+
 /// Interface to control the unit, the unit controller uses this to interact with the unit.
+///
+/// The batched methods at the end have a default implementation on top of the single register
+/// access. Interfaces for which each call is expensive, like the one used in wasm, override these
+/// to retrieve all values in a single call.
 pub trait Interface {
     /// Retrieve the list of module ids that are available.
     fn modules(&self) -> Result<Vec<u32>, BoxedError>;
@@ -66,6 +127,54 @@ pub trait Interface {
 
     /// Set a byte register.
     fn set_bytes(&mut self, module: u32, register: u32, values: &[u8]) -> Result<(), BoxedError>;
+
+    /// Get the values of a list of (module, register) pairs, in the order they were requested.
+    fn get_registers(&self, registers: &[(u32, u32)]) -> Result<Vec<RegisterValue>, BoxedError> {
+        registers
+            .iter()
+            .map(|&(module, register)| {
+                Ok(match self.register_type(module, register)? {
+                    RegisterType::I32 => RegisterValue::I32(self.get_i32(module, register)?),
+                    RegisterType::F32 => RegisterValue::F32(self.get_f32(module, register)?),
+                    RegisterType::Bytes => {
+                        let mut v = vec![0u8; self.get_bytes_len(module, register)?];
+                        let len = self.get_bytes(module, register, &mut v)?;
+                        v.truncate(len);
+                        RegisterValue::Bytes(v)
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Get a snapshot of all registers in a module, as (register, value) pairs.
+    fn get_module(&self, module: u32) -> Result<Vec<(u32, RegisterValue)>, BoxedError> {
+        let registers = self.registers(module)?;
+        let pairs = registers.iter().map(|&r| (module, r)).collect::<Vec<_>>();
+        Ok(registers
+            .into_iter()
+            .zip(self.get_registers(&pairs)?)
+            .collect())
+    }
+
+    /// Set a list of (module, register, value) entries, in order. On failure, the entries before
+    /// the failing one have been written.
+    fn set_registers(&mut self, values: &[(u32, u32, RegisterValue)]) -> Result<(), BoxedError> {
+        for (module, register, value) in values.iter() {
+            match value {
+                RegisterValue::I32(v) => {
+                    self.set_i32(*module, *register, *v)?;
+                }
+                RegisterValue::F32(v) => {
+                    self.set_f32(*module, *register, *v)?;
+                }
+                RegisterValue::Bytes(v) => {
+                    self.set_bytes(*module, *register, v)?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// If an error occurs in the interface, the following boxed error is returned.
@@ -225,4 +334,25 @@ mod test {
             (RegisterType::Bytes as u32).try_into().unwrap()
         );
     }
+    #[test]
+    fn test_register_value_bytes() {
+        let mut buffer = vec![];
+        let values = [
+            RegisterValue::I32(-3),
+            RegisterValue::F32(1.5),
+            RegisterValue::Bytes(vec![1, 2, 3]),
+            RegisterValue::Bytes(vec![]),
+        ];
+        for v in values.iter() {
+            v.write_le_bytes(&mut buffer);
+        }
+        let mut offset = 0;
+        for v in values.iter() {
+            let (read, len) = RegisterValue::from_le_bytes(&buffer[offset..]).unwrap();
+            assert_eq!(&read, v);
+            offset += len;
+        }
+        assert_eq!(offset, buffer.len());
+        assert!(RegisterValue::from_le_bytes(&buffer[16..26]).is_none());
+    }
 }
@@ -35,7 +35,16 @@ pub type ControllerSpawn = fn() -> Box<dyn UnitControl>;
 
 /// The interface trait and types.
 pub mod interface;
-pub use interface::{Interface, InterfaceError, RegisterType};
+pub use interface::{Interface, InterfaceError, RegisterType, RegisterValue};
+
+/// Typed wrappers around the modules, built on the [`Interface`].
+pub mod sdk;
+
+/// Tracing of register access and replaying those traces offline.
+pub mod trace;
+
+/// In-memory interface to unit-test controllers.
+pub mod mock;
 
 /// The unit control trait and related types.
 pub mod unit_control;
@@ -0,0 +1,1185 @@
+//! An in-memory [`Interface`] to unit-test controllers without running the construct.
+//!
+//! The [`MockInterface`] holds modules and registers, it can be populated by hand or with the
+//! layout of one of the units through [`MockInterface::for_unit`]. Register values can be scripted
+//! as a function of time, these are applied before every update. All writes made by the controller
+//! are recorded and can be inspected or asserted on afterwards.
+//!
+//! ```
+//! use battleground_unit_control::mock::MockInterface;
+//! use battleground_unit_control::modules::differential_drive::*;
+//! use battleground_unit_control::units::tank::MODULE_TANK_DIFF_DRIVE;
+//! use battleground_unit_control::{Interface, UnitControl};
+//!
+//! struct Forward;
+//! impl UnitControl for Forward {
+//!     fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+//!         interface.set_f32(MODULE_TANK_DIFF_DRIVE, REG_DIFF_DRIVE_LEFT_CMD, 1.0)?;
+//!         interface.set_f32(MODULE_TANK_DIFF_DRIVE, REG_DIFF_DRIVE_RIGHT_CMD, 1.0)?;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut interface = MockInterface::tank();
+//! interface.run(&mut Forward, 0.01, 10).unwrap();
+//! interface.assert_written(MODULE_TANK_DIFF_DRIVE, REG_DIFF_DRIVE_LEFT_CMD, 1.0f32);
+//! ```
+
+use crate::interface::InterfaceErrorType;
+use crate::modules::*;
+use crate::units::{
+    artillery, base_tricycle, common, constructor, missile_launcher, scout, tank, UnitType,
+};
+use crate::{Interface, InterfaceError, RegisterType, UnitControl};
+use std::collections::BTreeMap;
+
+type BoxedError = Box<InterfaceError>;
+
+/// Value held by a register of the mock interface.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockValue {
+    I32(i32),
+    F32(f32),
+    Bytes(Vec<u8>),
+}
+
+impl From<i32> for MockValue {
+    fn from(v: i32) -> Self {
+        MockValue::I32(v)
+    }
+}
+impl From<f32> for MockValue {
+    fn from(v: f32) -> Self {
+        MockValue::F32(v)
+    }
+}
+impl From<bool> for MockValue {
+    fn from(v: bool) -> Self {
+        MockValue::I32(v as i32)
+    }
+}
+impl From<Vec<u8>> for MockValue {
+    fn from(v: Vec<u8>) -> Self {
+        MockValue::Bytes(v)
+    }
+}
+impl From<&[u8]> for MockValue {
+    fn from(v: &[u8]) -> Self {
+        MockValue::Bytes(v.to_vec())
+    }
+}
+
+impl MockValue {
+    fn register_type(&self) -> RegisterType {
+        match self {
+            MockValue::I32(_) => RegisterType::I32,
+            MockValue::F32(_) => RegisterType::F32,
+            MockValue::Bytes(_) => RegisterType::Bytes,
+        }
+    }
+}
+
+/// A write performed by the controller.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockWrite {
+    /// Time of the update in which the write happened.
+    pub time: f32,
+    pub module: u32,
+    pub register: u32,
+    pub value: MockValue,
+}
+
+struct MockRegister {
+    name: String,
+    value: MockValue,
+    /// Maximum length for byte registers.
+    max_len: usize,
+}
+
+struct MockModule {
+    name: String,
+    registers: BTreeMap<u32, MockRegister>,
+}
+
+type Script = Box<dyn Fn(f32) -> MockValue>;
+
+/// In-memory interface with scripted register values and recorded writes.
+#[derive(Default)]
+pub struct MockInterface {
+    modules: BTreeMap<u32, MockModule>,
+    scripts: Vec<(u32, u32, Script)>,
+    time: f32,
+    writes: Vec<MockWrite>,
+}
+
+impl MockInterface {
+    /// Create an empty interface, without any modules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an interface with the module layout of the provided unit type.
+    pub fn for_unit(unit_type: UnitType) -> Self {
+        let mut interface = MockInterface::new();
+        interface.add_common(unit_type);
+        match unit_type {
+            UnitType::Tank => interface.add_tank(),
+            UnitType::Artillery => interface.add_artillery(),
+            UnitType::Constructor => interface.add_constructor(),
+            UnitType::MissileLauncher => interface.add_missile_launcher(),
+            UnitType::Scout => interface.add_scout(),
+            UnitType::Unknown => {}
+        }
+        interface
+    }
+
+    /// Interface with the module layout of the tank.
+    pub fn tank() -> Self {
+        Self::for_unit(UnitType::Tank)
+    }
+
+    /// Interface with the module layout of the artillery.
+    pub fn artillery() -> Self {
+        Self::for_unit(UnitType::Artillery)
+    }
+
+    /// Interface with the module layout of the constructor.
+    pub fn constructor() -> Self {
+        Self::for_unit(UnitType::Constructor)
+    }
+
+    /// Interface with the module layout of the missile launcher.
+    pub fn missile_launcher() -> Self {
+        Self::for_unit(UnitType::MissileLauncher)
+    }
+
+    /// Interface with the module layout of the scout.
+    pub fn scout() -> Self {
+        Self::for_unit(UnitType::Scout)
+    }
+
+    /// Add a module, replacing an existing module with the same id.
+    pub fn add_module(&mut self, module: u32, name: &str) -> &mut Self {
+        self.modules.insert(
+            module,
+            MockModule {
+                name: name.to_owned(),
+                registers: Default::default(),
+            },
+        );
+        self
+    }
+
+    /// Add a register to a module, panics if the module does not exist.
+    pub fn add_register(
+        &mut self,
+        module: u32,
+        register: u32,
+        name: &str,
+        value: impl Into<MockValue>,
+    ) -> &mut Self {
+        self.add_register_max_len(module, register, name, value, usize::MAX)
+    }
+
+    /// Add a byte register that holds at most `max_len` bytes.
+    pub fn add_register_max_len(
+        &mut self,
+        module: u32,
+        register: u32,
+        name: &str,
+        value: impl Into<MockValue>,
+        max_len: usize,
+    ) -> &mut Self {
+        self.modules
+            .get_mut(&module)
+            .unwrap_or_else(|| panic!("module {module:0>8x} does not exist"))
+            .registers
+            .insert(
+                register,
+                MockRegister {
+                    name: name.to_owned(),
+                    value: value.into(),
+                    max_len,
+                },
+            );
+        self
+    }
+
+    /// Remove a register from a module, if it exists.
+    pub fn remove_register(&mut self, module: u32, register: u32) -> &mut Self {
+        if let Some(m) = self.modules.get_mut(&module) {
+            m.registers.remove(&register);
+        }
+        self
+    }
+
+    /// Set a register's value, without recording it as a write. Panics if the register does not
+    /// exist or the type differs.
+    pub fn set_value(&mut self, module: u32, register: u32, value: impl Into<MockValue>) {
+        let value = value.into();
+        let r = self
+            .modules
+            .get_mut(&module)
+            .and_then(|m| m.registers.get_mut(&register))
+            .unwrap_or_else(|| panic!("register {module:0>8x}:{register:0>8x} does not exist"));
+        assert_eq!(
+            r.value.register_type(),
+            value.register_type(),
+            "register {module:0>8x}:{register:0>8x} is of another type"
+        );
+        r.value = value;
+    }
+
+    /// The current value of a register.
+    pub fn value(&self, module: u32, register: u32) -> Option<&MockValue> {
+        self.modules
+            .get(&module)
+            .and_then(|m| m.registers.get(&register))
+            .map(|r| &r.value)
+    }
+
+    /// Script a register's value as a function of time, applied before each update.
+    pub fn script<V: Into<MockValue>>(
+        &mut self,
+        module: u32,
+        register: u32,
+        f: impl Fn(f32) -> V + 'static,
+    ) -> &mut Self {
+        self.scripts
+            .push((module, register, Box::new(move |t| f(t).into())));
+        self
+    }
+
+    /// The time of the next update.
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Set the time of the next update.
+    pub fn set_time(&mut self, time: f32) {
+        self.time = time;
+    }
+
+    /// Perform a single update at the current time; sets the clock, applies the scripts and then
+    /// calls the controller.
+    pub fn update(
+        &mut self,
+        controller: &mut dyn UnitControl,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self
+            .value(common::MODULE_CLOCK, clock::REG_CLOCK_ELAPSED)
+            .is_some()
+        {
+            self.set_value(common::MODULE_CLOCK, clock::REG_CLOCK_ELAPSED, self.time);
+        }
+        let scripts = std::mem::take(&mut self.scripts);
+        for (module, register, f) in scripts.iter() {
+            self.set_value(*module, *register, f(self.time));
+        }
+        self.scripts = scripts;
+        controller.update(self)
+    }
+
+    /// Perform a number of updates, advancing the time by `dt` after each one.
+    pub fn run(
+        &mut self,
+        controller: &mut dyn UnitControl,
+        dt: f32,
+        updates: usize,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        for _ in 0..updates {
+            self.update(controller)?;
+            self.time += dt;
+        }
+        Ok(())
+    }
+
+    /// All writes recorded so far, in order.
+    pub fn writes(&self) -> &[MockWrite] {
+        &self.writes
+    }
+
+    /// The writes recorded for a particular register, in order.
+    pub fn writes_to(&self, module: u32, register: u32) -> Vec<&MockWrite> {
+        self.writes
+            .iter()
+            .filter(|w| w.module == module && w.register == register)
+            .collect()
+    }
+
+    /// The value last written to a register.
+    pub fn last_write(&self, module: u32, register: u32) -> Option<&MockValue> {
+        self.writes
+            .iter()
+            .rev()
+            .find(|w| w.module == module && w.register == register)
+            .map(|w| &w.value)
+    }
+
+    /// Forget all recorded writes.
+    pub fn clear_writes(&mut self) {
+        self.writes.clear();
+    }
+
+    /// Panics if the last write to this register differs from the expected value.
+    #[track_caller]
+    pub fn assert_written(&self, module: u32, register: u32, expected: impl Into<MockValue>) {
+        let expected = expected.into();
+        match self.last_write(module, register) {
+            Some(v) => assert_eq!(
+                v, &expected,
+                "last write to {module:0>8x}:{register:0>8x} differs"
+            ),
+            None => {
+                panic!("{module:0>8x}:{register:0>8x} was never written, expected {expected:?}")
+            }
+        }
+    }
+
+    /// Panics if this register was written.
+    #[track_caller]
+    pub fn assert_not_written(&self, module: u32, register: u32) {
+        if let Some(v) = self.last_write(module, register) {
+            panic!("{module:0>8x}:{register:0>8x} was written with {v:?}");
+        }
+    }
+
+    /// Populate the reflections of a radar module.
+    pub fn set_radar_reflections(
+        &mut self,
+        module: u32,
+        reflections: &[crate::sdk::radar::Reflection],
+    ) {
+        use radar::*;
+        let old = self.list_len(module, REG_RADAR_REFLECTION_COUNT);
+        for i in 0..old {
+            for offset in 0..REG_RADAR_REFLECTION_STRIDE {
+                self.remove_register(
+                    module,
+                    REG_RADAR_REFLECTION_START + i * REG_RADAR_REFLECTION_STRIDE + offset,
+                );
+            }
+        }
+        self.set_value(module, REG_RADAR_REFLECTION_COUNT, reflections.len() as i32);
+        for (i, r) in reflections.iter().enumerate() {
+            let base = REG_RADAR_REFLECTION_START + i as u32 * REG_RADAR_REFLECTION_STRIDE;
+            self.add_register(module, base + REG_RADAR_REFLECTION_OFFSET_YAW, "yaw", r.yaw);
+            self.add_register(
+                module,
+                base + REG_RADAR_REFLECTION_OFFSET_PITCH,
+                "pitch",
+                r.pitch,
+            );
+            self.add_register(
+                module,
+                base + REG_RADAR_REFLECTION_OFFSET_DISTANCE,
+                "distance",
+                r.distance,
+            );
+            self.add_register(
+                module,
+                base + REG_RADAR_REFLECTION_OFFSET_STRENGTH,
+                "strength",
+                r.strength,
+            );
+        }
+    }
+
+    /// Populate the received messages of a radio receiver module.
+    pub fn set_radio_messages(
+        &mut self,
+        module: u32,
+        messages: &[crate::sdk::radio_receiver::Message],
+    ) {
+        use radio_receiver::*;
+        let old = self.list_len(module, REG_RADIO_RX_MSG_COUNT);
+        for i in 0..old {
+            for offset in 0..REG_RADIO_RX_MSG_STRIDE {
+                self.remove_register(
+                    module,
+                    REG_RADIO_RX_MSG_START + i * REG_RADIO_RX_MSG_STRIDE + offset,
+                );
+            }
+        }
+        self.set_value(module, REG_RADIO_RX_MSG_COUNT, messages.len() as i32);
+        for (i, m) in messages.iter().enumerate() {
+            let base = REG_RADIO_RX_MSG_START + i as u32 * REG_RADIO_RX_MSG_STRIDE;
+            self.add_register(
+                module,
+                base + REG_RADIO_RX_MSG_OFFSET_STRENGTH,
+                "message_strength",
+                m.strength,
+            );
+            self.add_register(
+                module,
+                base + REG_RADIO_RX_MSG_OFFSET_DATA,
+                "message_data",
+                m.data.clone(),
+            );
+        }
+    }
+
+    fn list_len(&self, module: u32, register: u32) -> u32 {
+        match self.value(module, register) {
+            Some(MockValue::I32(v)) => (*v).max(0) as u32,
+            _ => 0,
+        }
+    }
+
+    fn add_common(&mut self, unit_type: UnitType) {
+        use common::*;
+
+        self.add_module(MODULE_CLOCK, "clock").add_register(
+            MODULE_CLOCK,
+            clock::REG_CLOCK_ELAPSED,
+            "elapsed",
+            0.0f32,
+        );
+
+        use controller::*;
+        self.add_module(MODULE_CONTROLLER, "controller")
+            .add_register(
+                MODULE_CONTROLLER,
+                REG_CONTROLLER_UPDATE_INTERVAL,
+                "update_interval",
+                0.01f32,
+            )
+            .add_register(
+                MODULE_CONTROLLER,
+                REG_CONTROLLER_WASM_CPU_FUEL_ENABLED,
+                "wasm_cpu_fuel_enabled",
+                false,
+            )
+            .add_register(
+                MODULE_CONTROLLER,
+                REG_CONTROLLER_WASM_CPU_FUEL_LEFT,
+                "wasm_cpu_fuel_left",
+                i32::MAX,
+            );
+
+        self.add_module(MODULE_OBJECTIVES, "objectives")
+            .add_register(
+                MODULE_OBJECTIVES,
+                objectives::REG_OBJECTIVES_CAPTURE_POINT_COUNT,
+                "capture_point_count",
+                0,
+            )
+            .add_register(
+                MODULE_OBJECTIVES,
+                objectives::REG_OBJECTIVES_FLAG_COUNT,
+                "flag_count",
+                0,
+            )
+            .add_register(
+                MODULE_OBJECTIVES,
+                objectives::REG_OBJECTIVES_PAYLOAD_COUNT,
+                "payload_count",
+                0,
+            );
+
+        self.add_module(MODULE_TEAM, "team").add_register(
+            MODULE_TEAM,
+            team::REG_TEAM_TEAMID,
+            "team",
+            0,
+        );
+
+        self.add_module(MODULE_UNIT, "unit")
+            .add_register(MODULE_UNIT, unit::REG_UNIT_UNIT_ID, "unit_id", 1)
+            .add_register(
+                MODULE_UNIT,
+                unit::REG_UNIT_UNIT_TYPE,
+                "unit_type",
+                unit_type as i32,
+            )
+            .add_register(MODULE_UNIT, unit::REG_UNIT_HEALTH, "health", 1.0f32)
+            .add_register(
+                MODULE_UNIT,
+                unit::REG_UNIT_SUBSYSTEM_TRACKS,
+                "subsystem_tracks",
+                1.0f32,
+            )
+            .add_register(
+                MODULE_UNIT,
+                unit::REG_UNIT_SUBSYSTEM_RADAR,
+                "subsystem_radar",
+                1.0f32,
+            )
+            .add_register(
+                MODULE_UNIT,
+                unit::REG_UNIT_SUBSYSTEM_WEAPON,
+                "subsystem_weapon",
+                1.0f32,
+            );
+
+        use gps::*;
+        self.add_module(MODULE_GPS, "localization");
+        for (register, name) in [
+            (REG_GPS_X, "x"),
+            (REG_GPS_Y, "y"),
+            (REG_GPS_Z, "z"),
+            (REG_GPS_ROLL, "r"),
+            (REG_GPS_PITCH, "p"),
+            (REG_GPS_YAW, "y"),
+        ] {
+            self.add_register(MODULE_GPS, register, name, 0.0f32);
+        }
+        self.add_register(MODULE_GPS, REG_GPS_VALID, "valid", true)
+            .add_register(MODULE_GPS, REG_GPS_FIX_TIME, "fix_time", 0.0f32);
+
+        use imu::*;
+        self.add_module(MODULE_IMU, "imu")
+            .add_register(MODULE_IMU, REG_IMU_ROLL_RATE, "roll_rate", 0.0f32)
+            .add_register(MODULE_IMU, REG_IMU_PITCH_RATE, "pitch_rate", 0.0f32)
+            .add_register(MODULE_IMU, REG_IMU_YAW_RATE, "yaw_rate", 0.0f32);
+
+        self.add_module(MODULE_DRAW, "draw").add_register(
+            MODULE_DRAW,
+            draw::REG_DRAW_LINES,
+            "instructions",
+            vec![],
+        );
+
+        use radio_transmitter::*;
+        const TX_MESSAGE_SIZE_LIMIT: i32 = 32;
+        const TX_MESSAGE_COUNT_LIMIT: i32 = 16;
+        let m = MODULE_RADIO_TRANSMITTER;
+        self.add_module(m, "radio_transmitter")
+            .add_register(m, REG_RADIO_TX_RANGE_MAX, "transmit_range_max", 40.0f32)
+            .add_register(m, REG_RADIO_TX_INTERVAL, "transmit_interval", 0.01f32)
+            .add_register(
+                m,
+                REG_RADIO_TX_MSG_SIZE_LIMIT,
+                "message_size_limit",
+                TX_MESSAGE_SIZE_LIMIT,
+            )
+            .add_register(
+                m,
+                REG_RADIO_TX_MSG_COUNT_LIMIT,
+                "message_count_limit",
+                TX_MESSAGE_COUNT_LIMIT,
+            )
+            .add_register(m, REG_RADIO_TX_CHANNEL_MIN, "channel_min", 0)
+            .add_register(m, REG_RADIO_TX_CHANNEL_MAX, "channel_max", 4)
+            .add_register(m, REG_RADIO_TX_CHANNEL_SELECT, "channel_select", 0)
+            .add_register(m, REG_RADIO_TX_MSG_COUNT, "payload_count", 0);
+        for i in 0..TX_MESSAGE_COUNT_LIMIT as u32 {
+            self.add_register_max_len(
+                m,
+                REG_RADIO_TX_MSG_START + i,
+                "message",
+                vec![],
+                TX_MESSAGE_SIZE_LIMIT as usize,
+            );
+        }
+
+        use radio_receiver::*;
+        let m = MODULE_RADIO_RECEIVER;
+        self.add_module(m, "radio_receiver")
+            .add_register(m, REG_RADIO_RX_CHANNEL_MIN, "channel_min", 0)
+            .add_register(m, REG_RADIO_RX_CHANNEL_MAX, "channel_max", 4)
+            .add_register(m, REG_RADIO_RX_MSG_COUNT_LIMIT, "message_count_limit", 64)
+            .add_register(m, REG_RADIO_RX_CHANNEL_SELECT, "channel_select", 0)
+            .add_register(m, REG_RADIO_RX_MSG_OVERFLOW, "message_overflow", 0)
+            .add_register(m, REG_RADIO_RX_MSG_COUNT, "message_count", 0);
+
+        use bumper::*;
+        self.add_module(MODULE_BUMPER, "bumper")
+            .add_register(MODULE_BUMPER, REG_BUMPER_IN_CONTACT, "in_contact", false)
+            .add_register(MODULE_BUMPER, REG_BUMPER_CONTACT_COUNT, "contact_count", 0)
+            .add_register(MODULE_BUMPER, REG_BUMPER_DIRECTION_X, "direction_x", 0.0f32)
+            .add_register(MODULE_BUMPER, REG_BUMPER_DIRECTION_Y, "direction_y", 0.0f32);
+    }
+
+    fn add_odometry(&mut self) {
+        use odometry::*;
+        let m = common::MODULE_ODOMETRY;
+        self.add_module(m, "odometry")
+            .add_register(m, REG_ODOMETRY_LEFT_TICKS, "left_ticks", 0)
+            .add_register(m, REG_ODOMETRY_RIGHT_TICKS, "right_ticks", 0)
+            .add_register(
+                m,
+                REG_ODOMETRY_TICKS_PER_METER,
+                "ticks_per_meter",
+                1000.0f32,
+            );
+    }
+
+    fn add_diff_drive(&mut self, module: u32) {
+        self.add_odometry();
+        use differential_drive::*;
+        self.add_module(module, "diff_drive")
+            .add_register(module, REG_DIFF_DRIVE_LEFT_VEL, "left_wheel_vel", 0.0f32)
+            .add_register(module, REG_DIFF_DRIVE_RIGHT_VEL, "right_wheel_vel", 0.0f32)
+            .add_register(module, REG_DIFF_DRIVE_LEFT_CMD, "left_wheel_cmd", 0.0f32)
+            .add_register(module, REG_DIFF_DRIVE_RIGHT_CMD, "right_wheel_cmd", 0.0f32)
+            .add_register(module, REG_DIFF_DRIVE_TRACK_WIDTH, "track_width", 1.0f32)
+            .add_register(
+                module,
+                REG_DIFF_DRIVE_ACCELERATION_LOWER,
+                "acceleration_lower",
+                -0.5f32,
+            )
+            .add_register(
+                module,
+                REG_DIFF_DRIVE_ACCELERATION_UPPER,
+                "acceleration_upper",
+                0.5f32,
+            );
+    }
+
+    fn add_revolute(&mut self, module: u32, name: &str, acceleration_max: f32) {
+        use revolute::*;
+        // The acceleration bounds share their registers with the velocity bounds, the units
+        // report the acceleration bounds in those.
+        self.add_module(module, name)
+            .add_register(module, REG_REVOLUTE_POSITION, "position", 0.0f32)
+            .add_register(module, REG_REVOLUTE_VELOCITY, "velocity", 0.0f32)
+            .add_register(
+                module,
+                REG_REVOLUTE_ACCELERATION_LOWER,
+                "acceleration_lower",
+                -acceleration_max,
+            )
+            .add_register(
+                module,
+                REG_REVOLUTE_ACCELERATION_UPPER,
+                "acceleration_upper",
+                acceleration_max,
+            )
+            .add_register(module, REG_REVOLUTE_VELOCITY_CMD, "velocity_cmd", 0.0f32);
+    }
+
+    fn add_radar(&mut self, module: u32, range_max: f32, detection_angle_yaw: f32) {
+        use radar::*;
+        self.add_module(module, "radar")
+            .add_register(module, REG_RADAR_RANGE_MAX, "range_max", range_max)
+            .add_register(
+                module,
+                REG_RADAR_DETECTION_ANGLE_YAW,
+                "detection_angle_yaw",
+                detection_angle_yaw.to_radians(),
+            )
+            .add_register(
+                module,
+                REG_RADAR_DETECTION_ANGLE_PITCH,
+                "detection_angle_pitch",
+                180.0f32.to_radians(),
+            )
+            .add_register(module, REG_RADAR_REFLECTION_COUNT, "reflections", 0);
+    }
+
+    fn add_tank(&mut self) {
+        use tank::*;
+        self.add_diff_drive(MODULE_TANK_DIFF_DRIVE);
+        self.add_revolute(MODULE_TANK_REVOLUTE_TURRET, "turret", 1.0);
+        self.add_revolute(MODULE_TANK_REVOLUTE_BARREL, "barrel", 1.0);
+        self.add_revolute(
+            MODULE_TANK_REVOLUTE_RADAR,
+            "radar_rotation",
+            std::f32::consts::PI * 2.0,
+        );
+        self.add_radar(MODULE_TANK_RADAR, 70.0, 10.0);
+
+        use cannon::*;
+        let m = MODULE_TANK_CANNON;
+        self.add_module(m, "cannon")
+            .add_register(m, REG_CANNON_TRIGGER, "trigger", false)
+            .add_register(m, REG_CANNON_IS_TRIGGERED, "is_triggered", false)
+            .add_register(m, REG_CANNON_READY, "ready", true)
+            .add_register(m, REG_CANNON_RELOAD_TIME, "reload_time", 2.0f32)
+            .add_register(m, REG_CANNON_AMMUNITION, "ammunition", -1)
+            .add_register(m, REG_CANNON_AMMUNITION_CAPACITY, "ammunition_capacity", -1);
+    }
+
+    fn add_artillery(&mut self) {
+        use artillery::*;
+        self.add_diff_drive(MODULE_ARTILLERY_DIFF_DRIVE);
+        self.add_revolute(MODULE_ARTILLERY_REVOLUTE_TURRET, "turret", 1.0);
+        self.add_revolute(MODULE_ARTILLERY_REVOLUTE_BARREL, "barrel", 1.0);
+        self.add_revolute(
+            MODULE_ARTILLERY_REVOLUTE_RADAR,
+            "radar_rotation",
+            std::f32::consts::PI * 2.0,
+        );
+        self.add_radar(MODULE_ARTILLERY_RADAR, 70.0, 10.0);
+
+        use gun_battery::*;
+        const GUN_COUNT: u32 = 16;
+        let m = MODULE_ARTILLERY_GUN_BATTERY;
+        self.add_module(m, "gun_battery")
+            .add_register(m, REG_GUN_BATTERY_FIRING, "firing", false)
+            .add_register(m, REG_GUN_BATTERY_IS_TRIGGERED, "is_triggered", false)
+            .add_register(m, REG_GUN_BATTERY_READY, "ready", true)
+            .add_register(m, REG_GUN_BATTERY_GUN_RELOAD, "gun_reload", 0.0f32)
+            .add_register(
+                m,
+                REG_GUN_BATTERY_INTER_GUN_DURATION,
+                "inter_gun_duration",
+                0.1f32,
+            )
+            .add_register(m, REG_GUN_BATTERY_RELOAD, "battery_reload", 10.0f32)
+            .add_register(m, REG_GUN_BATTERY_FIRE_INDEX, "fire_index", 0)
+            .add_register(m, REG_GUN_BATTERY_AMMUNITION, "ammunition", -1)
+            .add_register(
+                m,
+                REG_GUN_BATTERY_AMMUNITION_CAPACITY,
+                "ammunition_capacity",
+                -1,
+            )
+            .add_register(m, REG_GUN_BATTERY_COUNT, "gun_count", GUN_COUNT as i32);
+        for i in 0..GUN_COUNT {
+            let base = REG_GUN_BATTERY_START + i * REG_GUN_BATTERY_STRIDE;
+            for (offset, name) in [
+                (REG_GUN_BATTERY_OFFSET_X, "x"),
+                (REG_GUN_BATTERY_OFFSET_Y, "y"),
+                (REG_GUN_BATTERY_OFFSET_Z, "z"),
+                (REG_GUN_BATTERY_OFFSET_ROLL, "roll"),
+                (REG_GUN_BATTERY_OFFSET_PITCH, "pitch"),
+                (REG_GUN_BATTERY_OFFSET_YAW, "yaw"),
+                (REG_GUN_BATTERY_OFFSET_LAST_FIRE_TIME, "last_fire_time"),
+            ] {
+                self.add_register(m, base + offset, name, 0.0f32);
+            }
+            self.add_register(m, base + REG_GUN_BATTERY_OFFSET_READY, "ready", true);
+        }
+    }
+
+    fn add_base_tricycle(&mut self) {
+        use base_tricycle::*;
+        use differential_drive::*;
+        let m = MODULE_BASE_TRICYCLE_DRIVE;
+        self.add_module(m, "tricycle")
+            .add_register(m, REG_DIFF_DRIVE_LEFT_VEL, "wheel_vel", 0.0f32)
+            .add_register(m, REG_DIFF_DRIVE_LEFT_CMD, "wheel_cmd", 0.0f32)
+            .add_register(m, REG_DIFF_DRIVE_TRACK_WIDTH, "wheel_base", 1.0f32)
+            .add_register(
+                m,
+                REG_DIFF_DRIVE_ACCELERATION_LOWER,
+                "acceleration_lower",
+                -0.5f32,
+            )
+            .add_register(
+                m,
+                REG_DIFF_DRIVE_ACCELERATION_UPPER,
+                "acceleration_upper",
+                0.5f32,
+            );
+        self.add_revolute(MODULE_BASE_TRICYCLE_REVOLUTE_STEER, "steer", 1.0);
+        self.add_odometry();
+    }
+
+    fn add_scout(&mut self) {
+        use scout::*;
+        self.add_base_tricycle();
+        self.add_revolute(
+            MODULE_SCOUT_REVOLUTE_RADAR,
+            "radar_rotation",
+            std::f32::consts::PI * 2.0,
+        );
+        self.add_radar(MODULE_SCOUT_RADAR, 60.0, 40.0);
+    }
+
+    fn add_missile_launcher(&mut self) {
+        use missile_launcher::*;
+        self.add_base_tricycle();
+        self.add_revolute(MODULE_MISSILE_LAUNCHER_REVOLUTE_YAW, "launcher", 1.0);
+
+        use cannon::*;
+        let m = MODULE_MISSILE_LAUNCHER_CANNON;
+        self.add_module(m, "cannon")
+            .add_register(m, REG_CANNON_TRIGGER, "trigger", false)
+            .add_register(m, REG_CANNON_IS_TRIGGERED, "is_triggered", false)
+            .add_register(m, REG_CANNON_READY, "ready", true)
+            .add_register(m, REG_CANNON_RELOAD_TIME, "reload_time", 5.0f32)
+            .add_register(m, REG_CANNON_AMMUNITION, "ammunition", -1)
+            .add_register(m, REG_CANNON_AMMUNITION_CAPACITY, "ammunition_capacity", -1);
+
+        use missile_datalink::*;
+        const SLOT_COUNT: u32 = 2;
+        let m = MODULE_MISSILE_LAUNCHER_DATALINK;
+        self.add_module(m, "datalink")
+            .add_register(m, REG_MISSILE_DATALINK_SPEED, "speed", 8.0f32)
+            .add_register(m, REG_MISSILE_DATALINK_TURN_RATE, "turn_rate", 1.5f32)
+            .add_register(m, REG_MISSILE_DATALINK_FUEL, "fuel", 6.0f32)
+            .add_register(m, REG_MISSILE_DATALINK_COUNT, "count", SLOT_COUNT as i32);
+        for i in 0..SLOT_COUNT {
+            let base = REG_MISSILE_DATALINK_START + i * REG_MISSILE_DATALINK_STRIDE;
+            self.add_register(
+                m,
+                base + REG_MISSILE_DATALINK_OFFSET_ACTIVE,
+                "active",
+                false,
+            );
+            self.add_register(
+                m,
+                base + REG_MISSILE_DATALINK_OFFSET_MODE,
+                "mode",
+                MISSILE_GUIDANCE_NONE,
+            );
+            for (offset, name) in [
+                (REG_MISSILE_DATALINK_OFFSET_X, "x"),
+                (REG_MISSILE_DATALINK_OFFSET_Y, "y"),
+                (REG_MISSILE_DATALINK_OFFSET_Z, "z"),
+                (REG_MISSILE_DATALINK_OFFSET_YAW, "yaw"),
+                (REG_MISSILE_DATALINK_OFFSET_PITCH, "pitch"),
+                (REG_MISSILE_DATALINK_OFFSET_FUEL, "fuel"),
+                (REG_MISSILE_DATALINK_OFFSET_TARGET_X, "target_x"),
+                (REG_MISSILE_DATALINK_OFFSET_TARGET_Y, "target_y"),
+                (REG_MISSILE_DATALINK_OFFSET_TARGET_Z, "target_z"),
+                (REG_MISSILE_DATALINK_OFFSET_TARGET_YAW, "target_yaw"),
+                (REG_MISSILE_DATALINK_OFFSET_TARGET_PITCH, "target_pitch"),
+            ] {
+                self.add_register(m, base + offset, name, 0.0f32);
+            }
+        }
+    }
+
+    fn add_constructor(&mut self) {
+        use constructor::*;
+        self.add_base_tricycle();
+
+        use deploy::*;
+        let m = common::MODULE_DEPLOY;
+        self.add_module(m, "deploy")
+            .add_register(m, REG_DEPLOY_FINISHED, "finished", false)
+            .add_register(
+                m,
+                REG_DEPLOY_DESIRED_STATE,
+                "desired_state",
+                DEPLOY_STATE_NORMAL,
+            )
+            .add_register(m, REG_DEPLOY_STATE, "state", DEPLOY_STATE_NORMAL);
+
+        use crate::modules::constructor::*;
+        let m = MODULE_CONSTRUCTOR_BUILD;
+        self.add_module(m, "constructor")
+            .add_register(m, REG_CONSTRUCTOR_BUILD, "build", STRUCTURE_NONE)
+            .add_register(m, REG_CONSTRUCTOR_BUILD_PROGRESS, "build_progress", 0.0f32)
+            .add_register(
+                m,
+                REG_CONSTRUCTOR_BUILD_STATUS,
+                "build_status",
+                BUILD_STATUS_IDLE,
+            )
+            .add_register(m, REG_CONSTRUCTOR_BUDGET, "budget", -1.0f32)
+            .add_register(m, REG_CONSTRUCTOR_STRUCTURE_COUNT, "structure_count", 3);
+        // Default costs and build times of the construct.
+        for (structure, cost, build_time) in [
+            (STRUCTURE_WALL, 10.0f32, 5.0f32),
+            (STRUCTURE_RADAR_TOWER, 30.0, 10.0),
+            (STRUCTURE_REPAIR_STATION, 50.0, 15.0),
+        ] {
+            let start = REG_CONSTRUCTOR_STRUCTURE_START
+                + structure as u32 * REG_CONSTRUCTOR_STRUCTURE_STRIDE;
+            self.add_register(
+                m,
+                start + REG_CONSTRUCTOR_STRUCTURE_OFFSET_COST,
+                "cost",
+                cost,
+            )
+            .add_register(
+                m,
+                start + REG_CONSTRUCTOR_STRUCTURE_OFFSET_BUILD_TIME,
+                "build_time",
+                build_time,
+            );
+        }
+
+        use crate::modules::mine_layer::*;
+        let m = MODULE_CONSTRUCTOR_MINE_LAYER;
+        self.add_module(m, "mine_layer")
+            .add_register(m, REG_MINE_LAYER_DROP, "drop", false)
+            .add_register(m, REG_MINE_LAYER_STATUS, "status", MINE_LAYER_STATUS_IDLE)
+            .add_register(m, REG_MINE_LAYER_MINES, "mines", 5)
+            .add_register(m, REG_MINE_LAYER_CAPACITY, "capacity", 5)
+            .add_register(m, REG_MINE_LAYER_COST, "cost", 5.0f32)
+            .add_register(m, REG_MINE_LAYER_DROP_INTERVAL, "drop_interval", 1.0f32)
+            .add_register(m, REG_MINE_LAYER_ARM_TIME, "arm_time", 3.0f32)
+            .add_register(m, REG_MINE_LAYER_TRIGGER_RADIUS, "trigger_radius", 1.0f32)
+            .add_register(m, REG_MINE_LAYER_DETECTION_RANGE, "detection_range", 4.0f32);
+
+        use crate::modules::tower_radar::*;
+        let m = MODULE_CONSTRUCTOR_TOWER_RADAR;
+        self.add_module(m, "tower_radar").add_register(
+            m,
+            REG_TOWER_RADAR_PRESENT,
+            "present",
+            false,
+        );
+    }
+
+    fn register(&self, module: u32, register: u32) -> Result<&MockRegister, BoxedError> {
+        self.modules
+            .get(&module)
+            .ok_or_else(|| error(module, register, InterfaceErrorType::NoSuchModule))?
+            .registers
+            .get(&register)
+            .ok_or_else(|| error(module, register, InterfaceErrorType::NoSuchRegister))
+    }
+
+    fn write(&mut self, module: u32, register: u32, value: MockValue) -> MockValue {
+        self.writes.push(MockWrite {
+            time: self.time,
+            module,
+            register,
+            value: value.clone(),
+        });
+        let r = self
+            .modules
+            .get_mut(&module)
+            .and_then(|m| m.registers.get_mut(&register))
+            .expect("register checked before write");
+        std::mem::replace(&mut r.value, value)
+    }
+}
+
+fn error(module: u32, register: u32, error_type: InterfaceErrorType) -> BoxedError {
+    Box::new(InterfaceError {
+        module,
+        register,
+        error_type,
+    })
+}
+
+impl Interface for MockInterface {
+    fn modules(&self) -> Result<Vec<u32>, BoxedError> {
+        Ok(self.modules.keys().copied().collect())
+    }
+
+    fn module_name(&self, module: u32) -> Result<String, BoxedError> {
+        self.modules
+            .get(&module)
+            .map(|m| m.name.clone())
+            .ok_or_else(|| error(module, 0, InterfaceErrorType::NoSuchModule))
+    }
+
+    fn registers(&self, module: u32) -> Result<Vec<u32>, BoxedError> {
+        self.modules
+            .get(&module)
+            .map(|m| m.registers.keys().copied().collect())
+            .ok_or_else(|| error(module, 0, InterfaceErrorType::NoSuchModule))
+    }
+
+    fn register_name(&self, module: u32, register: u32) -> Result<String, BoxedError> {
+        Ok(self.register(module, register)?.name.clone())
+    }
+
+    fn register_type(&self, module: u32, register: u32) -> Result<RegisterType, BoxedError> {
+        Ok(self.register(module, register)?.value.register_type())
+    }
+
+    fn get_i32(&self, module: u32, register: u32) -> Result<i32, BoxedError> {
+        match self.register(module, register)?.value {
+            MockValue::I32(v) => Ok(v),
+            _ => Err(error(module, register, InterfaceErrorType::WrongType)),
+        }
+    }
+
+    fn set_i32(&mut self, module: u32, register: u32, value: i32) -> Result<i32, BoxedError> {
+        let old = self.get_i32(module, register)?;
+        self.write(module, register, MockValue::I32(value));
+        Ok(old)
+    }
+
+    fn get_f32(&self, module: u32, register: u32) -> Result<f32, BoxedError> {
+        match self.register(module, register)?.value {
+            MockValue::F32(v) => Ok(v),
+            _ => Err(error(module, register, InterfaceErrorType::WrongType)),
+        }
+    }
+
+    fn set_f32(&mut self, module: u32, register: u32, value: f32) -> Result<f32, BoxedError> {
+        let old = self.get_f32(module, register)?;
+        self.write(module, register, MockValue::F32(value));
+        Ok(old)
+    }
+
+    fn get_bytes_len(&self, module: u32, register: u32) -> Result<usize, BoxedError> {
+        match &self.register(module, register)?.value {
+            MockValue::Bytes(v) => Ok(v.len()),
+            _ => Err(error(module, register, InterfaceErrorType::WrongType)),
+        }
+    }
+
+    fn get_bytes(
+        &self,
+        module: u32,
+        register: u32,
+        destination: &mut [u8],
+    ) -> Result<usize, BoxedError> {
+        match &self.register(module, register)?.value {
+            MockValue::Bytes(v) if destination.len() < v.len() => {
+                Err(error(module, register, InterfaceErrorType::ReadOverflow))
+            }
+            MockValue::Bytes(v) => {
+                destination[0..v.len()].copy_from_slice(v);
+                Ok(v.len())
+            }
+            _ => Err(error(module, register, InterfaceErrorType::WrongType)),
+        }
+    }
+
+    fn set_bytes(&mut self, module: u32, register: u32, values: &[u8]) -> Result<(), BoxedError> {
+        let r = self.register(module, register)?;
+        match r.value {
+            MockValue::Bytes(_) if values.len() > r.max_len => {
+                return Err(error(module, register, InterfaceErrorType::WriteOverflow))
+            }
+            MockValue::Bytes(_) => {}
+            _ => return Err(error(module, register, InterfaceErrorType::WrongType)),
+        }
+        self.write(module, register, MockValue::Bytes(values.to_vec()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Follow;
+    impl UnitControl for Follow {
+        fn update(
+            &mut self,
+            interface: &mut dyn Interface,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let yaw = interface.get_f32(common::MODULE_GPS, gps::REG_GPS_YAW)?;
+            interface.set_f32(
+                tank::MODULE_TANK_REVOLUTE_TURRET,
+                revolute::REG_REVOLUTE_VELOCITY_CMD,
+                -yaw,
+            )?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_layouts() {
+        let tank = MockInterface::tank();
+        assert_eq!(
+            tank.get_i32(common::MODULE_UNIT, unit::REG_UNIT_UNIT_TYPE)
+                .unwrap(),
+            UnitType::Tank as i32
+        );
+        assert_eq!(
+            tank.module_name(tank::MODULE_TANK_CANNON).unwrap(),
+            "cannon"
+        );
+        let artillery = MockInterface::artillery();
+        assert_eq!(
+            artillery
+                .module_name(artillery::MODULE_ARTILLERY_GUN_BATTERY)
+                .unwrap(),
+            "gun_battery"
+        );
+        let constructor = MockInterface::constructor();
+        assert!(constructor
+            .get_i32(common::MODULE_DEPLOY, deploy::REG_DEPLOY_STATE)
+            .is_ok());
+        assert!(constructor.get_f32(tank::MODULE_TANK_RADAR, 0).is_err());
+        assert_eq!(
+            constructor
+                .module_name(constructor::MODULE_CONSTRUCTOR_MINE_LAYER)
+                .unwrap(),
+            "mine_layer"
+        );
+        assert_eq!(
+            constructor
+                .get_i32(
+                    constructor::MODULE_CONSTRUCTOR_TOWER_RADAR,
+                    tower_radar::REG_TOWER_RADAR_PRESENT
+                )
+                .unwrap(),
+            0
+        );
+        let scout = MockInterface::scout();
+        assert_eq!(
+            scout.module_name(scout::MODULE_SCOUT_RADAR).unwrap(),
+            "radar"
+        );
+        assert!(scout.module_name(tank::MODULE_TANK_CANNON).is_err());
+        let launcher = MockInterface::missile_launcher();
+        assert_eq!(
+            launcher
+                .get_i32(
+                    missile_launcher::MODULE_MISSILE_LAUNCHER_DATALINK,
+                    missile_datalink::REG_MISSILE_DATALINK_COUNT
+                )
+                .unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_script_and_writes() {
+        let mut interface = MockInterface::tank();
+        interface.script(common::MODULE_GPS, gps::REG_GPS_YAW, |t| t * 2.0);
+        interface.run(&mut Follow, 0.5, 3).unwrap();
+        assert_eq!(interface.time(), 1.5);
+        assert_eq!(
+            interface.value(common::MODULE_CLOCK, clock::REG_CLOCK_ELAPSED),
+            Some(&MockValue::F32(1.0))
+        );
+        let m = tank::MODULE_TANK_REVOLUTE_TURRET;
+        let writes = interface.writes_to(m, revolute::REG_REVOLUTE_VELOCITY_CMD);
+        assert_eq!(writes.len(), 3);
+        assert_eq!(writes[1].time, 0.5);
+        interface.assert_written(m, revolute::REG_REVOLUTE_VELOCITY_CMD, -2.0f32);
+        interface.assert_not_written(tank::MODULE_TANK_DIFF_DRIVE, 0);
+        interface.clear_writes();
+        assert!(interface.writes().is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut interface = MockInterface::tank();
+        let e = interface.set_i32(0xdead, 0, 0).unwrap_err();
+        assert_eq!(e.error_type, InterfaceErrorType::NoSuchModule);
+        let e = interface
+            .set_i32(common::MODULE_GPS, gps::REG_GPS_X, 0)
+            .unwrap_err();
+        assert_eq!(e.error_type, InterfaceErrorType::WrongType);
+        let e = interface
+            .set_bytes(
+                common::MODULE_RADIO_TRANSMITTER,
+                radio_transmitter::REG_RADIO_TX_MSG_START,
+                &[0u8; 33],
+            )
+            .unwrap_err();
+        assert_eq!(e.error_type, InterfaceErrorType::WriteOverflow);
+        assert!(interface.writes().is_empty());
+    }
+
+    #[test]
+    fn test_batched_access() {
+        use crate::RegisterValue;
+        let mut interface = MockInterface::tank();
+        interface.set_value(common::MODULE_GPS, gps::REG_GPS_Y, 2.0f32);
+        let snapshot = interface.get_module(common::MODULE_GPS).unwrap();
+        assert_eq!(snapshot.len(), 8);
+        assert_eq!(snapshot[1], (gps::REG_GPS_Y, RegisterValue::F32(2.0)));
+
+        let m = tank::MODULE_TANK_DIFF_DRIVE;
+        use differential_drive::*;
+        interface
+            .set_registers(&[
+                (m, REG_DIFF_DRIVE_LEFT_CMD, RegisterValue::F32(1.0)),
+                (m, REG_DIFF_DRIVE_RIGHT_CMD, RegisterValue::F32(-1.0)),
+            ])
+            .unwrap();
+        interface.assert_written(m, REG_DIFF_DRIVE_RIGHT_CMD, -1.0f32);
+        let values = interface
+            .get_registers(&[(m, REG_DIFF_DRIVE_LEFT_CMD), (m, REG_DIFF_DRIVE_RIGHT_CMD)])
+            .unwrap();
+        assert_eq!(
+            values,
+            vec![RegisterValue::F32(1.0), RegisterValue::F32(-1.0)]
+        );
+
+        let e = interface
+            .set_registers(&[(m, REG_DIFF_DRIVE_LEFT_CMD, RegisterValue::I32(1))])
+            .unwrap_err();
+        assert_eq!(e.error_type, InterfaceErrorType::WrongType);
+    }
+}
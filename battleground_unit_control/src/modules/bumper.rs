@@ -0,0 +1,13 @@
+//! Contact sensor around the unit's base.
+//!
+//! Reports whether the unit is pushing against another unit or an obstacle. The direction is
+//! a unit vector towards the contacts, expressed in the frame of the unit's base.
+
+/// Register holding a boolean whether the unit is in contact with anything.
+pub const REG_BUMPER_IN_CONTACT: u32 = 0;
+/// Register holding the number of entities the unit is in contact with, integer value.
+pub const REG_BUMPER_CONTACT_COUNT: u32 = 1;
+/// Register holding the x component of the direction towards the contacts, float value.
+pub const REG_BUMPER_DIRECTION_X: u32 = 2;
+/// Register holding the y component of the direction towards the contacts, float value.
+pub const REG_BUMPER_DIRECTION_Y: u32 = 3;
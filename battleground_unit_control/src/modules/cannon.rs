@@ -1,5 +1,7 @@
 //! Fire control of simple cannons.
 //!
+//! Depending on the scenario the cannon may have limited ammunition, it can be replenished in
+//! resupply zones or near a deployed constructor of the same team.
 
 /// Trigger the cannon to fire, is_triggered will go true, and the cannon will fire as soon as ready
 /// then the trigger becomes inactive until activated again.
@@ -13,3 +15,9 @@ pub const REG_CANNON_READY: u32 = 2;
 
 /// Provides the reload time in seconds, float value.
 pub const REG_CANNON_RELOAD_TIME: u32 = 3;
+
+/// The number of rounds left, integer value, negative if ammunition is unlimited.
+pub const REG_CANNON_AMMUNITION: u32 = 4;
+
+/// The maximum number of rounds the cannon can hold, integer value, negative if unlimited.
+pub const REG_CANNON_AMMUNITION_CAPACITY: u32 = 5;
@@ -0,0 +1,54 @@
+//! Builds structures while the constructor is deployed.
+//!
+//! A build is started by writing the structure type to the build register. The cost of the
+//! structure is deducted from the team's budget when the build starts, the structure is placed
+//! behind the constructor when the build time has elapsed. The build only progresses while the
+//! constructor is deployed, if it undeploys the build is paused. Writing [`STRUCTURE_NONE`] to the
+//! build register cancels the build in progress and refunds its cost. Structures can't overlap, a
+//! build is refused if a structure already occupies the spot, the constructor has to move first.
+//!
+//! Structure costs and build times are provided as a list, indexed by the structure type.
+
+/// The structure type that is being built, integer value, [`STRUCTURE_NONE`] if idle. Write a
+/// structure type to start building it.
+pub const REG_CONSTRUCTOR_BUILD: u32 = 0;
+
+/// Progress of the current build, float value between 0.0 and 1.0.
+pub const REG_CONSTRUCTOR_BUILD_PROGRESS: u32 = 1;
+
+/// Status of the constructor, integer value, one of the `BUILD_STATUS_` values.
+pub const REG_CONSTRUCTOR_BUILD_STATUS: u32 = 2;
+
+/// Remaining budget of the team, float value, negative if the budget is unlimited.
+pub const REG_CONSTRUCTOR_BUDGET: u32 = 3;
+
+/// The number of structure types.
+pub const REG_CONSTRUCTOR_STRUCTURE_COUNT: u32 = 0x100;
+/// The start of the structure list.
+pub const REG_CONSTRUCTOR_STRUCTURE_START: u32 = 0x101;
+/// The offset of the cost of the structure, float value.
+pub const REG_CONSTRUCTOR_STRUCTURE_OFFSET_COST: u32 = 0;
+/// The offset of the build time of the structure, float value, seconds.
+pub const REG_CONSTRUCTOR_STRUCTURE_OFFSET_BUILD_TIME: u32 = 1;
+/// The stride of each structure record.
+pub const REG_CONSTRUCTOR_STRUCTURE_STRIDE: u32 = 2;
+
+/// Value used to express no structure.
+pub const STRUCTURE_NONE: i32 = -1;
+/// A static wall segment that blocks units, projectiles and radar.
+pub const STRUCTURE_WALL: i32 = 0;
+/// A tower carrying a long range radar.
+pub const STRUCTURE_RADAR_TOWER: i32 = 1;
+/// A station that repairs nearby units.
+pub const STRUCTURE_REPAIR_STATION: i32 = 2;
+
+/// Not building anything.
+pub const BUILD_STATUS_IDLE: i32 = 0;
+/// A build is in progress.
+pub const BUILD_STATUS_BUILDING: i32 = 1;
+/// The last build could not be started because the team budget was insufficient.
+pub const BUILD_STATUS_INSUFFICIENT_BUDGET: i32 = 2;
+/// The last build could not be started because the constructor was not deployed.
+pub const BUILD_STATUS_NOT_DEPLOYED: i32 = 3;
+/// The last build could not be started or placed because a structure already occupies the spot.
+pub const BUILD_STATUS_OCCUPIED: i32 = 4;
@@ -1,6 +1,10 @@
 //! The global position of the unit.
 //!
 //! This gps is usually attached to the center of the units' body.
+//!
+//! Depending on the scenario, the gps may not be perfect; readings can have noise and a constant
+//! bias, fixes may only arrive at a limited rate and there may be zones without reception. While
+//! there is no reception the registers hold the last fix and the valid register is false.
 
 /// Register holding the x coordinate in meters, float value.
 pub const REG_GPS_X: u32 = 0;
@@ -14,3 +18,7 @@ pub const REG_GPS_ROLL: u32 = 3;
 pub const REG_GPS_PITCH: u32 = 4;
 /// Register holding the yaw, float value, radians.
 pub const REG_GPS_YAW: u32 = 5;
+/// Register holding a boolean whether the gps currently has reception.
+pub const REG_GPS_VALID: u32 = 6;
+/// Register holding the clock time at which the current values were obtained, float value.
+pub const REG_GPS_FIX_TIME: u32 = 7;
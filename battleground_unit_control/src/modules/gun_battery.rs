@@ -4,6 +4,9 @@
 //! Each individual gun has its own reload duration, there may be an additional gun battery reload
 //! duration when all guns have been emptied.
 //! Individual guns have their own pose, last reload time and ready state. Guns fire in order.
+//!
+//! Depending on the scenario the gun battery may have limited ammunition, each gun firing uses one
+//! round. It can be replenished in resupply zones or near a deployed constructor of the same team.
 
 /// Set the gun battery to be firing. This does not disable when a shot is fired, but keeps firing.
 pub const REG_GUN_BATTERY_FIRING: u32 = 0;
@@ -26,6 +29,12 @@ pub const REG_GUN_BATTERY_RELOAD: u32 = 5;
 /// The index of the gun that will fire next.
 pub const REG_GUN_BATTERY_FIRE_INDEX: u32 = 6;
 
+/// The number of rounds left, integer value, negative if ammunition is unlimited.
+pub const REG_GUN_BATTERY_AMMUNITION: u32 = 7;
+
+/// The maximum number of rounds the battery can hold, integer value, negative if unlimited.
+pub const REG_GUN_BATTERY_AMMUNITION_CAPACITY: u32 = 8;
+
 /// The number of guns in this battery.
 pub const REG_GUN_BATTERY_COUNT: u32 = 0x1000;
 /// The start of the gun list.
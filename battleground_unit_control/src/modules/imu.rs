@@ -0,0 +1,10 @@
+//! Gyroscope attached to the unit's body.
+//!
+//! Provides the angular rates of the body, expressed in the body's frame.
+
+/// Register holding the angular rate around the body's x axis, float value, radians per second.
+pub const REG_IMU_ROLL_RATE: u32 = 0;
+/// Register holding the angular rate around the body's y axis, float value, radians per second.
+pub const REG_IMU_PITCH_RATE: u32 = 1;
+/// Register holding the angular rate around the body's z axis, float value, radians per second.
+pub const REG_IMU_YAW_RATE: u32 = 2;
@@ -0,0 +1,49 @@
+//! Drops mines while the constructor is deployed.
+//!
+//! Writing a non-zero value to the drop register requests a single mine, it is placed behind the
+//! constructor. The cost of the mine is deducted from the team's budget, each constructor carries a
+//! limited number of mines. The status register holds the outcome of the last request.
+//!
+//! Mines arm after a delay, once armed they detonate when a unit of another team comes within the
+//! trigger radius, doing splash damage. Radars of other teams only detect a mine within the
+//! detection range, radars of the own team always see it.
+
+/// Write a non-zero value to drop a mine, integer (bool) value, always reads as zero.
+pub const REG_MINE_LAYER_DROP: u32 = 0;
+
+/// Outcome of the last drop request, integer value, one of the `MINE_LAYER_STATUS_` values.
+pub const REG_MINE_LAYER_STATUS: u32 = 1;
+
+/// The number of mines left, integer value.
+pub const REG_MINE_LAYER_MINES: u32 = 2;
+
+/// The number of mines the constructor started with, integer value.
+pub const REG_MINE_LAYER_CAPACITY: u32 = 3;
+
+/// The cost of a mine, float value, deducted from the team's budget.
+pub const REG_MINE_LAYER_COST: u32 = 4;
+
+/// Minimum duration between two mines being dropped, float value, seconds.
+pub const REG_MINE_LAYER_DROP_INTERVAL: u32 = 5;
+
+/// Duration after being dropped before a mine can detonate, float value, seconds.
+pub const REG_MINE_LAYER_ARM_TIME: u32 = 6;
+
+/// Distance from the mine within which units of other teams detonate it, float value.
+pub const REG_MINE_LAYER_TRIGGER_RADIUS: u32 = 7;
+
+/// Distance within which radars of other teams detect a mine, float value.
+pub const REG_MINE_LAYER_DETECTION_RANGE: u32 = 8;
+
+/// No mine has been requested yet.
+pub const MINE_LAYER_STATUS_IDLE: i32 = 0;
+/// The last request dropped a mine.
+pub const MINE_LAYER_STATUS_DROPPED: i32 = 1;
+/// The last request was refused because the team budget was insufficient.
+pub const MINE_LAYER_STATUS_INSUFFICIENT_BUDGET: i32 = 2;
+/// The last request was refused because the constructor was not deployed.
+pub const MINE_LAYER_STATUS_NOT_DEPLOYED: i32 = 3;
+/// The last request was refused because no mines are left.
+pub const MINE_LAYER_STATUS_EMPTY: i32 = 4;
+/// The last request was refused because the drop interval had not yet passed.
+pub const MINE_LAYER_STATUS_NOT_READY: i32 = 5;
@@ -0,0 +1,65 @@
+//! Datalink to the guided missiles in flight.
+//!
+//! Each missile that is launched is linked to a slot of the datalink, the slot provides the state
+//! of the missile and accepts guidance commands while the missile is in flight. If all slots are
+//! in use when a missile is launched, the missile that was launched first loses its link and keeps
+//! following its last command.
+//!
+//! Missiles fly at a constant speed and can only change their direction at a limited turn rate
+//! while they have fuel. Once the fuel runs out they follow a ballistic trajectory. Missiles that
+//! are not guided fly straight ahead.
+//!
+//! Angles are in the world frame; yaw is the rotation around the z axis, pitch is the elevation
+//! above the horizontal plane, positive upwards.
+
+/// The speed of the missiles, float value, meters per second.
+pub const REG_MISSILE_DATALINK_SPEED: u32 = 0;
+
+/// The maximum turn rate of the missiles, float value, radians per second.
+pub const REG_MISSILE_DATALINK_TURN_RATE: u32 = 1;
+
+/// The duration of powered flight of the missiles, float value, seconds.
+pub const REG_MISSILE_DATALINK_FUEL: u32 = 2;
+
+/// The number of slots in the datalink.
+pub const REG_MISSILE_DATALINK_COUNT: u32 = 0x1000;
+/// The start of the slot list.
+pub const REG_MISSILE_DATALINK_START: u32 = 0x1001;
+
+/// Whether a missile in flight is linked to this slot, integer (bool) value.
+pub const REG_MISSILE_DATALINK_OFFSET_ACTIVE: u32 = 0;
+/// The offset of the missile's x position, float value.
+pub const REG_MISSILE_DATALINK_OFFSET_X: u32 = 1;
+/// The offset of the missile's y position, float value.
+pub const REG_MISSILE_DATALINK_OFFSET_Y: u32 = 2;
+/// The offset of the missile's z position, float value.
+pub const REG_MISSILE_DATALINK_OFFSET_Z: u32 = 3;
+/// The offset of the missile's yaw, float value, radians.
+pub const REG_MISSILE_DATALINK_OFFSET_YAW: u32 = 4;
+/// The offset of the missile's pitch, float value, radians.
+pub const REG_MISSILE_DATALINK_OFFSET_PITCH: u32 = 5;
+/// The offset of the missile's remaining fuel, float value, seconds.
+pub const REG_MISSILE_DATALINK_OFFSET_FUEL: u32 = 6;
+
+/// The offset of the guidance mode, integer value, one of the `MISSILE_GUIDANCE_` values. Writable.
+pub const REG_MISSILE_DATALINK_OFFSET_MODE: u32 = 7;
+/// The offset of the target x position, float value. Writable.
+pub const REG_MISSILE_DATALINK_OFFSET_TARGET_X: u32 = 8;
+/// The offset of the target y position, float value. Writable.
+pub const REG_MISSILE_DATALINK_OFFSET_TARGET_Y: u32 = 9;
+/// The offset of the target z position, float value. Writable.
+pub const REG_MISSILE_DATALINK_OFFSET_TARGET_Z: u32 = 10;
+/// The offset of the target yaw, float value, radians. Writable.
+pub const REG_MISSILE_DATALINK_OFFSET_TARGET_YAW: u32 = 11;
+/// The offset of the target pitch, float value, radians. Writable.
+pub const REG_MISSILE_DATALINK_OFFSET_TARGET_PITCH: u32 = 12;
+
+/// The stride of each slot record.
+pub const REG_MISSILE_DATALINK_STRIDE: u32 = 13;
+
+/// The missile flies straight ahead.
+pub const MISSILE_GUIDANCE_NONE: i32 = 0;
+/// The missile turns towards the target position.
+pub const MISSILE_GUIDANCE_POSITION: i32 = 1;
+/// The missile turns towards the target yaw and pitch.
+pub const MISSILE_GUIDANCE_HEADING: i32 = 2;
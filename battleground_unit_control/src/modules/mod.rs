@@ -19,18 +19,26 @@
 //! Boolean values are represented by integers, if the integer is zero, this represents boolean
 //! `false`, if the value is non-zero it represents `true`.
 
+pub mod bumper;
 pub mod cannon;
 pub mod clock;
+pub mod constructor;
 pub mod controller;
 pub mod deploy;
 pub mod differential_drive;
 pub mod draw;
 pub mod gps;
 pub mod gun_battery;
+pub mod imu;
+pub mod mine_layer;
+pub mod missile_datalink;
 pub mod objectives;
+pub mod odometry;
 pub mod radar;
 pub mod radio_receiver;
 pub mod radio_transmitter;
 pub mod revolute;
 pub mod team;
+pub mod team_blackboard;
+pub mod tower_radar;
 pub mod unit;
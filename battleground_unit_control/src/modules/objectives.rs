@@ -21,3 +21,64 @@ pub const REG_OBJECTIVES_CAPTURE_POINT_STRIDE: u32 = 4;
 
 /// Sentinel value used for the owner if a capture point is unused.
 pub const OBJECTIVES_CAPTURE_POINT_UNOWNED: i32 = -1;
+
+/// Register index to obtain the flag count, for capture the flag matches.
+pub const REG_OBJECTIVES_FLAG_COUNT: u32 = 0x1100;
+
+/// Register start index for the flag list.
+pub const REG_OBJECTIVES_FLAG_START: u32 = 0x1101;
+
+/// Register offset for the current x position of a flag, float value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_X: u32 = 0;
+/// Register offset for the current y position of a flag, float value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_Y: u32 = 1;
+/// Register offset for the team that owns the flag, integer value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_TEAM: u32 = 2;
+/// Register offset for the state of a flag, integer value, see `OBJECTIVES_FLAG_STATE_*`.
+pub const REG_OBJECTIVES_FLAG_OFFSET_STATE: u32 = 3;
+/// Register offset for the unit id carrying the flag, integer value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_CARRIER: u32 = 4;
+/// Register offset for the x position of the base of a flag, float value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_BASE_X: u32 = 5;
+/// Register offset for the y position of the base of a flag, float value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_BASE_Y: u32 = 6;
+/// Register offset for the radius of the base of a flag, float value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_RADIUS: u32 = 7;
+/// Register stride for each flag.
+pub const REG_OBJECTIVES_FLAG_STRIDE: u32 = 8;
+
+/// The flag is at its base.
+pub const OBJECTIVES_FLAG_STATE_AT_BASE: i32 = 0;
+/// The flag is carried by an enemy unit.
+pub const OBJECTIVES_FLAG_STATE_CARRIED: i32 = 1;
+/// The flag was dropped when its carrier got destroyed.
+pub const OBJECTIVES_FLAG_STATE_DROPPED: i32 = 2;
+
+/// Sentinel value used for the carrier if a flag is not carried.
+pub const OBJECTIVES_FLAG_NO_CARRIER: i32 = -1;
+
+/// Register index to obtain the payload count for escort matches, either zero or one.
+pub const REG_OBJECTIVES_PAYLOAD_COUNT: u32 = 0x1200;
+/// Register index for the x position of the payload, float value.
+pub const REG_OBJECTIVES_PAYLOAD_X: u32 = 0x1201;
+/// Register index for the y position of the payload, float value.
+pub const REG_OBJECTIVES_PAYLOAD_Y: u32 = 0x1202;
+/// Register index for the yaw of the payload, float value.
+pub const REG_OBJECTIVES_PAYLOAD_YAW: u32 = 0x1203;
+/// Register index for the progress of the payload along its path, float value from 0.0 to 1.0.
+pub const REG_OBJECTIVES_PAYLOAD_PROGRESS: u32 = 0x1204;
+/// Register index for the radius around the payload in which units push or stop it, float value.
+pub const REG_OBJECTIVES_PAYLOAD_RADIUS: u32 = 0x1205;
+/// Register index for the team that escorts the payload, integer value.
+pub const REG_OBJECTIVES_PAYLOAD_ATTACKER: u32 = 0x1206;
+
+/// Register index to obtain the number of waypoints in the payload path.
+pub const REG_OBJECTIVES_PAYLOAD_WAYPOINT_COUNT: u32 = 0x1210;
+/// Register start index for the waypoint list.
+pub const REG_OBJECTIVES_PAYLOAD_WAYPOINT_START: u32 = 0x1211;
+/// Register offset for the x position of a waypoint, float value.
+pub const REG_OBJECTIVES_PAYLOAD_WAYPOINT_OFFSET_X: u32 = 0;
+/// Register offset for the y position of a waypoint, float value.
+pub const REG_OBJECTIVES_PAYLOAD_WAYPOINT_OFFSET_Y: u32 = 1;
+/// Register stride for each waypoint.
+pub const REG_OBJECTIVES_PAYLOAD_WAYPOINT_STRIDE: u32 = 2;
@@ -0,0 +1,12 @@
+//! Wheel encoders of the unit's drive base.
+//!
+//! The tick counts accumulate the distance travelled by the wheels since the unit was spawned,
+//! they wrap around on overflow. Wheels can slip, for example when pushing against an obstacle.
+//! For a tricycle base both counts hold the travelled distance of the rear axle.
+
+/// Register holding the tick count of the left wheel, integer value.
+pub const REG_ODOMETRY_LEFT_TICKS: u32 = 0;
+/// Register holding the tick count of the right wheel, integer value.
+pub const REG_ODOMETRY_RIGHT_TICKS: u32 = 1;
+/// Register holding the number of ticks per meter travelled, float value.
+pub const REG_ODOMETRY_TICKS_PER_METER: u32 = 2;
@@ -3,7 +3,12 @@
 //!
 //! The radar returns reflections in the radar frame, detection area is limited by the detection yaw
 //! and detection pitch, both in the radar's frame. It can also only detect radar reflections up to
-//! the maximum distance.
+//! the maximum distance. Reflections require line of sight; other units, obstacles and terrain
+//! between the radar and the reflector block it.
+//!
+//! Depending on the scenario the radar may be noisy; reported yaw, pitch and distance can have
+//! gaussian noise, weak reflections may be missed and reflections can show up where there is
+//! nothing. Filtering over multiple scans helps.
 
 /// The maximum detection range of the radar, float value.
 pub const REG_RADAR_RANGE_MAX: u32 = 0x10;
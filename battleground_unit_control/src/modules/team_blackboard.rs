@@ -0,0 +1,13 @@
+//! Memory shared by all units of the team.
+//!
+//! This module is only present if the scenario enables it. The blackboard is a fixed size byte
+//! region, all units of the team read the same contents and can write to it. Writes become visible
+//! to all units in the next step. Only the bytes a unit changed are written; if multiple units
+//! change the same byte in the same step, the unit with the highest unit id wins.
+
+/// The size of the blackboard in bytes, integer value.
+pub const REG_TEAM_BLACKBOARD_SIZE: u32 = 0;
+
+/// The contents of the blackboard, byte values. Writing a shorter value only changes the bytes
+/// at the start of the blackboard.
+pub const REG_TEAM_BLACKBOARD_DATA: u32 = 1;
@@ -0,0 +1,18 @@
+//! The radar of the radar tower most recently built by the constructor.
+//!
+//! Once a radar tower is finished, its radar is readable through this module for as long as the
+//! tower stands. Besides the pose registers below, the module holds the range, detection angle and
+//! reflection registers of the [`crate::modules::radar`] module, with reflections expressed in the
+//! frame of the tower's radar. The tower's radar covers the full circle around the tower.
+
+/// Whether a radar tower built by this constructor is standing, integer (bool) value. If zero, none
+/// of the other registers are present.
+pub const REG_TOWER_RADAR_PRESENT: u32 = 0;
+/// Register holding the x coordinate of the tower's radar in meters, float value.
+pub const REG_TOWER_RADAR_X: u32 = 1;
+/// Register holding the y coordinate of the tower's radar in meters, float value.
+pub const REG_TOWER_RADAR_Y: u32 = 2;
+/// Register holding the z coordinate of the tower's radar in meters, float value.
+pub const REG_TOWER_RADAR_Z: u32 = 3;
+/// Register holding the yaw of the tower's radar, float value, radians.
+pub const REG_TOWER_RADAR_YAW: u32 = 4;
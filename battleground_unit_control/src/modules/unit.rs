@@ -1,6 +1,15 @@
 //! Information about the unit type and unit id.
 //!
 //! This provides the unit with knowledge about which unit id it has and what unit type it is.
+//!
+//! It also exposes the health of the unit and that of its subsystems, such that a controller can
+//! adapt to damage. Subsystem health ranges from 1.0 (intact) to 0.0 (destroyed). A damaged
+//! subsystem degrades the associated capability:
+//! - Tracks: the maximum velocity of the drive base is reduced.
+//! - Radar: the range of the radar is reduced.
+//! - Weapon: the reload time of the cannon or gun battery is increased.
+//!
+//! Even a fully destroyed subsystem retains a quarter of its capability.
 
 /// Holds the unit id, integer value.
 pub const REG_UNIT_UNIT_ID: u32 = 0x10;
@@ -8,6 +17,16 @@ pub const REG_UNIT_UNIT_ID: u32 = 0x10;
 /// Holds the unit type, integer value convertible to [`crate::units::UnitType`].
 pub const REG_UNIT_UNIT_TYPE: u32 = 0x11;
 
+/// Holds the health of the unit, float value, the unit is destroyed when this reaches 0.0.
+pub const REG_UNIT_HEALTH: u32 = 0x12;
+
+/// Holds the health of the tracks (or wheels) subsystem, float value between 0.0 and 1.0.
+pub const REG_UNIT_SUBSYSTEM_TRACKS: u32 = 0x20;
+/// Holds the health of the radar subsystem, float value between 0.0 and 1.0.
+pub const REG_UNIT_SUBSYSTEM_RADAR: u32 = 0x21;
+/// Holds the health of the weapon subsystem, float value between 0.0 and 1.0.
+pub const REG_UNIT_SUBSYSTEM_WEAPON: u32 = 0x22;
+
 /// Sentinel value if the unit doesn't have an id.
 pub const UNIT_NO_UNIT_ID: i32 = -1;
 /// Sentinel value if the unit doesn't have a unit type.
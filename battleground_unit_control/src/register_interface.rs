@@ -2,6 +2,8 @@ use engine::World;
 
 type BoxedError = Box<crate::interface::InterfaceError>;
 use crate::interface::InterfaceErrorType;
+use crate::trace::{TraceAccess, TraceEntry, TraceValue, Tracer};
+use std::cell::RefCell;
 
 /// A register value record.
 #[derive(Debug)]
@@ -193,6 +195,9 @@ impl Module {
 #[derive(Default)]
 pub struct RegisterInterface {
     modules: std::collections::HashMap<ModuleId, Module>,
+
+    /// Optional tracer, records all register access made through the Interface trait.
+    tracer: RefCell<Option<Tracer>>,
 }
 
 impl RegisterInterface {
@@ -334,6 +339,36 @@ impl RegisterInterface {
         }
     }
 
+    /// Enable tracing of register access through the [`crate::Interface`] trait, or disable it by
+    /// passing None.
+    pub fn set_tracer(&mut self, tracer: Option<Tracer>) {
+        *self.tracer.get_mut() = tracer;
+    }
+
+    /// Denote the start of a controller update in the trace, if tracing is enabled.
+    pub fn trace_begin_update(&mut self, time: f32) {
+        if let Some(tracer) = self.tracer.get_mut() {
+            tracer.begin_update(time);
+        }
+    }
+
+    fn trace<F: FnOnce() -> TraceValue>(
+        &self,
+        access: TraceAccess,
+        module: ModuleId,
+        register: RegisterId,
+        value: F,
+    ) {
+        if let Some(tracer) = self.tracer.borrow_mut().as_mut() {
+            tracer.record(&TraceEntry {
+                access,
+                module,
+                register,
+                value: value(),
+            });
+        }
+    }
+
     fn interface_error(module: u32, register: u32, error_type: InterfaceErrorType) -> BoxedError {
         Box::new(InterfaceError {
             module,
@@ -384,7 +419,10 @@ impl crate::interface::Interface for RegisterInterface {
     fn get_f32(&self, module: u32, register: u32) -> Result<f32, BoxedError> {
         let r = self.get_register(module, register)?;
         match r.value {
-            Value::F32(v) => Ok(v),
+            Value::F32(v) => {
+                self.trace(TraceAccess::Get, module, register, || TraceValue::F32(v));
+                Ok(v)
+            }
             _ => Err(RegisterInterface::interface_error(
                 module,
                 register,
@@ -396,7 +434,10 @@ impl crate::interface::Interface for RegisterInterface {
     fn get_i32(&self, module: u32, register: u32) -> Result<i32, BoxedError> {
         let r = self.get_register(module, register)?;
         match r.value {
-            Value::I32(v) => Ok(v),
+            Value::I32(v) => {
+                self.trace(TraceAccess::Get, module, register, || TraceValue::I32(v));
+                Ok(v)
+            }
             _ => Err(RegisterInterface::interface_error(
                 module,
                 register,
@@ -412,6 +453,9 @@ impl crate::interface::Interface for RegisterInterface {
             Value::F32(v) => {
                 let old = *v;
                 *v = value;
+                self.trace(TraceAccess::Set, module, register, || {
+                    TraceValue::F32(value)
+                });
                 Ok(old)
             }
             _ => Err(RegisterInterface::interface_error(
@@ -429,6 +473,9 @@ impl crate::interface::Interface for RegisterInterface {
             Value::I32(v) => {
                 let old = *v;
                 *v = value;
+                self.trace(TraceAccess::Set, module, register, || {
+                    TraceValue::I32(value)
+                });
                 Ok(old)
             }
             _ => Err(RegisterInterface::interface_error(
@@ -471,6 +518,9 @@ impl crate::interface::Interface for RegisterInterface {
                 } else {
                     // Must be the correct size.
                     destination[0..values.len()].copy_from_slice(values);
+                    self.trace(TraceAccess::Get, module, register, || {
+                        TraceValue::Bytes(values.clone())
+                    });
                     Ok(values.len())
                 }
             }
@@ -512,6 +562,9 @@ impl crate::interface::Interface for RegisterInterface {
                     // Must be the correct size.
                     values.clear();
                     values.extend_from_slice(input_values); // do the copy.
+                    self.trace(TraceAccess::Set, module, register, || {
+                        TraceValue::Bytes(input_values.to_vec())
+                    });
                     Ok(())
                 }
             }
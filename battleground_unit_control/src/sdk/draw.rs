@@ -0,0 +1,67 @@
+//! Typed access to the [`crate::modules::draw`] module.
+
+use super::BoxedError;
+use crate::modules::draw::*;
+use crate::Interface;
+
+/// Collects lines to be drawn, writing them all to the draw module at once.
+///
+/// ```
+/// use battleground_unit_control::sdk::Draw;
+/// use battleground_unit_control::units::common;
+/// let mut draw = Draw::new(common::MODULE_DRAW);
+/// draw.line([0.0, 0.0, 1.0], [5.0, 0.0, 1.0], 0.05, [255, 0, 0, 255]);
+/// // Then call draw.submit(interface) to write the lines.
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Draw {
+    module: u32,
+    lines: Vec<LineSegment>,
+}
+
+impl Draw {
+    /// Create a drawing buffer for the draw module with the provided id.
+    pub fn new(module: u32) -> Self {
+        Draw {
+            module,
+            lines: vec![],
+        }
+    }
+
+    /// The module id of this draw module.
+    pub fn module(&self) -> u32 {
+        self.module
+    }
+
+    /// Add a line from `p0` to `p1`, with color as `[r, g, b, a]`.
+    pub fn line(&mut self, p0: [f32; 3], p1: [f32; 3], width: f32, color: [u8; 4]) -> &mut Self {
+        self.lines.push(LineSegment {
+            p0,
+            p1,
+            width,
+            color,
+        });
+        self
+    }
+
+    /// Add an already created line segment.
+    pub fn segment(&mut self, segment: LineSegment) -> &mut Self {
+        self.lines.push(segment);
+        self
+    }
+
+    /// The lines currently collected.
+    pub fn lines(&self) -> &[LineSegment] {
+        &self.lines
+    }
+
+    /// Write the collected lines to the draw module and clear the buffer.
+    pub fn submit(&mut self, interface: &mut dyn Interface) -> Result<(), BoxedError> {
+        let mut bytes: Vec<u8> =
+            Vec::with_capacity(self.lines.len() * std::mem::size_of::<LineSegment>());
+        for l in self.lines.drain(..) {
+            bytes.extend(l.into_le_bytes());
+        }
+        interface.set_bytes(self.module, REG_DRAW_LINES, &bytes)
+    }
+}
@@ -0,0 +1,93 @@
+//! Typed access to the [`crate::modules::gun_battery`] module.
+
+use super::{BoxedError, ListValues};
+use crate::modules::gun_battery::*;
+use crate::Interface;
+
+/// State of an individual gun in the battery.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Gun {
+    /// Position of the gun, x.
+    pub x: f32,
+    /// Position of the gun, y.
+    pub y: f32,
+    /// Position of the gun, z.
+    pub z: f32,
+    /// Roll of the gun, radians.
+    pub roll: f32,
+    /// Pitch of the gun, radians.
+    pub pitch: f32,
+    /// Yaw of the gun, radians.
+    pub yaw: f32,
+    /// Time at which this gun last fired, seconds.
+    pub last_fire_time: f32,
+    /// Whether this gun is ready to fire.
+    pub ready: bool,
+}
+
+/// Handle to a gun battery module.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GunBattery {
+    module: u32,
+}
+
+impl GunBattery {
+    /// Create a handle for the gun battery module with the provided id.
+    pub const fn new(module: u32) -> Self {
+        GunBattery { module }
+    }
+
+    /// The module id of this gun battery.
+    pub fn module(&self) -> u32 {
+        self.module
+    }
+
+    register_accessors!(
+        /// Whether the battery is set to be firing.
+        get_bool firing => REG_GUN_BATTERY_FIRING;
+        /// Set the battery to keep firing.
+        set_bool set_firing => REG_GUN_BATTERY_FIRING;
+        /// Whether the battery has been triggered.
+        get_bool is_triggered => REG_GUN_BATTERY_IS_TRIGGERED;
+        /// Whether the battery is ready to fire.
+        get_bool is_ready => REG_GUN_BATTERY_READY;
+        /// The reload time per gun, seconds.
+        get_f32 gun_reload => REG_GUN_BATTERY_GUN_RELOAD;
+        /// The duration between individual gun firings, seconds.
+        get_f32 inter_gun_duration => REG_GUN_BATTERY_INTER_GUN_DURATION;
+        /// The reload time of the entire battery, seconds.
+        get_f32 battery_reload => REG_GUN_BATTERY_RELOAD;
+        /// The index of the gun that will fire next.
+        get_i32 fire_index => REG_GUN_BATTERY_FIRE_INDEX;
+        /// The number of rounds left, negative if unlimited.
+        get_i32 ammunition => REG_GUN_BATTERY_AMMUNITION;
+        /// The maximum number of rounds, negative if unlimited.
+        get_i32 ammunition_capacity => REG_GUN_BATTERY_AMMUNITION_CAPACITY;
+    );
+
+    /// Read the state of all guns in the battery.
+    pub fn guns(&self, interface: &dyn Interface) -> Result<Vec<Gun>, BoxedError> {
+        let count = interface.get_i32(self.module, REG_GUN_BATTERY_COUNT)?;
+        let list = ListValues::read(
+            interface,
+            self.module,
+            REG_GUN_BATTERY_START,
+            REG_GUN_BATTERY_STRIDE,
+            count,
+        )?;
+        (0..list.len())
+            .map(|i| {
+                Ok(Gun {
+                    x: list.f32(i, REG_GUN_BATTERY_OFFSET_X)?,
+                    y: list.f32(i, REG_GUN_BATTERY_OFFSET_Y)?,
+                    z: list.f32(i, REG_GUN_BATTERY_OFFSET_Z)?,
+                    roll: list.f32(i, REG_GUN_BATTERY_OFFSET_ROLL)?,
+                    pitch: list.f32(i, REG_GUN_BATTERY_OFFSET_PITCH)?,
+                    yaw: list.f32(i, REG_GUN_BATTERY_OFFSET_YAW)?,
+                    last_fire_time: list.f32(i, REG_GUN_BATTERY_OFFSET_LAST_FIRE_TIME)?,
+                    ready: list.i32(i, REG_GUN_BATTERY_OFFSET_READY)? != 0,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,313 @@
+//! Typed wrappers around the modules.
+//!
+//! The [`crate::modules`] describe the register layout of each module type, the wrappers in this
+//! module provide typed access on top of those register constants. Each wrapper is a small handle
+//! that only holds the module id, the interface is passed to each call. This allows storing the
+//! handles in the controller, or defining them as constants:
+//! ```
+//! use battleground_unit_control::sdk::{Radar, Revolute};
+//! use battleground_unit_control::units::tank;
+//! const TURRET: Revolute = Revolute::new(tank::MODULE_TANK_REVOLUTE_TURRET);
+//! const RADAR: Radar = Radar::new(tank::MODULE_TANK_RADAR);
+//! ```
+//! Lists, like the radar reflections, are read completely and returned as a vector of structs.
+//! They are retrieved with a single [`Interface::get_registers`] call.
+
+use crate::interface::{InterfaceError, InterfaceErrorType};
+use crate::{Interface, RegisterValue};
+
+type BoxedError = Box<InterfaceError>;
+
+/// Calculate the register of a field in a list entry, as described in [`crate::modules`].
+pub const fn list_register(start: u32, stride: u32, index: u32, offset: u32) -> u32 {
+    start + index * stride + offset
+}
+
+/// The registers of a list, read with a single batched call.
+struct ListValues {
+    module: u32,
+    start: u32,
+    stride: u32,
+    values: Vec<RegisterValue>,
+}
+
+impl ListValues {
+    /// Read `count` entries of the list at `start`, each entry spanning `stride` registers.
+    fn read(
+        interface: &dyn Interface,
+        module: u32,
+        start: u32,
+        stride: u32,
+        count: i32,
+    ) -> Result<Self, BoxedError> {
+        let registers = (0..count.max(0) as u32 * stride)
+            .map(|r| (module, start + r))
+            .collect::<Vec<_>>();
+        Ok(ListValues {
+            module,
+            start,
+            stride,
+            values: interface.get_registers(&registers)?,
+        })
+    }
+
+    /// The number of entries in the list.
+    fn len(&self) -> u32 {
+        self.values.len() as u32 / self.stride
+    }
+
+    fn get(&self, index: u32, offset: u32) -> &RegisterValue {
+        &self.values[(index * self.stride + offset) as usize]
+    }
+
+    fn wrong_type(&self, index: u32, offset: u32) -> BoxedError {
+        Box::new(InterfaceError {
+            module: self.module,
+            register: list_register(self.start, self.stride, index, offset),
+            error_type: InterfaceErrorType::WrongType,
+        })
+    }
+
+    fn f32(&self, index: u32, offset: u32) -> Result<f32, BoxedError> {
+        match self.get(index, offset) {
+            RegisterValue::F32(v) => Ok(*v),
+            _ => Err(self.wrong_type(index, offset)),
+        }
+    }
+
+    fn i32(&self, index: u32, offset: u32) -> Result<i32, BoxedError> {
+        match self.get(index, offset) {
+            RegisterValue::I32(v) => Ok(*v),
+            _ => Err(self.wrong_type(index, offset)),
+        }
+    }
+
+    fn bytes(&self, index: u32, offset: u32) -> Result<Vec<u8>, BoxedError> {
+        match self.get(index, offset) {
+            RegisterValue::Bytes(v) => Ok(v.clone()),
+            _ => Err(self.wrong_type(index, offset)),
+        }
+    }
+}
+
+/// Helper to create methods that read or write a single register of the module.
+macro_rules! register_accessors {
+    () => {};
+    ($(#[$doc:meta])* get_f32 $name:ident => $register:expr; $($rest:tt)*) => {
+        $(#[$doc])*
+        pub fn $name(&self, interface: &dyn crate::Interface) -> Result<f32, BoxedError> {
+            interface.get_f32(self.module, $register)
+        }
+        register_accessors!($($rest)*);
+    };
+    ($(#[$doc:meta])* get_i32 $name:ident => $register:expr; $($rest:tt)*) => {
+        $(#[$doc])*
+        pub fn $name(&self, interface: &dyn crate::Interface) -> Result<i32, BoxedError> {
+            interface.get_i32(self.module, $register)
+        }
+        register_accessors!($($rest)*);
+    };
+    ($(#[$doc:meta])* get_bool $name:ident => $register:expr; $($rest:tt)*) => {
+        $(#[$doc])*
+        pub fn $name(&self, interface: &dyn crate::Interface) -> Result<bool, BoxedError> {
+            Ok(interface.get_i32(self.module, $register)? != 0)
+        }
+        register_accessors!($($rest)*);
+    };
+    ($(#[$doc:meta])* set_f32 $name:ident => $register:expr; $($rest:tt)*) => {
+        $(#[$doc])*
+        pub fn $name(&self, interface: &mut dyn crate::Interface, value: f32) -> Result<(), BoxedError> {
+            interface.set_f32(self.module, $register, value)?;
+            Ok(())
+        }
+        register_accessors!($($rest)*);
+    };
+    ($(#[$doc:meta])* set_i32 $name:ident => $register:expr; $($rest:tt)*) => {
+        $(#[$doc])*
+        pub fn $name(&self, interface: &mut dyn crate::Interface, value: i32) -> Result<(), BoxedError> {
+            interface.set_i32(self.module, $register, value)?;
+            Ok(())
+        }
+        register_accessors!($($rest)*);
+    };
+    ($(#[$doc:meta])* set_bool $name:ident => $register:expr; $($rest:tt)*) => {
+        $(#[$doc])*
+        pub fn $name(&self, interface: &mut dyn crate::Interface, value: bool) -> Result<(), BoxedError> {
+            interface.set_i32(self.module, $register, value as i32)?;
+            Ok(())
+        }
+        register_accessors!($($rest)*);
+    };
+}
+
+pub mod draw;
+pub mod gun_battery;
+pub mod radar;
+pub mod radio_receiver;
+pub mod revolute;
+
+pub use draw::Draw;
+pub use gun_battery::GunBattery;
+pub use radar::Radar;
+pub use radio_receiver::RadioReceiver;
+pub use revolute::Revolute;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::MockInterface;
+    use crate::Interface;
+
+    const MODULE: u32 = 0x1600;
+
+    fn test_interface() -> MockInterface {
+        let mut interface = MockInterface::new();
+        interface.add_module(MODULE, "test");
+        interface
+    }
+
+    #[test]
+    fn test_radar() {
+        use crate::modules::radar::*;
+        let mut interface = test_interface();
+        let radar = Radar::new(MODULE);
+        interface.add_register(MODULE, REG_RADAR_RANGE_MAX, "test", 30.0);
+        interface.add_register(MODULE, REG_RADAR_REFLECTION_COUNT, "test", 2);
+        for i in 0..2 {
+            let base = REG_RADAR_REFLECTION_START + i * REG_RADAR_REFLECTION_STRIDE;
+            let v = i as f32;
+            for (offset, value) in [
+                (REG_RADAR_REFLECTION_OFFSET_YAW, v + 0.1),
+                (REG_RADAR_REFLECTION_OFFSET_PITCH, v + 0.2),
+                (REG_RADAR_REFLECTION_OFFSET_DISTANCE, v + 0.3),
+                (REG_RADAR_REFLECTION_OFFSET_STRENGTH, v + 0.4),
+            ] {
+                interface.add_register(MODULE, base + offset, "test", value);
+            }
+        }
+        assert_eq!(radar.range_max(&interface).unwrap(), 30.0);
+        let reflections = radar.reflections(&interface).unwrap();
+        assert_eq!(reflections.len(), 2);
+        assert_eq!(
+            reflections[1],
+            radar::Reflection {
+                yaw: 1.1,
+                pitch: 1.2,
+                distance: 1.3,
+                strength: 1.4
+            }
+        );
+        // Missing registers are propagated as errors.
+        assert!(radar.detection_angle_yaw(&interface).is_err());
+    }
+
+    #[test]
+    fn test_radio_receiver() {
+        use crate::modules::radio_receiver::*;
+        let mut interface = test_interface();
+        let receiver = RadioReceiver::new(MODULE);
+        interface.add_register(MODULE, REG_RADIO_RX_MSG_COUNT, "test", 1);
+        interface.add_register(MODULE, REG_RADIO_RX_CHANNEL_SELECT, "test", 0);
+        interface.add_register(
+            MODULE,
+            REG_RADIO_RX_MSG_START + REG_RADIO_RX_MSG_OFFSET_STRENGTH,
+            "test",
+            0.5,
+        );
+        interface.add_register(
+            MODULE,
+            REG_RADIO_RX_MSG_START + REG_RADIO_RX_MSG_OFFSET_DATA,
+            "test",
+            vec![1, 2, 3],
+        );
+        let messages = receiver.messages(&interface).unwrap();
+        assert_eq!(
+            messages,
+            vec![radio_receiver::Message {
+                strength: 0.5,
+                data: vec![1, 2, 3]
+            }]
+        );
+        receiver.set_channel(&mut interface, 3).unwrap();
+        assert_eq!(receiver.channel(&interface).unwrap(), 3);
+        receiver.clear_messages(&mut interface).unwrap();
+        assert!(receiver.messages(&interface).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_revolute() {
+        use crate::modules::revolute::*;
+        let mut interface = test_interface();
+        let revolute = Revolute::new(MODULE);
+        interface.add_register(MODULE, REG_REVOLUTE_VELOCITY_CMD, "test", 0.0);
+        revolute.set_velocity(&mut interface, 1.5).unwrap();
+        assert_eq!(
+            interface
+                .get_f32(MODULE, REG_REVOLUTE_VELOCITY_CMD)
+                .unwrap(),
+            1.5
+        );
+        assert_eq!(revolute.velocity_cmd(&interface).unwrap(), 1.5);
+    }
+
+    #[test]
+    fn test_draw() {
+        use crate::modules::draw::*;
+        let mut interface = test_interface();
+        interface.add_register(MODULE, REG_DRAW_LINES, "test", vec![]);
+        let mut draw = Draw::new(MODULE);
+        draw.line([0.0, 0.0, 0.0], [1.0, 0.0, 0.0], 0.1, [255, 0, 0, 255])
+            .line([0.0, 0.0, 0.0], [0.0, 1.0, 0.0], 0.1, [0, 255, 0, 255]);
+        let expected = draw.lines().to_vec();
+        draw.submit(&mut interface).unwrap();
+        assert!(draw.lines().is_empty());
+
+        let len = interface.get_bytes_len(MODULE, REG_DRAW_LINES).unwrap();
+        let mut bytes = vec![0u8; len];
+        interface
+            .get_bytes(MODULE, REG_DRAW_LINES, &mut bytes)
+            .unwrap();
+        const SIZE: usize = std::mem::size_of::<LineSegment>();
+        let lines = bytes
+            .chunks(SIZE)
+            .map(|c| {
+                let b: [u8; SIZE] = c.try_into().unwrap();
+                LineSegment::from(b)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn test_gun_battery() {
+        use crate::modules::gun_battery::*;
+        let mut interface = test_interface();
+        let battery = GunBattery::new(MODULE);
+        interface.add_register(MODULE, REG_GUN_BATTERY_COUNT, "test", 2);
+        interface.add_register(MODULE, REG_GUN_BATTERY_FIRING, "test", 0);
+        for i in 0..2 {
+            let base = REG_GUN_BATTERY_START + i * REG_GUN_BATTERY_STRIDE;
+            for offset in REG_GUN_BATTERY_OFFSET_X..REG_GUN_BATTERY_OFFSET_READY {
+                interface.add_register(MODULE, base + offset, "test", offset as f32);
+            }
+            interface.add_register(
+                MODULE,
+                base + REG_GUN_BATTERY_OFFSET_READY,
+                "test",
+                i as i32,
+            );
+        }
+        let guns = battery.guns(&interface).unwrap();
+        assert_eq!(guns.len(), 2);
+        assert!(!guns[0].ready);
+        assert!(guns[1].ready);
+        assert_eq!(guns[1].yaw, REG_GUN_BATTERY_OFFSET_YAW as f32);
+        assert_eq!(
+            guns[1].last_fire_time,
+            REG_GUN_BATTERY_OFFSET_LAST_FIRE_TIME as f32
+        );
+
+        battery.set_firing(&mut interface, true).unwrap();
+        assert!(battery.firing(&interface).unwrap());
+    }
+}
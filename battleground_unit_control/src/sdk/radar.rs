@@ -0,0 +1,67 @@
+//! Typed access to the [`crate::modules::radar`] module.
+
+use super::{BoxedError, ListValues};
+use crate::modules::radar::*;
+use crate::Interface;
+
+/// A single radar reflection, in the radar's frame.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Reflection {
+    /// Yaw of the reflection, radians.
+    pub yaw: f32,
+    /// Pitch of the reflection, radians.
+    pub pitch: f32,
+    /// Distance to the reflection.
+    pub distance: f32,
+    /// Relative strength of the reflection.
+    pub strength: f32,
+}
+
+/// Handle to a radar module.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Radar {
+    module: u32,
+}
+
+impl Radar {
+    /// Create a handle for the radar module with the provided id.
+    pub const fn new(module: u32) -> Self {
+        Radar { module }
+    }
+
+    /// The module id of this radar.
+    pub fn module(&self) -> u32 {
+        self.module
+    }
+
+    register_accessors!(
+        /// The maximum detection range.
+        get_f32 range_max => REG_RADAR_RANGE_MAX;
+        /// The maximum detection yaw, radians.
+        get_f32 detection_angle_yaw => REG_RADAR_DETECTION_ANGLE_YAW;
+        /// The maximum detection pitch, radians.
+        get_f32 detection_angle_pitch => REG_RADAR_DETECTION_ANGLE_PITCH;
+    );
+
+    /// Read all current reflections.
+    pub fn reflections(&self, interface: &dyn Interface) -> Result<Vec<Reflection>, BoxedError> {
+        let count = interface.get_i32(self.module, REG_RADAR_REFLECTION_COUNT)?;
+        let list = ListValues::read(
+            interface,
+            self.module,
+            REG_RADAR_REFLECTION_START,
+            REG_RADAR_REFLECTION_STRIDE,
+            count,
+        )?;
+        (0..list.len())
+            .map(|i| {
+                Ok(Reflection {
+                    yaw: list.f32(i, REG_RADAR_REFLECTION_OFFSET_YAW)?,
+                    pitch: list.f32(i, REG_RADAR_REFLECTION_OFFSET_PITCH)?,
+                    distance: list.f32(i, REG_RADAR_REFLECTION_OFFSET_DISTANCE)?,
+                    strength: list.f32(i, REG_RADAR_REFLECTION_OFFSET_STRENGTH)?,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,75 @@
+//! Typed access to the [`crate::modules::radio_receiver`] module.
+
+use super::{BoxedError, ListValues};
+use crate::modules::radio_receiver::*;
+use crate::Interface;
+
+/// A received radio message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    /// Strength with which the message was received.
+    pub strength: f32,
+    /// The message payload.
+    pub data: Vec<u8>,
+}
+
+/// Handle to a radio receiver module.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RadioReceiver {
+    module: u32,
+}
+
+impl RadioReceiver {
+    /// Create a handle for the radio receiver module with the provided id.
+    pub const fn new(module: u32) -> Self {
+        RadioReceiver { module }
+    }
+
+    /// The module id of this radio receiver.
+    pub fn module(&self) -> u32 {
+        self.module
+    }
+
+    register_accessors!(
+        /// The minimum selectable channel.
+        get_i32 channel_min => REG_RADIO_RX_CHANNEL_MIN;
+        /// The maximum selectable channel.
+        get_i32 channel_max => REG_RADIO_RX_CHANNEL_MAX;
+        /// The maximum number of messages held in the buffer.
+        get_i32 message_count_limit => REG_RADIO_RX_MSG_COUNT_LIMIT;
+        /// The currently selected channel.
+        get_i32 channel => REG_RADIO_RX_CHANNEL_SELECT;
+        /// Select the channel to receive on.
+        set_i32 set_channel => REG_RADIO_RX_CHANNEL_SELECT;
+        /// Number of messages dropped because the buffer was full.
+        get_i32 overflow => REG_RADIO_RX_MSG_OVERFLOW;
+        /// Set the overflow counter, usually to clear it.
+        set_i32 set_overflow => REG_RADIO_RX_MSG_OVERFLOW;
+    );
+
+    /// Read all messages currently in the buffer.
+    pub fn messages(&self, interface: &dyn Interface) -> Result<Vec<Message>, BoxedError> {
+        let count = interface.get_i32(self.module, REG_RADIO_RX_MSG_COUNT)?;
+        let list = ListValues::read(
+            interface,
+            self.module,
+            REG_RADIO_RX_MSG_START,
+            REG_RADIO_RX_MSG_STRIDE,
+            count,
+        )?;
+        (0..list.len())
+            .map(|i| {
+                Ok(Message {
+                    strength: list.f32(i, REG_RADIO_RX_MSG_OFFSET_STRENGTH)?,
+                    data: list.bytes(i, REG_RADIO_RX_MSG_OFFSET_DATA)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Clear all messages from the buffer, marking them as received.
+    pub fn clear_messages(&self, interface: &mut dyn Interface) -> Result<(), BoxedError> {
+        interface.set_i32(self.module, REG_RADIO_RX_MSG_COUNT, 0)?;
+        Ok(())
+    }
+}
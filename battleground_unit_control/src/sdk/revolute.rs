@@ -0,0 +1,37 @@
+//! Typed access to the [`crate::modules::revolute`] module.
+
+use super::BoxedError;
+use crate::modules::revolute::*;
+
+/// Handle to a revolute joint module.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Revolute {
+    module: u32,
+}
+
+impl Revolute {
+    /// Create a handle for the revolute module with the provided id.
+    pub const fn new(module: u32) -> Self {
+        Revolute { module }
+    }
+
+    /// The module id of this revolute joint.
+    pub fn module(&self) -> u32 {
+        self.module
+    }
+
+    register_accessors!(
+        /// The current rotation, radians.
+        get_f32 position => REG_REVOLUTE_POSITION;
+        /// The current rotational velocity, radians per second.
+        get_f32 velocity => REG_REVOLUTE_VELOCITY;
+        /// The minimum rotational velocity.
+        get_f32 velocity_min => REG_REVOLUTE_VELOCITY_MIN;
+        /// The maximum rotational velocity.
+        get_f32 velocity_max => REG_REVOLUTE_VELOCITY_MAX;
+        /// The currently commanded rotational velocity, radians per second.
+        get_f32 velocity_cmd => REG_REVOLUTE_VELOCITY_CMD;
+        /// Command the rotational velocity, radians per second.
+        set_f32 set_velocity => REG_REVOLUTE_VELOCITY_CMD;
+    );
+}
@@ -0,0 +1,594 @@
+//! Tracing of register access and offline replay of those traces.
+//!
+//! A [`Tracer`] writes every register read and write made by a controller to a text file, one
+//! access per line. Each controller update starts with an `update` line holding the time:
+//! ```text
+//! update 1.01
+//! get 00000100:00000000 f32 1.01
+//! set 00001000:00000002 f32 0.1
+//! get 00001800:00000000 bytes 00ff
+//! ```
+//!
+//! Such a file can be loaded with [`Replay::from_file`], which allows running a controller
+//! against the recorded reads without the simulation, for example to step through it in a
+//! debugger. The writes made during replay are compared against the recorded writes.
+
+use crate::interface::{InterfaceError, InterfaceErrorType};
+use crate::{Interface, RegisterType, UnitControl};
+
+type BoxedError = Box<InterfaceError>;
+
+/// Value of a register access.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceValue {
+    /// An i32 value.
+    I32(i32),
+    /// An f32 value.
+    F32(f32),
+    /// A bytes value.
+    Bytes(Vec<u8>),
+}
+
+/// Type of register access.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TraceAccess {
+    /// Register was read by the controller.
+    Get,
+    /// Register was written by the controller.
+    Set,
+}
+
+/// A single register access.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEntry {
+    /// Whether this was a read or a write.
+    pub access: TraceAccess,
+    /// The module accessed.
+    pub module: u32,
+    /// The register accessed.
+    pub register: u32,
+    /// The value read or written.
+    pub value: TraceValue,
+}
+
+impl std::fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let access = match self.access {
+            TraceAccess::Get => "get",
+            TraceAccess::Set => "set",
+        };
+        write!(f, "{access} {:0>8x}:{:0>8x} ", self.module, self.register)?;
+        // Debug formatting of floats roundtrips exactly.
+        match &self.value {
+            TraceValue::I32(v) => write!(f, "i32 {v}"),
+            TraceValue::F32(v) => write!(f, "f32 {v:?}"),
+            TraceValue::Bytes(v) => {
+                write!(f, "bytes ")?;
+                if v.is_empty() {
+                    write!(f, "-")?;
+                }
+                for b in v.iter() {
+                    write!(f, "{b:0>2x}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for TraceEntry {
+    type Err = Box<dyn std::error::Error>;
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut tokens = line.split_whitespace();
+        let mut next = || tokens.next().ok_or("unexpected end of line");
+        let access = match next()? {
+            "get" => TraceAccess::Get,
+            "set" => TraceAccess::Set,
+            v => return Err(format!("unknown access {v}").into()),
+        };
+        let (module, register) = next()?.split_once(':').ok_or("expected module:register")?;
+        let module = u32::from_str_radix(module, 16)?;
+        let register = u32::from_str_radix(register, 16)?;
+        let value_type = next()?;
+        let value = next()?;
+        let value = match value_type {
+            "i32" => TraceValue::I32(value.parse()?),
+            "f32" => TraceValue::F32(value.parse()?),
+            "bytes" if value == "-" => TraceValue::Bytes(vec![]),
+            "bytes" => {
+                if value.len() % 2 != 0 {
+                    return Err("odd number of hex characters".into());
+                }
+                let mut bytes = vec![];
+                for i in (0..value.len()).step_by(2) {
+                    bytes.push(u8::from_str_radix(&value[i..i + 2], 16)?);
+                }
+                TraceValue::Bytes(bytes)
+            }
+            v => return Err(format!("unknown value type {v}").into()),
+        };
+        Ok(TraceEntry {
+            access,
+            module,
+            register,
+            value,
+        })
+    }
+}
+
+/// Writes register accesses to a writer.
+pub struct Tracer {
+    writer: Box<dyn std::io::Write>,
+    failed: bool,
+}
+
+impl Tracer {
+    /// Create a tracer writing into the provided writer.
+    pub fn new(writer: Box<dyn std::io::Write>) -> Self {
+        Tracer {
+            writer,
+            failed: false,
+        }
+    }
+
+    /// Create a tracer writing to a file at the provided path.
+    pub fn create(path: &std::path::Path) -> Result<Self, std::io::Error> {
+        let file = std::fs::File::create(path)?;
+        Ok(Self::new(Box::new(std::io::BufWriter::new(file))))
+    }
+
+    /// Mark the start of a controller update at the provided time.
+    pub fn begin_update(&mut self, time: f32) {
+        self.write_line(format_args!("update {time:?}"));
+    }
+
+    /// Record a register access.
+    pub fn record(&mut self, entry: &TraceEntry) {
+        self.write_line(format_args!("{entry}"));
+    }
+
+    fn write_line(&mut self, line: std::fmt::Arguments) {
+        if self.failed {
+            return;
+        }
+        if let Err(e) = writeln!(self.writer, "{line}") {
+            // Stop tracing, instead of failing for every subsequent access.
+            log::error!("writing trace failed, tracing stopped: {e:?}");
+            self.failed = true;
+        }
+    }
+}
+
+impl Drop for Tracer {
+    fn drop(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
+/// All register accesses made during a single controller update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceUpdate {
+    /// Time at which the update took place.
+    pub time: f32,
+    /// The accesses, in order.
+    pub entries: Vec<TraceEntry>,
+}
+
+/// Parse a trace as written by the [`Tracer`].
+pub fn read_trace(
+    reader: impl std::io::BufRead,
+) -> Result<Vec<TraceUpdate>, Box<dyn std::error::Error>> {
+    let mut updates: Vec<TraceUpdate> = vec![];
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(time) = line.strip_prefix("update ") {
+            updates.push(TraceUpdate {
+                time: time.trim().parse()?,
+                entries: vec![],
+            });
+            continue;
+        }
+        let entry = line
+            .parse::<TraceEntry>()
+            .map_err(|e| format!("line {}: {e}", i + 1))?;
+        updates
+            .last_mut()
+            .ok_or("register access before first update")?
+            .entries
+            .push(entry);
+    }
+    Ok(updates)
+}
+
+/// Interface that serves values recorded in a trace, used by [`Replay`].
+#[derive(Default)]
+pub struct ReplayInterface {
+    registers: std::collections::BTreeMap<(u32, u32), TraceValue>,
+    writes: std::collections::BTreeMap<(u32, u32), TraceValue>,
+}
+
+impl ReplayInterface {
+    fn get(&self, module: u32, register: u32) -> Result<&TraceValue, BoxedError> {
+        if !self.registers.keys().any(|(m, _r)| *m == module) {
+            return Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::NoSuchModule,
+            ));
+        }
+        self.registers.get(&(module, register)).ok_or_else(|| {
+            Self::interface_error(module, register, InterfaceErrorType::NoSuchRegister)
+        })
+    }
+
+    fn set(
+        &mut self,
+        module: u32,
+        register: u32,
+        value: TraceValue,
+    ) -> Result<TraceValue, BoxedError> {
+        let old = self.get(module, register)?.clone();
+        if std::mem::discriminant(&old) != std::mem::discriminant(&value) {
+            return Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::WrongType,
+            ));
+        }
+        self.registers.insert((module, register), value.clone());
+        self.writes.insert((module, register), value);
+        Ok(old)
+    }
+
+    fn interface_error(module: u32, register: u32, error_type: InterfaceErrorType) -> BoxedError {
+        Box::new(InterfaceError {
+            module,
+            register,
+            error_type,
+        })
+    }
+}
+
+impl Interface for ReplayInterface {
+    fn modules(&self) -> Result<Vec<u32>, BoxedError> {
+        let mut modules = self.registers.keys().map(|(m, _r)| *m).collect::<Vec<_>>();
+        modules.dedup();
+        Ok(modules)
+    }
+
+    fn module_name(&self, module: u32) -> Result<String, BoxedError> {
+        // Names are not traced.
+        Ok(format!("module_{module:0>8x}"))
+    }
+
+    fn registers(&self, module: u32) -> Result<Vec<u32>, BoxedError> {
+        Ok(self
+            .registers
+            .keys()
+            .filter(|(m, _r)| *m == module)
+            .map(|(_m, r)| *r)
+            .collect())
+    }
+
+    fn register_name(&self, module: u32, register: u32) -> Result<String, BoxedError> {
+        self.get(module, register)?;
+        Ok(format!("register_{register:0>8x}"))
+    }
+
+    fn register_type(&self, module: u32, register: u32) -> Result<RegisterType, BoxedError> {
+        Ok(match self.get(module, register)? {
+            TraceValue::I32(_) => RegisterType::I32,
+            TraceValue::F32(_) => RegisterType::F32,
+            TraceValue::Bytes(_) => RegisterType::Bytes,
+        })
+    }
+
+    fn get_i32(&self, module: u32, register: u32) -> Result<i32, BoxedError> {
+        match self.get(module, register)? {
+            TraceValue::I32(v) => Ok(*v),
+            _ => Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::WrongType,
+            )),
+        }
+    }
+
+    fn set_i32(&mut self, module: u32, register: u32, value: i32) -> Result<i32, BoxedError> {
+        match self.set(module, register, TraceValue::I32(value))? {
+            TraceValue::I32(v) => Ok(v),
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_f32(&self, module: u32, register: u32) -> Result<f32, BoxedError> {
+        match self.get(module, register)? {
+            TraceValue::F32(v) => Ok(*v),
+            _ => Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::WrongType,
+            )),
+        }
+    }
+
+    fn set_f32(&mut self, module: u32, register: u32, value: f32) -> Result<f32, BoxedError> {
+        match self.set(module, register, TraceValue::F32(value))? {
+            TraceValue::F32(v) => Ok(v),
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_bytes_len(&self, module: u32, register: u32) -> Result<usize, BoxedError> {
+        match self.get(module, register)? {
+            TraceValue::Bytes(v) => Ok(v.len()),
+            _ => Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::WrongType,
+            )),
+        }
+    }
+
+    fn get_bytes(
+        &self,
+        module: u32,
+        register: u32,
+        destination: &mut [u8],
+    ) -> Result<usize, BoxedError> {
+        match self.get(module, register)? {
+            TraceValue::Bytes(v) => {
+                if destination.len() < v.len() {
+                    return Err(Self::interface_error(
+                        module,
+                        register,
+                        InterfaceErrorType::ReadOverflow,
+                    ));
+                }
+                destination[0..v.len()].copy_from_slice(v);
+                Ok(v.len())
+            }
+            _ => Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::WrongType,
+            )),
+        }
+    }
+
+    fn set_bytes(&mut self, module: u32, register: u32, values: &[u8]) -> Result<(), BoxedError> {
+        self.set(module, register, TraceValue::Bytes(values.to_vec()))?;
+        Ok(())
+    }
+}
+
+/// Difference between the recorded writes and the writes made during replay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayDivergence {
+    /// Index of the update.
+    pub update: usize,
+    /// Time of the update.
+    pub time: f32,
+    /// Module that differs.
+    pub module: u32,
+    /// Register that differs.
+    pub register: u32,
+    /// The final value written in the trace, if any.
+    pub recorded: Option<TraceValue>,
+    /// The final value written during replay, if any.
+    pub replayed: Option<TraceValue>,
+}
+
+impl std::fmt::Display for ReplayDivergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "replay diverged at update {} (t={}) in {:0>8x}:{:0>8x}, recorded: {:?}, replayed: {:?}",
+            self.update, self.time, self.module, self.register, self.recorded, self.replayed
+        )
+    }
+}
+impl std::error::Error for ReplayDivergence {}
+
+/// Feeds the reads of a trace to a controller, one update at a time.
+pub struct Replay {
+    updates: Vec<TraceUpdate>,
+    interface: ReplayInterface,
+    index: usize,
+}
+
+impl Replay {
+    /// Create a replay from parsed trace updates.
+    pub fn new(updates: Vec<TraceUpdate>) -> Self {
+        Replay {
+            updates,
+            interface: Default::default(),
+            index: 0,
+        }
+    }
+
+    /// Load a trace file as written by the [`Tracer`].
+    pub fn from_file(path: &std::path::Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let file = std::fs::File::open(path)?;
+        Ok(Self::new(read_trace(std::io::BufReader::new(file))?))
+    }
+
+    /// The updates in this trace.
+    pub fn updates(&self) -> &[TraceUpdate] {
+        &self.updates
+    }
+
+    /// Index of the next update to be replayed.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Replay the next update, returns false if the trace is exhausted.
+    pub fn step(
+        &mut self,
+        controller: &mut dyn UnitControl,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let update = if let Some(update) = self.updates.get(self.index) {
+            update
+        } else {
+            return Ok(false);
+        };
+
+        // The first read of each register during this update is what the controller saw at the
+        // start of it, later reads may follow writes by the controller itself.
+        let mut initial = std::collections::BTreeMap::new();
+        for entry in update.entries.iter() {
+            if entry.access == TraceAccess::Get {
+                initial
+                    .entry((entry.module, entry.register))
+                    .or_insert_with(|| entry.value.clone());
+            }
+        }
+        self.interface.registers.extend(initial);
+        // Registers only written still need to exist.
+        for entry in update.entries.iter() {
+            if entry.access == TraceAccess::Set {
+                self.interface
+                    .registers
+                    .entry((entry.module, entry.register))
+                    .or_insert_with(|| entry.value.clone());
+            }
+        }
+
+        self.interface.writes.clear();
+        controller.update(&mut self.interface)?;
+
+        let mut recorded = std::collections::BTreeMap::new();
+        for entry in update.entries.iter() {
+            if entry.access == TraceAccess::Set {
+                recorded.insert((entry.module, entry.register), entry.value.clone());
+            }
+        }
+        let replayed = &self.interface.writes;
+        let mut keys = recorded.keys().chain(replayed.keys()).collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+        for key in keys {
+            if recorded.get(key) != replayed.get(key) {
+                return Err(Box::new(ReplayDivergence {
+                    update: self.index,
+                    time: update.time,
+                    module: key.0,
+                    register: key.1,
+                    recorded: recorded.get(key).cloned(),
+                    replayed: replayed.get(key).cloned(),
+                }));
+            }
+        }
+
+        self.index += 1;
+        Ok(true)
+    }
+
+    /// Replay all remaining updates, stops at the first error or divergence.
+    pub fn run(
+        &mut self,
+        controller: &mut dyn UnitControl,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        while self.step(controller)? {}
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_entry_roundtrip() {
+        let entries = [
+            TraceEntry {
+                access: TraceAccess::Get,
+                module: 0x100,
+                register: 0,
+                value: TraceValue::F32(0.1 + 0.2),
+            },
+            TraceEntry {
+                access: TraceAccess::Set,
+                module: 0x1000,
+                register: 0x1001,
+                value: TraceValue::I32(-5),
+            },
+            TraceEntry {
+                access: TraceAccess::Set,
+                module: 0x1800,
+                register: 0,
+                value: TraceValue::Bytes(vec![0, 255, 16]),
+            },
+            TraceEntry {
+                access: TraceAccess::Get,
+                module: 0x1800,
+                register: 0,
+                value: TraceValue::Bytes(vec![]),
+            },
+        ];
+        for entry in entries {
+            let line = format!("{entry}");
+            let parsed: TraceEntry = line.parse().unwrap();
+            assert_eq!(entry, parsed);
+        }
+    }
+
+    struct Doubler {}
+    impl UnitControl for Doubler {
+        fn update(
+            &mut self,
+            interface: &mut dyn Interface,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let v = interface.get_f32(1, 0)?;
+            interface.set_f32(1, 1, v * 2.0)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_replay() {
+        let trace = "update 0.01\nget 00000001:00000000 f32 1.5\nset 00000001:00000001 f32 3.0\n\
+                     update 0.02\nget 00000001:00000000 f32 2.0\nset 00000001:00000001 f32 4.0\n";
+        let updates = read_trace(trace.as_bytes()).unwrap();
+        assert_eq!(updates.len(), 2);
+        let mut replay = Replay::new(updates);
+        replay.run(&mut Doubler {}).unwrap();
+        assert_eq!(replay.index(), 2);
+
+        let trace = "update 0.01\nget 00000001:00000000 f32 1.5\nset 00000001:00000001 f32 2.0\n";
+        let mut replay = Replay::new(read_trace(trace.as_bytes()).unwrap());
+        let e = replay.run(&mut Doubler {}).unwrap_err();
+        let divergence = e.downcast::<ReplayDivergence>().unwrap();
+        assert_eq!(divergence.recorded, Some(TraceValue::F32(2.0)));
+        assert_eq!(divergence.replayed, Some(TraceValue::F32(3.0)));
+    }
+
+    /// Increments a register and then reports what it reads back.
+    struct Incrementer {}
+    impl UnitControl for Incrementer {
+        fn update(
+            &mut self,
+            interface: &mut dyn Interface,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let v = interface.get_f32(1, 0)?;
+            interface.set_f32(1, 0, v + 1.0)?;
+            let v = interface.get_f32(1, 0)?;
+            interface.set_f32(1, 1, v)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_replay_read_after_write() {
+        let trace = "update 0.01\nget 00000001:00000000 f32 1.0\nset 00000001:00000000 f32 2.0\n\
+                     get 00000001:00000000 f32 2.0\nset 00000001:00000001 f32 2.0\n";
+        let mut replay = Replay::new(read_trace(trace.as_bytes()).unwrap());
+        replay.run(&mut Incrementer {}).unwrap();
+        assert_eq!(replay.index(), 1);
+    }
+}
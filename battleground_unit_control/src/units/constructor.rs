@@ -1,3 +1,12 @@
 //! Holds module constants specific to the constructor unit.
 
 pub use super::base_tricycle::*;
+
+/// Module identifier for the construction module, see [`crate::modules::constructor`].
+pub const MODULE_CONSTRUCTOR_BUILD: u32 = 0x1300;
+
+/// Module identifier for the mine layer module, see [`crate::modules::mine_layer`].
+pub const MODULE_CONSTRUCTOR_MINE_LAYER: u32 = 0x1400;
+
+/// Module identifier for the tower radar module, see [`crate::modules::tower_radar`].
+pub const MODULE_CONSTRUCTOR_TOWER_RADAR: u32 = 0x1500;
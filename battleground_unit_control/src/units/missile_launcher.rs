@@ -0,0 +1,25 @@
+//! Holds module constants specific to the missile launcher unit.
+//!
+//! The missile launcher is built on the base tricycle platform, it has a rotating launch rail that
+//! fires guided missiles. It has no radar of its own.
+
+pub use super::base_tricycle::*;
+
+/// Module identifier for the revolute joint to rotate the launcher yaw.
+pub const MODULE_MISSILE_LAUNCHER_REVOLUTE_YAW: u32 = 0x1200;
+
+/// Module identifier for the launch control module, see [`crate::modules::cannon`].
+pub const MODULE_MISSILE_LAUNCHER_CANNON: u32 = 0x1300;
+
+/// Module identifier for the datalink to the missiles, see [`crate::modules::missile_datalink`].
+pub const MODULE_MISSILE_LAUNCHER_DATALINK: u32 = 0x1400;
+
+/// Distance in z between the payload and the launcher joint.
+pub const MISSILE_LAUNCHER_DIM_PAYLOAD_TO_LAUNCHER_Z: f32 = 0.05;
+/// Distance in z between the launcher joint and the launch rail.
+pub const MISSILE_LAUNCHER_DIM_LAUNCHER_TO_RAIL_Z: f32 = 0.3;
+/// Distance between the rail joint and the point where missiles are released, along the rail.
+pub const MISSILE_LAUNCHER_DIM_RAIL_TO_RELEASE_X: f32 = 0.5;
+
+/// Elevation of the launch rail, radians.
+pub const MISSILE_LAUNCHER_PARAM_LAUNCH_PITCH: f32 = std::f32::consts::PI / 6.0;
@@ -6,6 +6,8 @@
 pub mod artillery;
 pub mod base_tricycle;
 pub mod constructor;
+pub mod missile_launcher;
+pub mod scout;
 pub mod tank;
 
 /// Holds module information that's shared between units.
@@ -26,6 +28,8 @@ pub mod common {
     pub const MODULE_RADIO_TRANSMITTER: u32 = 0x0600;
     /// Module identifier for the unit's radio receiver module.
     pub const MODULE_RADIO_RECEIVER: u32 = 0x0700;
+    /// Module identifier for the team blackboard module, if the scenario enables it.
+    pub const MODULE_TEAM_BLACKBOARD: u32 = 0x0800;
 
     /// Module identifier for the unit's gps module.
     pub const MODULE_GPS: u32 = 0x1700;
@@ -34,17 +38,29 @@ pub mod common {
 
     /// Module identifier for the unit's deploy module, if it has one.
     pub const MODULE_DEPLOY: u32 = 0x1900;
+
+    /// Module identifier for the unit's bumper module.
+    pub const MODULE_BUMPER: u32 = 0x1A00;
+
+    /// Module identifier for the unit's odometry module, if it has a drive base.
+    pub const MODULE_ODOMETRY: u32 = 0x1B00;
+
+    /// Module identifier for the unit's imu module.
+    pub const MODULE_IMU: u32 = 0x1C00;
 }
 
 /// Unit type enum to denote the unit type.
 ///
-/// This implements [`UnitType::try_from`] to convert from u32 to the enum.
+/// This implements [`UnitType::try_from`] to convert from u32 to the enum. The values are read by
+/// controllers and stored in recordings, new unit types are appended to keep them stable.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum UnitType {
     Artillery,
     Constructor,
     Tank,
     Unknown,
+    Scout,
+    MissileLauncher,
 }
 
 impl TryFrom<u32> for UnitType {
@@ -55,6 +71,8 @@ impl TryFrom<u32> for UnitType {
             x if x == UnitType::Constructor as u32 => Ok(UnitType::Constructor),
             x if x == UnitType::Tank as u32 => Ok(UnitType::Tank),
             x if x == UnitType::Unknown as u32 => Ok(UnitType::Unknown),
+            x if x == UnitType::Scout as u32 => Ok(UnitType::Scout),
+            x if x == UnitType::MissileLauncher as u32 => Ok(UnitType::MissileLauncher),
             _ => Err("could not convert unit_type"),
         }
     }
@@ -76,6 +94,12 @@ impl std::fmt::Display for UnitType {
             UnitType::Unknown => {
                 write!(f, "unknown")
             }
+            UnitType::Scout => {
+                write!(f, "scout")
+            }
+            UnitType::MissileLauncher => {
+                write!(f, "missile_launcher")
+            }
         }
     }
 }
@@ -90,5 +114,20 @@ mod test {
             UnitType::Artillery,
             (UnitType::Artillery as u32).try_into().unwrap()
         );
+        assert_eq!(
+            UnitType::Scout,
+            (UnitType::Scout as u32).try_into().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unit_type_values() {
+        // Controllers and recordings use these values, they must not change.
+        assert_eq!(UnitType::Artillery as u32, 0);
+        assert_eq!(UnitType::Constructor as u32, 1);
+        assert_eq!(UnitType::Tank as u32, 2);
+        assert_eq!(UnitType::Unknown as u32, 3);
+        assert_eq!(UnitType::Scout as u32, 4);
+        assert_eq!(UnitType::MissileLauncher as u32, 5);
     }
 }
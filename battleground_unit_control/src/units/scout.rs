@@ -0,0 +1,15 @@
+//! Holds module constants specific to the scout unit.
+//!
+//! The scout is built on the base tricycle platform, it has no weapon but carries a wide angle
+//! long range radar on a mast.
+
+pub use super::base_tricycle::*;
+
+/// Module identifier for the revolute joint that controls the radar yaw.
+pub const MODULE_SCOUT_REVOLUTE_RADAR: u32 = 0x1500;
+
+/// Module identifier for the radar module.
+pub const MODULE_SCOUT_RADAR: u32 = 0x1600;
+
+/// Distance in z between the payload and the radar joint, at the top of the mast.
+pub const SCOUT_DIM_PAYLOAD_TO_RADAR_Z: f32 = 0.6;
@@ -109,6 +109,11 @@ mod interface {
         fn wasm_interface_get_bytes(module: u32, register: u32, dest: u32, len: u32) -> u32;
         // return type of set bytes is just for uniform handling on the wasm wrapper side.
         fn wasm_interface_set_bytes(module: u32, register: u32, src: u32, len: u32) -> u32;
+
+        // Batched access, on error these return the index of the failing entry.
+        fn wasm_interface_get_registers(src: u32, count: u32) -> u32;
+        fn wasm_interface_get_module(module: u32) -> u32;
+        fn wasm_interface_set_registers(src: u32, len: u32) -> u32;
     }
 
     // static singleton to store messages going from rust to here.
@@ -163,6 +168,17 @@ mod interface {
         res
     }
 
+    fn read_from_buffer_bytes(length: u32) -> Vec<u8> {
+        let buffer = BUFFER.lock().expect("cannot be poisoned");
+        buffer[0..length as usize].to_vec()
+    }
+
+    /// Error for the entry at the index, or for module 0 and register 0 if out of range.
+    fn get_error_at(registers: &[(u32, u32)], index: u32) -> Result<(), Box<InterfaceError>> {
+        let (module, register) = registers.get(index as usize).copied().unwrap_or_default();
+        get_error(module, register)
+    }
+
     fn read_from_buffer_string(length: u32) -> String {
         let buffer = BUFFER.lock().expect("cannot be poisoned");
         match String::from_utf8(buffer[0..length as usize].to_vec()) {
@@ -317,6 +333,83 @@ mod interface {
             get_error(module, register)?;
             Ok(())
         }
+
+        /// Get the values of a list of registers, in a single call.
+        fn get_registers(
+            &self,
+            registers: &[(u32, u32)],
+        ) -> Result<Vec<RegisterValue>, Box<InterfaceError>> {
+            clear_error();
+            let request = registers
+                .iter()
+                .flat_map(|(module, register)| {
+                    module
+                        .to_le_bytes()
+                        .into_iter()
+                        .chain(register.to_le_bytes())
+                })
+                .collect::<Vec<u8>>();
+            let length = unsafe {
+                wasm_interface_get_registers(request.as_ptr() as u32, registers.len() as u32)
+            };
+            // On error the length is the index of the failing register.
+            get_error_at(registers, length)?;
+            let data = read_from_buffer_bytes(length);
+            let mut values = Vec::with_capacity(registers.len());
+            let mut offset = 0;
+            while offset < data.len() {
+                let (value, used) =
+                    RegisterValue::from_le_bytes(&data[offset..]).expect("host sends valid values");
+                values.push(value);
+                offset += used;
+            }
+            Ok(values)
+        }
+
+        /// Get a snapshot of all registers in a module, in a single call.
+        fn get_module(
+            &self,
+            module: u32,
+        ) -> Result<Vec<(u32, RegisterValue)>, Box<InterfaceError>> {
+            clear_error();
+            let length = unsafe { wasm_interface_get_module(module) };
+            get_error(module, 0)?;
+            let data = read_from_buffer_bytes(length);
+            let mut values = vec![];
+            let mut offset = 0;
+            while offset < data.len() {
+                let register =
+                    crate::interface::read_u32(&data, offset).expect("host sends valid values");
+                let (value, used) = RegisterValue::from_le_bytes(&data[offset + 4..])
+                    .expect("host sends valid values");
+                values.push((register, value));
+                offset += 4 + used;
+            }
+            Ok(values)
+        }
+
+        /// Set a list of registers, in a single call.
+        fn set_registers(
+            &mut self,
+            values: &[(u32, u32, RegisterValue)],
+        ) -> Result<(), Box<InterfaceError>> {
+            clear_error();
+            let mut request = vec![];
+            for (module, register, value) in values.iter() {
+                request.extend(module.to_le_bytes());
+                request.extend(register.to_le_bytes());
+                value.write_le_bytes(&mut request);
+            }
+            let index = unsafe {
+                wasm_interface_set_registers(request.as_ptr() as u32, request.len() as u32)
+            };
+            let registers = values
+                .iter()
+                .map(|(module, register, _)| (*module, *register))
+                .collect::<Vec<_>>();
+            get_error_at(&registers, index)?;
+            Ok(())
+        }
     }
 }
 
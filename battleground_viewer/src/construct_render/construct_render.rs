@@ -1,10 +1,12 @@
 use three_d::*;
 
 use super::effects;
+use super::fog_of_war;
 use super::render::{
     BatchProperties, GeometryRef, MeshGeometry, PrimitiveGeometry, RenderPass, RenderableGeometry,
 };
 
+use battleground_construct::components::team::TeamId;
 use battleground_construct::components::unit::UnitId;
 use battleground_construct::display;
 use battleground_construct::display::primitives::{Drawable, Primitive};
@@ -37,6 +39,19 @@ pub struct ConstructRender {
 
     /// Tracked effects that are carried over to the next frame.
     effects: std::collections::HashMap<u64, Box<dyn RetainedEffect>>,
+
+    /// Terrain meshes, these are expensive to create so they are retained.
+    terrain_meshes: std::collections::HashMap<EntityId, CpuMesh>,
+
+    /// Entities that are not drawn, because the team being viewed can't see them.
+    hidden: std::collections::HashSet<EntityId>,
+
+    /// Units the team being viewed can't see. Destroyed units keep the state they had while alive,
+    /// such that the effects they leave behind are hidden as well.
+    hidden_units: std::collections::HashSet<UnitId>,
+
+    /// The team whose perspective is shown.
+    perspective: Option<TeamId>,
 }
 
 impl ConstructRender {
@@ -67,11 +82,17 @@ impl ConstructRender {
             fence_primitives,
             overlay_primitives,
             effects: Default::default(),
+            terrain_meshes: Default::default(),
+            hidden: Default::default(),
+            hidden_units: Default::default(),
+            perspective: None,
         }
     }
 
     pub fn reset(&mut self) {
         self.effects.clear();
+        self.terrain_meshes.clear();
+        self.hidden_units.clear();
     }
 
     fn renderables(&self) -> Vec<&dyn RenderableGeometry> {
@@ -113,6 +134,26 @@ impl ConstructRender {
         );
     }
 
+    fn add_terrain_meshes(&mut self, construct: &Construct) {
+        for (entity, terrain) in construct
+            .world()
+            .component_iter::<display::terrain::TerrainMesh>()
+        {
+            let mesh = self.terrain_meshes.entry(entity).or_insert_with(|| {
+                let mut mesh = CpuMesh {
+                    positions: Positions::F32(terrain.vertices()),
+                    indices: Indices::U32(terrain.indices()),
+                    ..Default::default()
+                };
+                mesh.compute_normals();
+                mesh
+            });
+            let world_pose = construct.entity_pose(entity);
+            self.static_meshes
+                .add_mesh(mesh, *world_pose.transform(), terrain.color().to_color());
+        }
+    }
+
     fn add_grid(&mut self) {
         // Grid goes into overlay for now...
         let lower = -15isize;
@@ -268,6 +309,51 @@ impl ConstructRender {
         }
     }
 
+    fn add_radar_cones(&mut self, construct: &Construct, team: Option<TeamId>) {
+        let width = 0.02;
+        let batch_hints = BatchProperties::Basic {
+            is_transparent: false,
+        };
+        let arc_segments = 16;
+        for cone in fog_of_war::radar_cones(construct, team) {
+            let color = cone
+                .team
+                .and_then(|t| {
+                    battleground_construct::components::team::get_team_entity(construct.world(), t)
+                })
+                .and_then(|e| {
+                    construct
+                        .world()
+                        .component::<battleground_construct::components::team::Team>(e)
+                        .map(|t| t.color().to_color())
+                })
+                .unwrap_or(Color::WHITE);
+            let point = |yaw: f32| (cone.range * yaw.cos(), cone.range * yaw.sin(), 0.0);
+            let mut lines = vec![
+                ((0.0, 0.0, 0.0), point(-cone.yaw)),
+                ((0.0, 0.0, 0.0), point(cone.yaw)),
+            ];
+            let arc_yaw = |i: usize| -cone.yaw + 2.0 * cone.yaw * (i as f32 / arc_segments as f32);
+            for i in 0..arc_segments {
+                lines.push((point(arc_yaw(i)), point(arc_yaw(i + 1))));
+            }
+            for (p0, p1) in lines {
+                let primitive =
+                    Primitive::Line(battleground_construct::display::primitives::Line {
+                        p0,
+                        p1,
+                        width,
+                    });
+                self.overlay_primitives.add_primitive(
+                    batch_hints,
+                    primitive,
+                    cone.transform,
+                    color,
+                );
+            }
+        }
+    }
+
     fn selected_to_units(
         construct: &Construct,
         selected: &std::collections::HashSet<EntityId>,
@@ -286,12 +372,30 @@ impl ConstructRender {
         context: &Context,
         construct: &Construct,
         selected: &std::collections::HashSet<EntityId>,
+        perspective: Option<TeamId>,
+        radar_cones: bool,
     ) {
         // a new cycle, clear the previous instances.
         self.prepare_scene(context);
 
+        // Hide whatever the team whose perspective is shown can't see.
+        if perspective != self.perspective {
+            self.hidden_units.clear();
+            self.perspective = perspective;
+        }
+        if let Some(team) = perspective {
+            let alive = fog_of_war::alive_units(construct);
+            self.hidden_units.retain(|u| !alive.contains(u));
+            self.hidden_units
+                .extend(fog_of_war::hidden_units(construct, team));
+            self.hidden = fog_of_war::hidden_entities(construct, team, &self.hidden_units);
+        } else {
+            self.hidden.clear();
+        }
+
         // World geometry
         self.add_static_meshes();
+        self.add_terrain_meshes(construct);
 
         // Overlays
         self.add_grid();
@@ -299,6 +403,9 @@ impl ConstructRender {
             construct,
             &selected.iter().copied().collect::<Vec<EntityId>>(),
         );
+        if radar_cones {
+            self.add_radar_cones(construct, perspective);
+        }
 
         // Iterate through all displayables to collect meshes
 
@@ -329,6 +436,16 @@ impl ConstructRender {
         self.component_to_meshes::<display::wheeled_steer_beam::WheeledSteerBeam>(construct);
         self.component_to_meshes::<display::wheel::Wheel>(construct);
 
+        // Scout
+        self.component_to_meshes::<display::scout_mast::ScoutMast>(construct);
+
+        // Missile launcher
+        self.component_to_meshes::<display::missile_rail::MissileRail>(construct);
+        self.component_to_meshes::<display::missile::Missile>(construct);
+
+        // Mines
+        self.component_to_meshes::<display::mine::Mine>(construct);
+
         // We could also pre-calculate all entities that have the correct unit members, and then
         // filter based on that...
         let units = Self::selected_to_units(construct, selected);
@@ -361,6 +478,7 @@ impl ConstructRender {
         // Other components.
         self.component_to_meshes::<display::flag::Flag>(construct);
         self.component_to_meshes::<display::display_control_point::DisplayControlPoint>(construct);
+        self.component_to_meshes::<display::obstacle::Obstacle>(construct);
 
         // Get the current effect keys.
         let mut start_keys = self
@@ -401,7 +519,7 @@ impl ConstructRender {
         filter_function: F,
     ) {
         for (element_id, component_with_drawables) in construct.world().component_iter::<C>() {
-            if !filter_function(element_id) {
+            if !filter_function(element_id) || self.is_hidden(construct, element_id) {
                 continue;
             }
             // Get the world pose for this entity, to add draw transform local to this component.
@@ -412,6 +530,26 @@ impl ConstructRender {
         }
     }
 
+    /// Whether the entity is hidden, or originates from a hidden unit like projectiles and effects.
+    fn is_hidden(&self, construct: &Construct, entity: EntityId) -> bool {
+        use battleground_construct::components::{
+            unit_member::UnitMember, unit_source::UnitSource,
+        };
+        let world = construct.world();
+        // Mines are visible once detected, regardless of whether the unit that laid them is.
+        if world.component::<display::mine::Mine>(entity).is_some() {
+            return self.hidden.contains(&entity);
+        }
+        let unit = world
+            .component::<UnitSource>(entity)
+            .map(|s| s.source())
+            .or_else(|| world.component::<UnitMember>(entity).map(|m| m.unit()));
+        self.hidden.contains(&entity)
+            || unit
+                .map(|u| self.hidden_units.contains(&u))
+                .unwrap_or(false)
+    }
+
     /// Function to iterate over the components and convert their drawables into elements.
     fn component_to_meshes<C: Component + Drawable + 'static>(&mut self, construct: &Construct) {
         self.component_to_meshes_filtered::<C, _>(construct, |_| true);
@@ -428,6 +566,10 @@ impl ConstructRender {
         let mut res = vec![];
 
         for (element_id, component_with_drawables) in construct.world().component_iter::<C>() {
+            // Effects of hidden units reveal where they are, so these are dropped as well.
+            if self.is_hidden(construct, element_id) {
+                continue;
+            }
             // Get the world pose for this entity, to add draw transform local to this component.
             let world_pose = construct.entity_pose(element_id);
 
@@ -0,0 +1,107 @@
+use battleground_construct::components;
+use battleground_construct::Construct;
+use components::radar::RadarDetections;
+use components::team::TeamId;
+use components::team_member::TeamMember;
+use components::unit::UnitId;
+use components::unit_member::UnitMember;
+use engine::prelude::*;
+
+/// Reflector entities that the team's radars currently detect, these are recorded so this also
+/// works during playback.
+fn detected_reflectors(construct: &Construct, team: TeamId) -> std::collections::HashSet<EntityId> {
+    let world = construct.world();
+    world
+        .component_iter::<RadarDetections>()
+        .filter(|(e, _)| world.component::<TeamMember>(*e).map(|t| t.team()) == Some(team))
+        .flat_map(|(_, detections)| detections.reflectors().to_vec())
+        .collect()
+}
+
+/// Units that the team knows about, its own units and anything its radars currently detect.
+fn known_units(
+    construct: &Construct,
+    team: TeamId,
+    detected: &std::collections::HashSet<EntityId>,
+) -> std::collections::HashSet<UnitId> {
+    let world = construct.world();
+    let mut known: std::collections::HashSet<UnitId> = Default::default();
+    for (entity, member) in world.component_iter::<UnitMember>() {
+        if world.component::<TeamMember>(entity).map(|t| t.team()) == Some(team)
+            || detected.contains(&entity)
+        {
+            known.insert(member.unit());
+        }
+    }
+    known
+}
+
+/// Units that are alive, objectives like flags are units without health.
+pub fn alive_units(construct: &Construct) -> std::collections::HashSet<UnitId> {
+    let world = construct.world();
+    world
+        .component_iter::<components::unit::Unit>()
+        .filter(|(e, _)| world.component::<components::health::Health>(*e).is_some())
+        .map(|(_, u)| u.id())
+        .collect()
+}
+
+/// Alive units of other teams that are not detected by any of the team's radars. Only units that
+/// are alive are hidden, objectives like flags should always be visible.
+pub fn hidden_units(construct: &Construct, team: TeamId) -> std::collections::HashSet<UnitId> {
+    let detected = detected_reflectors(construct, team);
+    let known = known_units(construct, team, &detected);
+    alive_units(construct)
+        .into_iter()
+        .filter(|u| !known.contains(u))
+        .collect()
+}
+
+/// Entities of the hidden units and mines of other teams that are not detected by any of the
+/// team's radars.
+pub fn hidden_entities(
+    construct: &Construct,
+    team: TeamId,
+    hidden_units: &std::collections::HashSet<UnitId>,
+) -> std::collections::HashSet<EntityId> {
+    let world = construct.world();
+    let detected = detected_reflectors(construct, team);
+    // Mines of other teams are hidden until the team's radars detect them.
+    let hidden_mines = world
+        .component_iter::<battleground_construct::display::mine::Mine>()
+        .filter(|(e, _)| {
+            world.component::<TeamMember>(*e).map(|t| t.team()) != Some(team)
+                && !detected.contains(e)
+        })
+        .map(|(e, _)| e);
+    world
+        .component_iter::<UnitMember>()
+        .filter(|(_, m)| hidden_units.contains(&m.unit()))
+        .map(|(e, _)| e)
+        .chain(hidden_mines)
+        .collect()
+}
+
+/// A radar's detection cone in the horizontal plane of the radar.
+pub struct RadarCone {
+    pub transform: three_d::Mat4,
+    pub range: f32,
+    pub yaw: f32,
+    pub team: Option<TeamId>,
+}
+
+/// The detection cones of the radars of the team, or of all radars if no team is provided.
+pub fn radar_cones(construct: &Construct, team: Option<TeamId>) -> Vec<RadarCone> {
+    let world = construct.world();
+    world
+        .component_iter::<RadarDetections>()
+        .map(|(e, radar)| (e, world.component::<TeamMember>(e).map(|t| t.team()), radar))
+        .filter(|(_, radar_team, _)| team.is_none() || *radar_team == team)
+        .map(|(e, radar_team, radar)| RadarCone {
+            transform: *construct.entity_pose(e).transform(),
+            range: radar.range(),
+            yaw: radar.detection_angle_yaw(),
+            team: radar_team,
+        })
+        .collect()
+}
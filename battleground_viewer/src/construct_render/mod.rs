@@ -1,6 +1,7 @@
 #[allow(clippy::module_inception)]
 mod construct_render;
 mod effects;
+mod fog_of_war;
 mod render;
 
 pub use construct_render::ConstructRender;
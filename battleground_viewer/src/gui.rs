@@ -85,10 +85,13 @@ pub fn window_match(ctx: &egui::Context, construct: &Option<crate::Construct>, s
         .open(&mut open)
         .show(ctx, |ui| {
             use components::capturable::Capturable;
+            use components::match_capture_the_flag::MatchCaptureTheFlag;
+            use components::match_escort::MatchEscort;
             use components::match_finished::MatchFinished;
             use components::match_king_of_the_hill::MatchKingOfTheHill;
             use components::match_team_deathmatch::MatchTeamDeathmatch;
             use components::match_time_limit::MatchTimeLimit;
+            use components::scoreboard::Scoreboard;
             let progress_width = 200.0;
 
             ui.heading("General");
@@ -119,16 +122,77 @@ pub fn window_match(ctx: &egui::Context, construct: &Option<crate::Construct>, s
                 });
             }
 
+            for (_e, escort) in construct.world.component_iter::<MatchEscort>() {
+                let color = state.get_team_color(Some(escort.attackers()));
+                let progress = escort.progress();
+                let furthest = escort.furthest_progress();
+                ui.scope(|ui| {
+                    ui.visuals_mut().selection.bg_fill = color;
+                    ui.add(
+                        ProgressBar::new(progress)
+                            .desired_width(progress_width)
+                            .text(format!("Payload: {progress:.2} (furthest {furthest:.2})")),
+                    );
+                });
+            }
+
+            let is_finished = construct
+                .world
+                .component_iter::<MatchFinished>()
+                .next()
+                .is_some();
+            for (_e, scoreboard) in construct.world.component_iter::<Scoreboard>() {
+                if !scoreboard.is_scored() || is_finished {
+                    continue;
+                }
+                for score in scoreboard.scores() {
+                    let team_name = state.get_team_name(score.team);
+                    ui.label(format!("#{} {team_name:}: {:.1}", score.rank, score.total));
+                }
+            }
+
             if let Some((_e, match_finished)) =
                 construct.world.component_iter::<MatchFinished>().next()
             {
                 if let Some(report) = match_finished.report() {
-                    if let Some(winner) = report.winner {
+                    if let Some(winner) = report.winner() {
                         let team_name = state.get_team_name(winner);
                         ui.label(format!(
                             "Result: {team_name:} won by {:?} in {:.1}s",
                             report.conclusion, report.duration
                         ));
+                    } else if report.is_draw() {
+                        let team_names = report
+                            .scores
+                            .iter()
+                            .filter(|s| s.rank == 1)
+                            .map(|s| state.get_team_name(s.team))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        ui.label(format!(
+                            "Result: Draw between {team_names:} by {:?} in {:.1}s",
+                            report.conclusion, report.duration
+                        ));
+                    }
+                    ui.label("Ranking:");
+                    for score in report.scores.iter() {
+                        let team_name = state.get_team_name(score.team);
+                        let breakdown = score
+                            .entries
+                            .iter()
+                            .filter(|e| e.weight != 0.0)
+                            .map(|e| format!("{:?}: {:.1}", e.objective, e.value))
+                            .collect::<Vec<_>>();
+                        if breakdown.is_empty() {
+                            ui.label(format!("#{} {team_name:}", score.rank));
+                        } else {
+                            ui.label(format!(
+                                "#{} {team_name:}: {:.1} ({})",
+                                score.rank,
+                                score.total,
+                                breakdown.join(", ")
+                            ));
+                        }
                     }
                 } else {
                     ui.label("Result: Ongoing");
@@ -181,6 +245,12 @@ pub fn window_match(ctx: &egui::Context, construct: &Option<crate::Construct>, s
                 .next()
                 .map(|v| v.1.clone());
 
+            let capture_the_flag_report = construct
+                .world
+                .component_iter::<MatchCaptureTheFlag>()
+                .next()
+                .map(|v| v.1.clone());
+
             for (team_id, team) in state.teams.iter() {
                 ui.heading(format!("Team - {}", team.name()));
 
@@ -242,6 +312,33 @@ pub fn window_match(ctx: &egui::Context, construct: &Option<crate::Construct>, s
                     }
                 }
 
+                if let Some(ref capture_the_flag_report) = capture_the_flag_report {
+                    let limit = capture_the_flag_report.point_limit();
+
+                    let points = capture_the_flag_report
+                        .points()
+                        .iter()
+                        .filter(|x| x.0 == *team_id)
+                        .map(|x| x.1)
+                        .last()
+                        .unwrap_or(0);
+
+                    if let Some(ref max) = limit {
+                        ui.scope(|ui| {
+                            ui.visuals_mut().selection.bg_fill = State::ui_team_color(team.color()); // Temporary change
+                            let ratio = points as f32 / *max as f32;
+                            ui.add(
+                                ProgressBar::new(ratio)
+                                    .desired_width(progress_width)
+                                    .text(format!("Flags: {points}/{max}")),
+                            );
+                        });
+                    } else {
+                        // No limit, lets just make some text.
+                        ui.label(format!("Flags: {points}"));
+                    }
+                }
+
                 // Show the units.
                 if let Some(entry) = team_info.get(team_id) {
                     let mut sorted_units = entry.units.iter().collect::<Vec<_>>();
@@ -333,7 +430,11 @@ pub fn window_play(
         });
 }
 
-pub fn top_bar(ctx: &egui::Context, viewer_state: &mut crate::ViewerState) {
+pub fn top_bar(
+    ctx: &egui::Context,
+    construct: &Option<crate::Construct>,
+    viewer_state: &mut crate::ViewerState,
+) {
     egui::TopBottomPanel::top("my_panel").show(ctx, |ui| {
         menu::bar(ui, |ui| {
             ui.menu_button("Construct", |ui| {
@@ -349,6 +450,35 @@ pub fn top_bar(ctx: &egui::Context, viewer_state: &mut crate::ViewerState) {
                 let new_state = (!*viewer_state.gui.time_window.borrow()).into();
                 viewer_state.gui.time_window = new_state;
             };
+            ui.menu_button("View", |ui| {
+                ui.radio_value(&mut viewer_state.perspective, None, "All units");
+                let mut teams = viewer_state
+                    .gui
+                    .teams
+                    .values()
+                    .map(|t| (t.id(), t.name().to_owned()))
+                    .collect::<Vec<_>>();
+                teams.sort();
+                for (team_id, name) in teams {
+                    ui.radio_value(&mut viewer_state.perspective, Some(team_id), name);
+                }
+                ui.separator();
+                ui.checkbox(&mut viewer_state.radar_cones, "Radar cones");
+                // Recordings made before radar detections were recorded have nothing to base the
+                // view on.
+                let has_radars = construct
+                    .as_ref()
+                    .map(|c| {
+                        c.world
+                            .component_iter::<components::radar::RadarDetections>()
+                            .next()
+                            .is_some()
+                    })
+                    .unwrap_or(false);
+                if viewer_state.perspective.is_some() && !has_radars {
+                    ui.label("No radar data, enemy units stay hidden.");
+                }
+            });
             ui.with_layout(
                 egui::Layout::centered_and_justified(egui::Direction::LeftToRight),
                 |ui| {
@@ -32,6 +32,10 @@ pub struct ViewerState {
     playback: f32,
     desired_speed: f32,
     selected: std::collections::HashSet<EntityId>,
+    /// Only show what this team can see, everything is shown if None.
+    perspective: Option<battleground_construct::components::team::TeamId>,
+    /// Draw the detection cones of the radars.
+    radar_cones: bool,
     gui: gui::State,
 }
 impl Default for ViewerState {
@@ -43,6 +47,8 @@ impl Default for ViewerState {
             playback: 0.0,
             desired_speed: 1.0,
             selected: Default::default(),
+            perspective: None,
+            radar_cones: false,
             gui: Default::default(),
         }
     }
@@ -143,7 +149,7 @@ impl ConstructViewer {
                 |ctx| {
                     gui::window_match(ctx, &self.construct, &mut viewer_state.gui);
                     gui::window_play(ctx, &self.construct, &mut viewer_state, &mut self.limiter);
-                    gui::top_bar(ctx, &mut viewer_state);
+                    gui::top_bar(ctx, &self.construct, &mut viewer_state);
                 },
             );
             self.control
@@ -302,6 +308,8 @@ impl ConstructViewer {
                 &self.context,
                 construct,
                 &viewer_state.selected,
+                viewer_state.perspective,
+                viewer_state.radar_cones,
             );
 
             if PRINT_DURATIONS {
@@ -577,15 +585,15 @@ mod wasm32 {
             array.copy_to(&mut as_vec[..]);
             Setup::PlayBytes(as_vec)
         } else if let Some(scenario) = get_scenario()? {
-            Setup::Scenario(
+            Setup::Scenario(Box::new(
                 battleground_construct::config::reader::get_builtin_scenario(&scenario)
                     .map_err(|v| format!("{v:?}"))?,
-            )
+            ))
         } else {
-            Setup::Scenario(ScenarioConfig {
+            Setup::Scenario(Box::new(ScenarioConfig {
                 pre_setup: "playground".to_owned(),
                 ..Default::default()
-            })
+            }))
         };
 
         let construct = battleground_construct::config::setup::setup(&setup_config).unwrap();
@@ -3,10 +3,10 @@
 // #![allow(dead_code)]
 // #![allow(unused_variables)]
 // use battleground_unit_control::log;
-use battleground_unit_control::modules::{clock::*, revolute::*};
+use battleground_unit_control::modules::clock::*;
+use battleground_unit_control::sdk::{Draw, Revolute};
 use battleground_unit_control::{Interface, UnitControl};
 // Module constants live in common and their respective units.
-use battleground_unit_control::modules::draw::LineSegment;
 use battleground_unit_control::units::common;
 // use cgmath_util;
 use cgmath_util::prelude::*;
@@ -26,7 +26,7 @@ pub struct UnitControlExample {}
 pub struct JointP {
     k_p: f32,
     set_point: f32,
-    revolute: u32,
+    revolute: Revolute,
     position: f32,
 }
 impl JointP {
@@ -34,7 +34,7 @@ impl JointP {
         JointP {
             k_p: 0.5,
             set_point: 0.0,
-            revolute,
+            revolute: Revolute::new(revolute),
             position: 0.0,
         }
     }
@@ -51,7 +51,7 @@ impl JointP {
         interface: &mut dyn Interface,
         v: f32,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        interface.set_f32(self.revolute, REG_REVOLUTE_VELOCITY_CMD, v)?;
+        self.revolute.set_velocity(interface, v)?;
         Ok(())
     }
 
@@ -59,7 +59,7 @@ impl JointP {
         &mut self,
         interface: &mut dyn Interface,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        self.position = interface.get_f32(self.revolute, REG_REVOLUTE_POSITION)?;
+        self.position = self.revolute.position(interface)?;
         Ok(())
     }
 
@@ -79,7 +79,7 @@ impl JointP {
 impl UnitControl for UnitControlExample {
     /// This function gets called periodically to control our unit.
     fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
-        draw_clear(interface)?;
+        let mut draw = Draw::new(common::MODULE_DRAW);
 
         // This gets the current time.
         let t = interface.get_f32(common::MODULE_CLOCK, REG_CLOCK_ELAPSED)?;
@@ -120,11 +120,12 @@ impl UnitControl for UnitControlExample {
             * (T310 * c_2.position()).exp()
             * H300;
 
-        draw_frame(interface, H1_0)?;
-        draw_frame(interface, H2_0)?;
-        draw_frame(interface, H3_0)?;
+        draw_frame(&mut draw, H1_0);
+        draw_frame(&mut draw, H2_0);
+        draw_frame(&mut draw, H3_0);
 
-        draw_trajectory(interface, t)?;
+        draw_trajectory(&mut draw, t);
+        draw.submit(interface)?;
 
         // Time to calculate the control law.
         let setpoint = figure_eight_trajectory(t);
@@ -158,15 +159,6 @@ impl UnitControl for UnitControlExample {
     }
 }
 
-fn draw_clear(interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
-    interface.set_bytes(
-        common::MODULE_DRAW,
-        battleground_unit_control::modules::draw::REG_DRAW_LINES,
-        &[],
-    )?;
-    Ok(())
-}
-
 const RATE: f32 = 0.4;
 fn figure_eight_trajectory(t: f32) -> cgmath::Vector3<f32> {
     vec3(
@@ -175,10 +167,7 @@ fn figure_eight_trajectory(t: f32) -> cgmath::Vector3<f32> {
         1.5 + 0.6 * (2.0 * (t * RATE)).sin(),
     )
 }
-fn draw_trajectory(
-    interface: &mut dyn Interface,
-    t: f32,
-) -> Result<(), Box<dyn std::error::Error>> {
+fn draw_trajectory(draw: &mut Draw, t: f32) {
     let l = std::f32::consts::PI * 2.0 / RATE;
     let dl = l / 100.0;
     for k in 0..101 {
@@ -186,57 +175,13 @@ fn draw_trajectory(
         let x1 = (k + 1) as f32 * dl;
         let p0 = figure_eight_trajectory(x0);
         let p1 = figure_eight_trajectory(x1);
-        draw_line(
-            interface,
-            LineSegment {
-                p0: p0.into(),
-                p1: p1.into(),
-                width: 0.01,
-                color: TRANSPARENT_MAGENTA,
-            },
-        )?;
+        draw.line(p0.into(), p1.into(), 0.01, TRANSPARENT_MAGENTA);
     }
     let p0 = figure_eight_trajectory(t);
-    draw_line(
-        interface,
-        LineSegment {
-            p0: p0.into(),
-            p1: (p0 + vec3(0.00, 0.00, -0.01)).into(),
-            width: 0.1,
-            color: GREEN,
-        },
-    )?;
-    Ok(())
-}
-
-fn draw_line(
-    interface: &mut dyn Interface,
-    l: LineSegment,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let len = interface.get_bytes_len(
-        common::MODULE_DRAW,
-        battleground_unit_control::modules::draw::REG_DRAW_LINES,
-    )?;
-    let mut read_v = vec![0; len];
-    interface.get_bytes(
-        common::MODULE_DRAW,
-        battleground_unit_control::modules::draw::REG_DRAW_LINES,
-        &mut read_v,
-    )?;
-    read_v.extend(l.into_le_bytes());
-
-    interface.set_bytes(
-        common::MODULE_DRAW,
-        battleground_unit_control::modules::draw::REG_DRAW_LINES,
-        &read_v,
-    )?;
-    Ok(())
+    draw.line(p0.into(), (p0 + vec3(0.00, 0.00, -0.01)).into(), 0.1, GREEN);
 }
 
-fn draw_frame(
-    interface: &mut dyn Interface,
-    h: cgmath_util::Mat4,
-) -> Result<(), Box<dyn std::error::Error>> {
+fn draw_frame(draw: &mut Draw, h: cgmath_util::Mat4) {
     let origin = vec3(0.0, 0.0, 0.0).to_h();
     let h_origin = h * origin;
     let r = 0.25;
@@ -247,34 +192,24 @@ fn draw_frame(
     let x1_origin = h * x1;
     let x2 = vec3(0.0, 0.0, r).to_h();
     let x2_origin = h * x2;
-    draw_line(
-        interface,
-        LineSegment {
-            p0: x0_origin.to_translation().into(),
-            p1: h_origin.to_translation().into(),
-            width: w,
-            color: RED,
-        },
-    )?;
-    draw_line(
-        interface,
-        LineSegment {
-            p0: x1_origin.to_translation().into(),
-            p1: h_origin.to_translation().into(),
-            width: w,
-            color: GREEN,
-        },
-    )?;
-    draw_line(
-        interface,
-        LineSegment {
-            p0: x2_origin.to_translation().into(),
-            p1: h_origin.to_translation().into(),
-            width: w,
-            color: BLUE,
-        },
-    )?;
-    Ok(())
+    draw.line(
+        x0_origin.to_translation().into(),
+        h_origin.to_translation().into(),
+        w,
+        RED,
+    );
+    draw.line(
+        x1_origin.to_translation().into(),
+        h_origin.to_translation().into(),
+        w,
+        GREEN,
+    );
+    draw.line(
+        x2_origin.to_translation().into(),
+        h_origin.to_translation().into(),
+        w,
+        BLUE,
+    );
 }
 
 #[no_mangle]
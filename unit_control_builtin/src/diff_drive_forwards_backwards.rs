@@ -40,3 +40,33 @@ impl UnitControl for DiffDriveForwardsBackwardsControl {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use battleground_unit_control::mock::MockInterface;
+    use battleground_unit_control::modules::differential_drive::*;
+
+    #[test]
+    fn test_flips_direction() {
+        let mut interface = MockInterface::tank();
+        let mut control = DiffDriveForwardsBackwardsControl::new((1.0, 0.5), 1.0);
+        interface.run(&mut control, 0.1, 5).unwrap();
+        interface.assert_written(
+            tank::MODULE_TANK_DIFF_DRIVE,
+            REG_DIFF_DRIVE_LEFT_CMD,
+            1.0f32,
+        );
+        interface.run(&mut control, 0.1, 10).unwrap();
+        interface.assert_written(
+            tank::MODULE_TANK_DIFF_DRIVE,
+            REG_DIFF_DRIVE_LEFT_CMD,
+            -1.0f32,
+        );
+        interface.assert_written(
+            tank::MODULE_TANK_DIFF_DRIVE,
+            REG_DIFF_DRIVE_RIGHT_CMD,
+            -0.5f32,
+        );
+    }
+}
@@ -5,10 +5,8 @@ use std::f32::consts::PI;
 use battleground_unit_control::modules::cannon::*;
 use battleground_unit_control::modules::clock::*;
 use battleground_unit_control::modules::gps::*;
-use battleground_unit_control::modules::radar::*;
-use battleground_unit_control::modules::radio_receiver::*;
-use battleground_unit_control::modules::revolute::*;
 use battleground_unit_control::modules::unit::*;
+use battleground_unit_control::sdk::{Radar, RadioReceiver, Revolute};
 use battleground_unit_control::units::artillery;
 use battleground_unit_control::units::common;
 use battleground_unit_control::units::tank;
@@ -50,10 +48,11 @@ impl UnitControl for NaiveShoot {
         // let radar_z;
         let radar_local_x;
         let body_z;
-        let turret_pos;
-        let radar_pos;
-        let barrel_pos;
         let muzzle_velocity;
+        let turret;
+        let radar_joint;
+        let barrel;
+        let radar;
 
         let unit_type = interface.get_i32(common::MODULE_UNIT, REG_UNIT_UNIT_TYPE)?;
         let unit_type: UnitType = (unit_type as u32).try_into()?;
@@ -66,13 +65,10 @@ impl UnitControl for NaiveShoot {
                 // radar_z = tank::TANK_DIM_FLOOR_TO_TURRET_Z + tank::TANK_DIM_TURRET_TO_RADAR_Z;
                 radar_local_x = 0.0;
                 body_z = tank::TANK_DIM_FLOOR_TO_BODY_Z;
-                turret_pos =
-                    interface.get_f32(tank::MODULE_TANK_REVOLUTE_TURRET, REG_REVOLUTE_POSITION)?;
-                radar_pos =
-                    interface.get_f32(tank::MODULE_TANK_REVOLUTE_RADAR, REG_REVOLUTE_POSITION)?;
-
-                barrel_pos =
-                    interface.get_f32(tank::MODULE_TANK_REVOLUTE_BARREL, REG_REVOLUTE_POSITION)?;
+                turret = Revolute::new(tank::MODULE_TANK_REVOLUTE_TURRET);
+                radar_joint = Revolute::new(tank::MODULE_TANK_REVOLUTE_RADAR);
+                barrel = Revolute::new(tank::MODULE_TANK_REVOLUTE_BARREL);
+                radar = Radar::new(tank::MODULE_TANK_RADAR);
             }
             UnitType::Artillery => {
                 muzzle_velocity = artillery::ARTILLERY_PARAM_MUZZLE_VELOCITY;
@@ -83,23 +79,18 @@ impl UnitControl for NaiveShoot {
                 // radar_z = artillery::ARTILLERY_DIM_TURRET_TO_RADAR_Z;
                 radar_local_x = artillery::ARTILLERY_DIM_RADAR_JOINT_TO_RADAR_X;
                 body_z = artillery::ARTILLERY_DIM_FLOOR_TO_BODY_Z;
-                turret_pos = interface.get_f32(
-                    artillery::MODULE_ARTILLERY_REVOLUTE_TURRET,
-                    REG_REVOLUTE_POSITION,
-                )?;
-                radar_pos = interface.get_f32(
-                    artillery::MODULE_ARTILLERY_REVOLUTE_RADAR,
-                    REG_REVOLUTE_POSITION,
-                )?;
-                barrel_pos = interface.get_f32(
-                    artillery::MODULE_ARTILLERY_REVOLUTE_BARREL,
-                    REG_REVOLUTE_POSITION,
-                )?;
+                turret = Revolute::new(artillery::MODULE_ARTILLERY_REVOLUTE_TURRET);
+                radar_joint = Revolute::new(artillery::MODULE_ARTILLERY_REVOLUTE_RADAR);
+                barrel = Revolute::new(artillery::MODULE_ARTILLERY_REVOLUTE_BARREL);
+                radar = Radar::new(artillery::MODULE_ARTILLERY_RADAR);
             }
             _ => {
                 panic!("unknown type for this controller");
             }
         }
+        let turret_pos = turret.position(interface)?;
+        let radar_pos = radar_joint.position(interface)?;
+        let barrel_pos = barrel.position(interface)?;
 
         let elapsed = interface
             .get_f32(common::MODULE_CLOCK, REG_CLOCK_ELAPSED)
@@ -117,25 +108,13 @@ impl UnitControl for NaiveShoot {
         let tank_yaw = interface.get_f32(common::MODULE_GPS, REG_GPS_YAW).unwrap();
 
         // Check the radio for broadcasted friendlies.
-        let msg_count = interface
-            .get_i32(common::MODULE_RADIO_RECEIVER, REG_RADIO_RX_MSG_COUNT)
-            .unwrap();
-
+        let receiver = RadioReceiver::new(common::MODULE_RADIO_RECEIVER);
         let mut team_xy = vec![];
-        for i in 0..msg_count as u32 {
-            let msg_offset = REG_RADIO_RX_MSG_START + i * REG_RADIO_RX_MSG_STRIDE;
-            let data_offset = msg_offset + REG_RADIO_RX_MSG_OFFSET_DATA;
-            let c = interface
-                .get_bytes_len(common::MODULE_RADIO_RECEIVER, data_offset)
-                .unwrap();
-            if c != 12 {
+        for message in receiver.messages(interface)? {
+            let d = message.data;
+            if d.len() != 12 {
                 continue;
             }
-
-            let mut d = vec![0; c];
-            interface
-                .get_bytes(common::MODULE_RADIO_RECEIVER, data_offset, &mut d)
-                .unwrap();
             // Now that we have the bytes, we can reconstruct the (team, x, y).
             let team = u32::from_le_bytes([d[0], d[1], d[2], d[3]]);
             let x = f32::from_le_bytes([d[4], d[5], d[6], d[7]]);
@@ -143,9 +122,7 @@ impl UnitControl for NaiveShoot {
             team_xy.push((team, x, y));
         }
         // Drop all messages now that we have obtained them.
-        interface
-            .set_i32(common::MODULE_RADIO_RECEIVER, REG_RADIO_RX_MSG_COUNT, 0)
-            .unwrap();
+        receiver.clear_messages(interface)?;
 
         // Next, check that radar, calculating expressing things in global pose.
         // let turret_pos = interface
@@ -156,23 +133,11 @@ impl UnitControl for NaiveShoot {
         // .unwrap();
         let radar_yaw = turret_pos + radar_pos + tank_yaw;
 
-        let reflection_count = interface
-            .get_i32(tank::MODULE_TANK_RADAR, REG_RADAR_REFLECTION_COUNT)
-            .unwrap();
-
-        let mut reflections = vec![];
-        for i in 0..reflection_count as u32 {
-            let reflection_offset = REG_RADAR_REFLECTION_START + i * REG_RADAR_REFLECTION_STRIDE;
-            let yaw_offset = reflection_offset + REG_RADAR_REFLECTION_OFFSET_YAW;
-            let distance_offset = reflection_offset + REG_RADAR_REFLECTION_OFFSET_DISTANCE;
-            let reflection_yaw = interface
-                .get_f32(tank::MODULE_TANK_RADAR, yaw_offset)
-                .unwrap();
-            let distance = interface
-                .get_f32(tank::MODULE_TANK_RADAR, distance_offset)
-                .unwrap();
-            reflections.push((radar_yaw + reflection_yaw, distance));
-        }
+        let reflections = radar
+            .reflections(interface)?
+            .iter()
+            .map(|r| (radar_yaw + r.yaw, r.distance))
+            .collect::<Vec<_>>();
 
         fn distance(p0: (f32, f32), p1: (f32, f32)) -> f32 {
             let dx = p0.0 - p1.0;
@@ -251,13 +216,7 @@ impl UnitControl for NaiveShoot {
             } else {
                 yaw_error.max(min_value)
             };
-            interface
-                .set_f32(
-                    tank::MODULE_TANK_REVOLUTE_TURRET,
-                    REG_REVOLUTE_VELOCITY_CMD,
-                    yaw_error_minned,
-                )
-                .unwrap();
+            turret.set_velocity(interface, yaw_error_minned)?;
 
             // Then, calculate the angle we need to fire at.
             let distance = (dx * dx + dy * dy).sqrt();
@@ -299,13 +258,7 @@ impl UnitControl for NaiveShoot {
                 } else {
                     angle_error.max(min_value)
                 };
-                interface
-                    .set_f32(
-                        tank::MODULE_TANK_REVOLUTE_BARREL,
-                        REG_REVOLUTE_VELOCITY_CMD,
-                        angle_error_minned,
-                    )
-                    .unwrap();
+                barrel.set_velocity(interface, angle_error_minned)?;
 
                 interface
                     .set_i32(tank::MODULE_TANK_CANNON, REG_CANNON_TRIGGER, true as i32)
@@ -3,11 +3,15 @@ use battleground_unit_control::{Interface, UnitControl};
 // use std::f32::consts::PI;
 
 use battleground_unit_control::modules::cannon::*;
-use battleground_unit_control::modules::radar::*;
-use battleground_unit_control::modules::revolute::*;
+use battleground_unit_control::sdk::{Radar, Revolute};
 use battleground_unit_control::units::common;
 use battleground_unit_control::units::tank;
 
+const TURRET: Revolute = Revolute::new(tank::MODULE_TANK_REVOLUTE_TURRET);
+const BARREL: Revolute = Revolute::new(tank::MODULE_TANK_REVOLUTE_BARREL);
+const RADAR_JOINT: Revolute = Revolute::new(tank::MODULE_TANK_REVOLUTE_RADAR);
+const RADAR: Radar = Radar::new(tank::MODULE_TANK_RADAR);
+
 pub struct TankSwivelShoot {
     init_done: bool,
     turret_swivel_interval: f32,
@@ -53,20 +57,8 @@ impl UnitControl for TankSwivelShoot {
 
         let clock = interface.get_f32(common::MODULE_CLOCK, 0).unwrap();
         if !self.init_done {
-            interface
-                .set_f32(
-                    tank::MODULE_TANK_REVOLUTE_TURRET,
-                    REG_REVOLUTE_VELOCITY_CMD,
-                    0.3,
-                )
-                .unwrap();
-            interface
-                .set_f32(
-                    tank::MODULE_TANK_REVOLUTE_BARREL,
-                    REG_REVOLUTE_VELOCITY_CMD,
-                    -0.1,
-                )
-                .unwrap();
+            TURRET.set_velocity(interface, 0.3)?;
+            BARREL.set_velocity(interface, -0.1)?;
             self.init_done = true;
             return Ok(());
         }
@@ -81,15 +73,8 @@ impl UnitControl for TankSwivelShoot {
         // .unwrap();
         // println!("turret_pos: {turret_pos}");
         if (clock - self.turret_flip_last_time) > self.turret_swivel_interval {
-            interface
-                .set_f32(
-                    tank::MODULE_TANK_REVOLUTE_TURRET,
-                    REG_REVOLUTE_VELOCITY_CMD,
-                    -interface
-                        .get_f32(tank::MODULE_TANK_REVOLUTE_TURRET, REG_REVOLUTE_VELOCITY)
-                        .unwrap(),
-                )
-                .unwrap();
+            let velocity = TURRET.velocity(interface)?;
+            TURRET.set_velocity(interface, -velocity)?;
             self.turret_flip_last_time = clock;
         }
 
@@ -98,15 +83,8 @@ impl UnitControl for TankSwivelShoot {
         // .unwrap();
         // println!("barrel_pos: {barrel_pos}");
         if (clock - self.barrel_flip_last_time) > self.barrel_swivel_interval {
-            interface
-                .set_f32(
-                    tank::MODULE_TANK_REVOLUTE_BARREL,
-                    REG_REVOLUTE_VELOCITY_CMD,
-                    -interface
-                        .get_f32(tank::MODULE_TANK_REVOLUTE_BARREL, REG_REVOLUTE_VELOCITY)
-                        .unwrap(),
-                )
-                .unwrap();
+            let velocity = BARREL.velocity(interface)?;
+            BARREL.set_velocity(interface, -velocity)?;
             self.barrel_flip_last_time = clock;
         }
 
@@ -114,36 +92,12 @@ impl UnitControl for TankSwivelShoot {
         // interface.set_f32(0x1200, 4, -1.0).unwrap();
 
         if false {
-            let turret_yaw = interface
-                .get_f32(tank::MODULE_TANK_REVOLUTE_TURRET, REG_REVOLUTE_POSITION)
-                .unwrap();
-            let radar_yaw = interface
-                .get_f32(tank::MODULE_TANK_REVOLUTE_RADAR, REG_REVOLUTE_POSITION)
-                .unwrap();
-            let radar_hits = interface
-                .get_i32(tank::MODULE_TANK_RADAR, REG_RADAR_REFLECTION_COUNT)
-                .unwrap();
-            for i in 0..radar_hits {
-                let offset = i as u32 * REG_RADAR_REFLECTION_STRIDE + REG_RADAR_REFLECTION_START;
-                let reading_yaw = interface
-                    .get_f32(
-                        tank::MODULE_TANK_RADAR,
-                        offset + REG_RADAR_REFLECTION_OFFSET_YAW,
-                    )
-                    .unwrap();
-                let pitch = interface
-                    .get_f32(
-                        tank::MODULE_TANK_RADAR,
-                        offset + REG_RADAR_REFLECTION_OFFSET_PITCH,
-                    )
-                    .unwrap();
-                let distance = interface
-                    .get_f32(
-                        tank::MODULE_TANK_RADAR,
-                        offset + REG_RADAR_REFLECTION_OFFSET_DISTANCE,
-                    )
-                    .unwrap();
-                // let strength = interface.get_f32(tank::MODULE_TANK_RADAR, offset + 3).unwrap();
+            let turret_yaw = TURRET.position(interface)?;
+            let radar_yaw = RADAR_JOINT.position(interface)?;
+            for (i, reflection) in RADAR.reflections(interface)?.iter().enumerate() {
+                let reading_yaw = reflection.yaw;
+                let pitch = reflection.pitch;
+                let distance = reflection.distance;
                 let combined_yaw =
                     (reading_yaw + radar_yaw + turret_yaw).rem_euclid(std::f32::consts::PI * 2.0);
                 let x = combined_yaw.cos() * distance;
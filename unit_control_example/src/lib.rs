@@ -7,8 +7,10 @@ use battleground_unit_control::{Interface, UnitControl};
 // Modules hold their register index constants, they always contain the module name so they
 // can be imported without collisions.
 use battleground_unit_control::modules::{
-    clock::*, controller::*, differential_drive::*, draw::*, gps::*, revolute::*, unit::*,
+    clock::*, controller::*, differential_drive::*, gps::*, unit::*,
 };
+// The sdk provides typed wrappers for modules that are more involved than a single register.
+use battleground_unit_control::sdk::{Draw, Revolute};
 // Module constants live in common and their respective units.
 use battleground_unit_control::units::{common, tank, UnitType};
 
@@ -100,8 +102,8 @@ fn draw_stuff(interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::E
     let z = interface.get_f32(common::MODULE_GPS, REG_GPS_Z)?;
     let yaw = interface.get_f32(common::MODULE_GPS, REG_GPS_YAW)?;
 
-    // Vector for the lines we're going to draw.
-    let mut lines = vec![];
+    // Collects the lines we're going to draw.
+    let mut draw = Draw::new(common::MODULE_DRAW);
 
     const GREEN: [u8; 4] = [0, 255, 0, 255];
     const BLUE: [u8; 4] = [0, 0, 255, 255];
@@ -109,60 +111,47 @@ fn draw_stuff(interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::E
     const TRANSPARENT_MAGENTA: [u8; 4] = [255, 0, 255, 64];
 
     // Red line from the origin to the vehicle.
-    lines.push(LineSegment {
-        p0: [0.0, 0.0, z],
-        p1: [x, y, z],
-        width: 0.05,
-        color: RED,
-    });
+    draw.line([0.0, 0.0, z], [x, y, z], 0.05, RED);
 
     // Blue line pointing out of the front of the vehicle.
-    lines.push(LineSegment {
-        p0: [x, y, z],
-        p1: [x + yaw.cos() * 5.0, y + yaw.sin() * 5.0, z],
-        width: 0.05,
-        color: BLUE,
-    });
+    draw.line(
+        [x, y, z],
+        [x + yaw.cos() * 5.0, y + yaw.sin() * 5.0, z],
+        0.05,
+        BLUE,
+    );
 
     // Green line for yaw + radar (this does not account for turret, so it is wrong!)
     // Also assume it is a tank, unwrapping to 0.0 if it isn't.
-    let radar_pos = interface
-        .get_f32(tank::MODULE_TANK_REVOLUTE_RADAR, REG_REVOLUTE_POSITION)
+    let radar_pos = Revolute::new(tank::MODULE_TANK_REVOLUTE_RADAR)
+        .position(interface)
         .unwrap_or(0.0);
-    lines.push(LineSegment {
-        p0: [x, y, z + 0.5],
-        p1: [
+    draw.line(
+        [x, y, z + 0.5],
+        [
             x + (yaw + radar_pos).cos() * 5.0,
             y + (yaw + radar_pos).sin() * 5.0,
             z + 0.5,
         ],
-        width: 0.05,
-        color: GREEN,
-    });
+        0.05,
+        GREEN,
+    );
 
     // Finally, draw a circle around our tank, just because we can.
     for i in 1..20 {
         let now = ((i as f32) / 19.0) * 2.0 * PI;
         let prev = (((i - 1) as f32) / 19.0) * 2.0 * PI;
         let r = 3.5;
-        lines.push(LineSegment {
-            p0: [x + now.cos() * r, y + now.sin() * r, z + 0.5],
-            p1: [x + prev.cos() * r, y + prev.sin() * r, z + 0.5],
-            width: 0.05,
-            color: TRANSPARENT_MAGENTA,
-        });
+        draw.line(
+            [x + now.cos() * r, y + now.sin() * r, z + 0.5],
+            [x + prev.cos() * r, y + prev.sin() * r, z + 0.5],
+            0.05,
+            TRANSPARENT_MAGENTA,
+        );
     }
 
-    // Now we just need to convert the drawing structs to bytes and set the draw register.
-    let mut draw_instructions: Vec<u8> = vec![];
-    for l in lines {
-        draw_instructions.extend(l.into_le_bytes());
-    }
-    interface.set_bytes(
-        common::MODULE_DRAW,
-        battleground_unit_control::modules::draw::REG_DRAW_LINES,
-        &draw_instructions,
-    )?;
+    // Now we just need to write the lines to the draw module.
+    draw.submit(interface)?;
 
     Ok(())
 }
@@ -1,9 +1,12 @@
 use battleground_unit_control::register_interface::RegisterInterface;
-use battleground_unit_control::{Interface, InterfaceError, UnitControl};
+use battleground_unit_control::{Interface, InterfaceError, RegisterValue, UnitControl};
 use std::time::SystemTime;
 
 use wasmtime::{Caller, Engine, Extern, Instance, Linker, Module, Store, TypedFunc};
 
+/// Harness to compare a native controller against its wasm build.
+pub mod parity;
+
 /// Configuration struct for the wasm control unit.
 #[derive(Clone, Debug)]
 pub struct UnitControlWasmConfig {
@@ -165,15 +168,14 @@ impl UnitControlWasm {
             }
         }
 
-        fn send_string_result(
+        fn send_bytes_result(
             mut caller: Caller<'_, State>,
-            res: Result<String, Box<InterfaceError>>,
+            res: Result<Vec<u8>, Box<InterfaceError>>,
         ) -> usize {
             let wasm_transmission_buffer = get_wasm_transmission_buffer(&mut caller);
             let wasm_set_error = get_wasm_set_error(&mut caller);
             match res {
-                Ok(v) => {
-                    let data = v.into_bytes();
+                Ok(data) => {
                     let data_width = data.len();
                     let p = wasm_transmission_buffer
                         .call(&mut caller, data_width as u32)
@@ -198,6 +200,77 @@ impl UnitControlWasm {
             }
         }
 
+        fn send_string_result(
+            caller: Caller<'_, State>,
+            res: Result<String, Box<InterfaceError>>,
+        ) -> usize {
+            send_bytes_result(caller, res.map(|v| v.into_bytes()))
+        }
+
+        /// Error for a request that points outside of the guest's memory.
+        fn out_of_bounds() -> Box<InterfaceError> {
+            Box::new(InterfaceError {
+                module: 0,
+                register: 0,
+                error_type: battleground_unit_control::interface::InterfaceErrorType::ReadOverflow,
+            })
+        }
+
+        fn read_memory(
+            caller: &mut Caller<'_, State>,
+            src: u32,
+            len: u32,
+        ) -> Result<Vec<u8>, Box<InterfaceError>> {
+            let mem = caller
+                .get_export("memory")
+                .expect("memory should exist")
+                .into_memory()
+                .expect("was not memory");
+            let (bytes, _storage) = mem.data_and_store_mut(caller);
+            let src = src as usize;
+            src.checked_add(len as usize)
+                .and_then(|end| bytes.get(src..end))
+                .map(|v| v.to_vec())
+                .ok_or_else(out_of_bounds)
+        }
+
+        /// The fuel registers of the controller module are provided by the wasm runtime.
+        fn fuel_override(
+            caller: &mut Caller<'_, State>,
+            module: u32,
+            register: u32,
+        ) -> Option<i32> {
+            use battleground_unit_control as buc;
+            use buc::modules::controller::*;
+            use buc::units::common::MODULE_CONTROLLER;
+            if !caller.data().using_fuel || module != MODULE_CONTROLLER {
+                return None;
+            }
+            match register {
+                REG_CONTROLLER_WASM_CPU_FUEL_LEFT => match caller.consume_fuel(0) {
+                    Ok(remaining_fuel) => Some(remaining_fuel as i32),
+                    Err(_f) => Some(i32::MAX),
+                },
+                REG_CONTROLLER_WASM_CPU_FUEL_ENABLED => Some(1),
+                _ => None,
+            }
+        }
+
+        fn get_value(
+            caller: &mut Caller<'_, State>,
+            module: u32,
+            register: u32,
+        ) -> Result<RegisterValue, Box<InterfaceError>> {
+            if let Some(v) = fuel_override(caller, module, register) {
+                return Ok(RegisterValue::I32(v));
+            }
+            let mut values = caller
+                .data()
+                .register_interface
+                .get_registers(&[(module, register)])?;
+            Ok(values.remove(0))
+        }
+
         fn send_pod_result<C>(
             mut caller: Caller<'_, State>,
             res: Result<C, Box<InterfaceError>>,
@@ -277,25 +350,10 @@ impl UnitControlWasm {
             "env",
             "wasm_interface_get_i32",
             |mut caller: Caller<'_, State>, module: u32, register: u32| -> i32 {
-                let mut res = caller.data().register_interface.get_i32(module, register);
-                if caller.data().using_fuel {
-                    use battleground_unit_control as buc;
-                    use buc::modules::controller::*;
-                    use buc::units::common::MODULE_CONTROLLER;
-                    if module == MODULE_CONTROLLER && register == REG_CONTROLLER_WASM_CPU_FUEL_LEFT
-                    {
-                        let z = caller.consume_fuel(0);
-                        res = match z {
-                            Ok(remaining_fuel) => Ok(remaining_fuel as i32),
-                            Err(_f) => Ok(i32::MAX),
-                        };
-                    }
-                    if module == MODULE_CONTROLLER
-                        && register == REG_CONTROLLER_WASM_CPU_FUEL_ENABLED
-                    {
-                        res = Ok(1);
-                    }
-                }
+                let res = match fuel_override(&mut caller, module, register) {
+                    Some(v) => Ok(v),
+                    None => caller.data().register_interface.get_i32(module, register),
+                };
                 send_pod_result(caller, res, 0)
             },
         )?;
@@ -412,6 +470,91 @@ impl UnitControlWasm {
             },
         )?;
 
+        linker.func_wrap(
+            "env",
+            "wasm_interface_get_registers",
+            |mut caller: Caller<'_, State>, src: u32, count: u32| -> u32 {
+                let request = match count
+                    .checked_mul(8)
+                    .ok_or_else(out_of_bounds)
+                    .and_then(|len| read_memory(&mut caller, src, len))
+                {
+                    Ok(v) => v,
+                    Err(e) => return send_pod_result(caller, Err(e), 0),
+                };
+                let mut data = vec![];
+                for (i, pair) in request.chunks_exact(8).enumerate() {
+                    let module = u32::from_le_bytes(pair[0..4].try_into().unwrap());
+                    let register = u32::from_le_bytes(pair[4..8].try_into().unwrap());
+                    match get_value(&mut caller, module, register) {
+                        Ok(v) => v.write_le_bytes(&mut data),
+                        Err(e) => return send_pod_result(caller, Err(e), i as u32),
+                    }
+                }
+                send_bytes_result(caller, Ok(data)) as u32
+            },
+        )?;
+        linker.func_wrap(
+            "env",
+            "wasm_interface_get_module",
+            |mut caller: Caller<'_, State>, module: u32| -> u32 {
+                let registers = match caller.data().register_interface.registers(module) {
+                    Ok(v) => v,
+                    Err(e) => return send_pod_result(caller, Err(e), 0),
+                };
+                let mut data = vec![];
+                for register in registers {
+                    match get_value(&mut caller, module, register) {
+                        Ok(v) => {
+                            data.extend(register.to_le_bytes());
+                            v.write_le_bytes(&mut data);
+                        }
+                        Err(e) => return send_pod_result(caller, Err(e), 0),
+                    }
+                }
+                send_bytes_result(caller, Ok(data)) as u32
+            },
+        )?;
+        linker.func_wrap(
+            "env",
+            "wasm_interface_set_registers",
+            |mut caller: Caller<'_, State>, src: u32, len: u32| -> u32 {
+                let request = match read_memory(&mut caller, src, len) {
+                    Ok(v) => v,
+                    Err(e) => return send_pod_result(caller, Err(e), 0),
+                };
+                let mut offset = 0;
+                let mut index = 0;
+                while offset < request.len() {
+                    let entry = request.get(offset..offset + 8).and_then(|header| {
+                        let module = u32::from_le_bytes(header[0..4].try_into().unwrap());
+                        let register = u32::from_le_bytes(header[4..8].try_into().unwrap());
+                        let (value, used) = RegisterValue::from_le_bytes(&request[offset + 8..])?;
+                        Some(((module, register, value), 8 + used))
+                    });
+                    let Some((entry, used)) = entry else {
+                        let e = Box::new(InterfaceError {
+                            module: 0,
+                            register: 0,
+                            error_type:
+                                battleground_unit_control::interface::InterfaceErrorType::WrongType,
+                        });
+                        return send_pod_result(caller, Err(e), index);
+                    };
+                    let res = caller
+                        .data_mut()
+                        .register_interface
+                        .set_registers(std::slice::from_ref(&entry));
+                    if let Err(e) = res {
+                        return send_pod_result(caller, Err(e), index);
+                    }
+                    offset += used;
+                    index += 1;
+                }
+                index
+            },
+        )?;
+
         linker.func_wrap(
             "env",
             "wasm_log_record",
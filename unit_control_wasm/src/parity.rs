@@ -0,0 +1,240 @@
+//! Harness to verify a controller behaves identically when built natively and as wasm.
+//!
+//! A [`Scenario`] describes the registers a unit exposes and how their values evolve between
+//! updates. The scenario is run against two controllers, for each update all effective register
+//! writes are recorded and compared, the first difference is reported as a [`Divergence`].
+//!
+//! Writes are collapsed per update to the final value of each register, and writes that leave the
+//! register at the value it had before the update are dropped. This is necessary because the wasm
+//! controller copies all registers back after every update, whereas a native controller only
+//! touches the registers it actually sets.
+
+use battleground_unit_control::register_interface::{
+    ModuleId, Register, RegisterId, RegisterInterface, UnitModule,
+};
+use battleground_unit_control::{Interface, InterfaceError, RegisterType, UnitControl};
+
+type BoxedError = Box<InterfaceError>;
+
+/// Value written to a register.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WriteValue {
+    I32(i32),
+    F32(f32),
+    Bytes(Vec<u8>),
+}
+
+/// The effective writes of a single update, sorted by module and register.
+pub type UpdateWrites = std::collections::BTreeMap<(ModuleId, RegisterId), WriteValue>;
+
+/// Module without handler, the registers are populated and modified by the scenario.
+struct ScenarioModule {}
+impl UnitModule for ScenarioModule {}
+
+/// Description of the interface a controller is tested against.
+pub struct Scenario {
+    /// Number of updates to perform.
+    pub updates: usize,
+    /// Creates the modules and registers with their initial values.
+    pub setup: fn(&mut RegisterInterface) -> Result<(), BoxedError>,
+    /// Modifies the registers before each update, the argument is the update index.
+    pub step: fn(&mut RegisterInterface, usize) -> Result<(), BoxedError>,
+}
+
+impl Scenario {
+    /// Helper to add a module with the provided registers to the interface.
+    pub fn add_module(
+        interface: &mut RegisterInterface,
+        name: &str,
+        module: ModuleId,
+        registers: Vec<(RegisterId, Register)>,
+    ) -> Result<(), BoxedError> {
+        interface.add_module(name, module, ScenarioModule {});
+        let m = interface.get_module_mut(module)?;
+        for (register, value) in registers {
+            m.add_register(register, value);
+        }
+        Ok(())
+    }
+
+    /// Run the scenario against a controller, returning the effective writes for each update.
+    pub fn run(
+        &self,
+        controller: &mut dyn UnitControl,
+    ) -> Result<Vec<UpdateWrites>, Box<dyn std::error::Error>> {
+        let mut interface = RegisterInterface::new();
+        (self.setup)(&mut interface)?;
+
+        let mut updates = vec![];
+        for i in 0..self.updates {
+            (self.step)(&mut interface, i)?;
+            let mut recorder = RecordingInterface::new(&mut interface);
+            controller.update(&mut recorder)?;
+            updates.push(recorder.finish());
+        }
+        Ok(updates)
+    }
+}
+
+/// Wraps an interface and records all writes made through it.
+pub struct RecordingInterface<'a> {
+    interface: &'a mut dyn Interface,
+    /// Value before the first write and the latest value written.
+    writes: std::collections::BTreeMap<(ModuleId, RegisterId), (WriteValue, WriteValue)>,
+}
+
+impl<'a> RecordingInterface<'a> {
+    pub fn new(interface: &'a mut dyn Interface) -> Self {
+        RecordingInterface {
+            interface,
+            writes: Default::default(),
+        }
+    }
+
+    fn record(&mut self, module: ModuleId, register: RegisterId, old: WriteValue, new: WriteValue) {
+        self.writes
+            .entry((module, register))
+            .and_modify(|v| v.1 = new.clone())
+            .or_insert((old, new));
+    }
+
+    /// Consume the recorder, returning the writes that changed a register's value.
+    pub fn finish(self) -> UpdateWrites {
+        self.writes
+            .into_iter()
+            .filter(|(_k, (old, new))| old != new)
+            .map(|(k, (_old, new))| (k, new))
+            .collect()
+    }
+}
+
+impl<'a> Interface for RecordingInterface<'a> {
+    fn modules(&self) -> Result<Vec<u32>, BoxedError> {
+        self.interface.modules()
+    }
+    fn registers(&self, module: u32) -> Result<Vec<u32>, BoxedError> {
+        self.interface.registers(module)
+    }
+    fn module_name(&self, module: u32) -> Result<String, BoxedError> {
+        self.interface.module_name(module)
+    }
+    fn register_name(&self, module: u32, register: u32) -> Result<String, BoxedError> {
+        self.interface.register_name(module, register)
+    }
+    fn register_type(&self, module: u32, register: u32) -> Result<RegisterType, BoxedError> {
+        self.interface.register_type(module, register)
+    }
+    fn get_i32(&self, module: u32, register: u32) -> Result<i32, BoxedError> {
+        self.interface.get_i32(module, register)
+    }
+    fn get_f32(&self, module: u32, register: u32) -> Result<f32, BoxedError> {
+        self.interface.get_f32(module, register)
+    }
+    fn set_i32(&mut self, module: u32, register: u32, value: i32) -> Result<i32, BoxedError> {
+        let old = self.interface.set_i32(module, register, value)?;
+        self.record(
+            module,
+            register,
+            WriteValue::I32(old),
+            WriteValue::I32(value),
+        );
+        Ok(old)
+    }
+    fn set_f32(&mut self, module: u32, register: u32, value: f32) -> Result<f32, BoxedError> {
+        let old = self.interface.set_f32(module, register, value)?;
+        self.record(
+            module,
+            register,
+            WriteValue::F32(old),
+            WriteValue::F32(value),
+        );
+        Ok(old)
+    }
+    fn get_bytes_len(&self, module: u32, register: u32) -> Result<usize, BoxedError> {
+        self.interface.get_bytes_len(module, register)
+    }
+    fn get_bytes(
+        &self,
+        module: u32,
+        register: u32,
+        destination: &mut [u8],
+    ) -> Result<usize, BoxedError> {
+        self.interface.get_bytes(module, register, destination)
+    }
+    fn set_bytes(&mut self, module: u32, register: u32, values: &[u8]) -> Result<(), BoxedError> {
+        let mut old = vec![0u8; self.interface.get_bytes_len(module, register)?];
+        self.interface.get_bytes(module, register, &mut old)?;
+        self.interface.set_bytes(module, register, values)?;
+        self.record(
+            module,
+            register,
+            WriteValue::Bytes(old),
+            WriteValue::Bytes(values.to_vec()),
+        );
+        Ok(())
+    }
+}
+
+/// The first difference found between two runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    /// Index of the update in which the runs diverged.
+    pub update: usize,
+    pub module: ModuleId,
+    pub register: RegisterId,
+    /// Value written by the native controller, None if it did not write this register.
+    pub native: Option<WriteValue>,
+    /// Value written by the wasm controller, None if it did not write this register.
+    pub wasm: Option<WriteValue>,
+}
+
+impl std::fmt::Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "diverged at update {} in {:0>8x}:{:0>8x}, native: {:?}, wasm: {:?}",
+            self.update, self.module, self.register, self.native, self.wasm
+        )
+    }
+}
+impl std::error::Error for Divergence {}
+
+/// Compare the writes of two runs, returning the first divergence if there is one.
+pub fn compare(native: &[UpdateWrites], wasm: &[UpdateWrites]) -> Option<Divergence> {
+    let empty = UpdateWrites::new();
+    for update in 0..native.len().max(wasm.len()) {
+        let n = native.get(update).unwrap_or(&empty);
+        let w = wasm.get(update).unwrap_or(&empty);
+        let mut keys = n.keys().chain(w.keys()).copied().collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+        for (module, register) in keys {
+            let native = n.get(&(module, register)).cloned();
+            let wasm = w.get(&(module, register)).cloned();
+            if native != wasm {
+                return Some(Divergence {
+                    update,
+                    module,
+                    register,
+                    native,
+                    wasm,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Run the scenario against the native and wasm controller and compare the writes.
+pub fn check_parity(
+    scenario: &Scenario,
+    native: &mut dyn UnitControl,
+    wasm: &mut dyn UnitControl,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let native_writes = scenario.run(native)?;
+    let wasm_writes = scenario.run(wasm)?;
+    if let Some(divergence) = compare(&native_writes, &wasm_writes) {
+        return Err(Box::new(divergence));
+    }
+    Ok(())
+}
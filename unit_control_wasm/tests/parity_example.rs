@@ -0,0 +1,149 @@
+use battleground_unit_control::modules::{
+    clock::*, controller::*, differential_drive::*, draw::*, gps::*, revolute::*, unit::*,
+};
+use battleground_unit_control::register_interface::{Register, RegisterInterface};
+use battleground_unit_control::units::{common, tank, UnitType};
+use battleground_unit_control::{Interface, InterfaceError};
+
+use unit_control_wasm::parity::{check_parity, Scenario};
+use unit_control_wasm::{UnitControlWasm, UnitControlWasmConfig};
+
+/// Path of the wasm build, created with:
+/// `cargo build --release --target wasm32-unknown-unknown -p unit_control_example`
+fn example_wasm_path() -> std::path::PathBuf {
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/wasm32-unknown-unknown/release/unit_control_example.wasm")
+}
+
+/// A tank driving in a circle, for 20 seconds.
+fn tank_scenario() -> Scenario {
+    fn setup(interface: &mut RegisterInterface) -> Result<(), Box<InterfaceError>> {
+        Scenario::add_module(
+            interface,
+            "clock",
+            common::MODULE_CLOCK,
+            vec![(REG_CLOCK_ELAPSED, Register::new_f32("elapsed", 0.0))],
+        )?;
+        Scenario::add_module(
+            interface,
+            "controller",
+            common::MODULE_CONTROLLER,
+            vec![
+                (
+                    REG_CONTROLLER_UPDATE_INTERVAL,
+                    Register::new_f32("update_interval", 0.01),
+                ),
+                (
+                    REG_CONTROLLER_WASM_CPU_FUEL_ENABLED,
+                    Register::new_i32("fuel_enabled", 0),
+                ),
+                (
+                    REG_CONTROLLER_WASM_CPU_FUEL_LEFT,
+                    Register::new_i32("fuel_left", 0),
+                ),
+            ],
+        )?;
+        Scenario::add_module(
+            interface,
+            "unit",
+            common::MODULE_UNIT,
+            vec![
+                (REG_UNIT_UNIT_ID, Register::new_i32("unit_id", 1)),
+                (
+                    REG_UNIT_UNIT_TYPE,
+                    Register::new_i32("unit_type", UnitType::Tank as i32),
+                ),
+            ],
+        )?;
+        Scenario::add_module(
+            interface,
+            "gps",
+            common::MODULE_GPS,
+            vec![
+                (REG_GPS_X, Register::new_f32("x", 0.0)),
+                (REG_GPS_Y, Register::new_f32("y", 0.0)),
+                (REG_GPS_Z, Register::new_f32("z", 0.0)),
+                (REG_GPS_ROLL, Register::new_f32("roll", 0.0)),
+                (REG_GPS_PITCH, Register::new_f32("pitch", 0.0)),
+                (REG_GPS_YAW, Register::new_f32("yaw", 0.0)),
+            ],
+        )?;
+        Scenario::add_module(
+            interface,
+            "draw",
+            common::MODULE_DRAW,
+            vec![(REG_DRAW_LINES, Register::new_bytes("lines"))],
+        )?;
+        Scenario::add_module(
+            interface,
+            "diff_drive",
+            tank::MODULE_TANK_DIFF_DRIVE,
+            vec![
+                (REG_DIFF_DRIVE_LEFT_VEL, Register::new_f32("left_vel", 0.0)),
+                (
+                    REG_DIFF_DRIVE_RIGHT_VEL,
+                    Register::new_f32("right_vel", 0.0),
+                ),
+                (REG_DIFF_DRIVE_LEFT_CMD, Register::new_f32("left_cmd", 0.0)),
+                (
+                    REG_DIFF_DRIVE_RIGHT_CMD,
+                    Register::new_f32("right_cmd", 0.0),
+                ),
+            ],
+        )?;
+        Scenario::add_module(
+            interface,
+            "radar_rotation",
+            tank::MODULE_TANK_REVOLUTE_RADAR,
+            vec![(REG_REVOLUTE_POSITION, Register::new_f32("position", 0.0))],
+        )?;
+        Ok(())
+    }
+
+    fn step(interface: &mut RegisterInterface, update: usize) -> Result<(), Box<InterfaceError>> {
+        let t = update as f32 * 0.01;
+        interface.set_f32(common::MODULE_CLOCK, REG_CLOCK_ELAPSED, t)?;
+        interface.set_f32(common::MODULE_GPS, REG_GPS_X, (t * 0.1).cos() * 3.0)?;
+        interface.set_f32(common::MODULE_GPS, REG_GPS_Y, (t * 0.1).sin() * 3.0)?;
+        interface.set_f32(common::MODULE_GPS, REG_GPS_YAW, t * 0.1)?;
+        interface.set_f32(
+            tank::MODULE_TANK_REVOLUTE_RADAR,
+            REG_REVOLUTE_POSITION,
+            t * 0.5,
+        )?;
+        Ok(())
+    }
+
+    Scenario {
+        updates: 2000,
+        setup,
+        step,
+    }
+}
+
+/// Needs the wasm build of the example, build it and run this test with:
+/// `cargo build --release --target wasm32-unknown-unknown -p unit_control_example`
+/// `cargo test -p unit_control_wasm --test parity_example -- --ignored`
+#[test]
+#[ignore]
+fn parity_unit_control_example() {
+    let wasm_path = example_wasm_path();
+    assert!(
+        wasm_path.is_file(),
+        "wasm build not found at {}, build it first",
+        wasm_path.display()
+    );
+
+    let mut native = unit_control_example::UnitControlExample::default();
+    let mut wasm = UnitControlWasm::new_with_config(UnitControlWasmConfig {
+        wasm_path,
+        fuel_per_update: None,
+        fuel_for_setup: None,
+        reload: false,
+    })
+    .expect("should be able to load the wasm file");
+
+    if let Err(e) = check_parity(&tank_scenario(), &mut native, &mut wasm) {
+        panic!("native and wasm controller differ: {e}");
+    }
+}